@@ -88,6 +88,9 @@ enum Command {
         /// Flag that specifies if RocksDB with tree should be rolled back.
         #[arg(long)]
         rollback_tree: bool,
+        /// Flag that specifies if RocksDB with tree should be compacted after it's rolled back.
+        #[arg(long, requires = "rollback_tree")]
+        compact_tree: bool,
         /// Flag that specifies if RocksDB with state keeper cache should be rolled back.
         #[arg(long)]
         rollback_sk_cache: bool,
@@ -315,6 +318,7 @@ async fn main() -> anyhow::Result<()> {
             l1_batch_number,
             rollback_postgres,
             rollback_tree,
+            compact_tree,
             rollback_sk_cache,
             rollback_vm_runners_cache,
             rollback_snapshots,
@@ -362,6 +366,9 @@ async fn main() -> anyhow::Result<()> {
             }
             if rollback_tree {
                 block_reverter.enable_rolling_back_merkle_tree(db_config.merkle_tree.path);
+                if compact_tree {
+                    block_reverter.enable_merkle_tree_compaction();
+                }
             }
             if rollback_sk_cache {
                 block_reverter.add_rocksdb_storage_path_to_rollback(db_config.state_keeper_db_path);
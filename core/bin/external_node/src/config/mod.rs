@@ -398,6 +398,18 @@ pub(crate) struct OptionalENConfig {
     // This is intentionally not a part of `RemoteENConfig` because fetching this info from the main node would defeat
     // its purpose; the consistency checker assumes that the main node may provide false information.
     pub contracts_diamond_proxy_addr: Option<Address>,
+    /// Maximum number of L1 batches checked concurrently by the consistency checker. Default is 1 (batches are checked
+    /// one by one).
+    #[serde(default = "OptionalENConfig::default_consistency_checker_max_concurrent_checks")]
+    pub consistency_checker_max_concurrent_checks: NonZeroU32,
+    /// Runs the consistency checker in the read-only mode, in which it doesn't persist the last processed L1 batch.
+    /// Allows running the checker against a read-only Postgres replica.
+    #[serde(default)]
+    pub consistency_checker_read_only: bool,
+    /// URL of the webhook to which alerts on critical conditions (e.g., reorgs or inconsistencies with L1) are posted
+    /// as JSON. If not set, alerts are only logged.
+    #[serde(default)]
+    pub alert_webhook_url: Option<String>,
     /// Number of requests per second allocated for the main node HTTP client. Default is 100 requests.
     #[serde(default = "OptionalENConfig::default_main_node_rate_limit_rps")]
     pub main_node_rate_limit_rps: NonZeroUsize,
@@ -665,6 +677,10 @@ impl OptionalENConfig {
                 .unwrap_or_else(Self::default_main_node_rate_limit_rps),
             api_namespaces,
             contracts_diamond_proxy_addr: None,
+            consistency_checker_max_concurrent_checks:
+                Self::default_consistency_checker_max_concurrent_checks(),
+            consistency_checker_read_only: false,
+            alert_webhook_url: None,
             gateway_url: enconfig.gateway_url.clone(),
         })
     }
@@ -780,6 +796,10 @@ impl OptionalENConfig {
         true
     }
 
+    const fn default_consistency_checker_max_concurrent_checks() -> NonZeroU32 {
+        NonZeroU32::MIN
+    }
+
     fn default_main_node_rate_limit_rps() -> NonZeroUsize {
         NonZeroUsize::new(100).unwrap()
     }
@@ -15,6 +15,7 @@ use zksync_metadata_calculator::{MetadataCalculatorConfig, MetadataCalculatorRec
 use zksync_node_api_server::{tx_sender::ApiContracts, web3::Namespace};
 use zksync_node_framework::{
     implementations::layers::{
+        alert_sink::AlertSinkLayer,
         batch_status_updater::BatchStatusUpdaterLayer,
         block_reverter::BlockReverterLayer,
         commitment_generator::CommitmentGeneratorLayer,
@@ -169,6 +170,12 @@ impl ExternalNodeBuilder {
         Ok(self)
     }
 
+    fn add_alert_sink_layer(mut self) -> anyhow::Result<Self> {
+        let layer = AlertSinkLayer::new(self.config.optional.alert_webhook_url.clone());
+        self.node.add_layer(layer);
+        Ok(self)
+    }
+
     fn add_prometheus_exporter_layer(mut self) -> anyhow::Result<Self> {
         if let Some(prom_config) = self.config.observability.prometheus() {
             self.node.add_layer(PrometheusExporterLayer(prom_config));
@@ -285,7 +292,13 @@ impl ExternalNodeBuilder {
             self.config.diamond_proxy_address(),
             max_batches_to_recheck,
             self.config.optional.l1_batch_commit_data_generator_mode,
-        );
+        )
+        .with_max_concurrent_checks(
+            self.config
+                .optional
+                .consistency_checker_max_concurrent_checks,
+        )
+        .with_read_only(self.config.optional.consistency_checker_read_only);
         self.node.add_layer(layer);
         Ok(self)
     }
@@ -524,6 +537,7 @@ impl ExternalNodeBuilder {
         self = self
             .add_sigint_handler_layer()?
             .add_healthcheck_layer()?
+            .add_alert_sink_layer()?
             .add_prometheus_exporter_layer()?
             .add_pools_layer()?
             .add_main_node_client_layer()?
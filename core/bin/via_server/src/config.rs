@@ -10,8 +10,8 @@ use zksync_config::{
         FriWitnessGeneratorConfig, ObservabilityConfig, PrometheusConfig,
         ProtectiveReadsWriterConfig,
     },
-    ApiConfig, DADispatcherConfig, DBConfig, ObjectStoreConfig, PostgresConfig, ViaBtcSenderConfig,
-    ViaBtcWatchConfig, ViaCelestiaConfig,
+    ApiConfig, DADispatcherConfig, DBConfig, ObjectStoreConfig, PostgresConfig, ViaBtcClientConfig,
+    ViaBtcSenderConfig, ViaBtcWatchConfig, ViaCelestiaConfig, ViaFeeModelConfig,
 };
 use zksync_core_leftovers::temp_config_store::{decode_yaml_repr, TempConfigStore};
 use zksync_env_config::FromEnv;
@@ -84,14 +84,29 @@ pub(crate) fn load_env_config() -> anyhow::Result<TempConfigStore> {
 }
 
 // TODO: temporary solution, should be removed after the config is refactored
-pub(crate) fn via_load_env_config(
-) -> anyhow::Result<(ViaBtcWatchConfig, ViaBtcSenderConfig, ViaCelestiaConfig)> {
+pub(crate) fn via_load_env_config() -> anyhow::Result<(
+    ViaBtcWatchConfig,
+    ViaBtcSenderConfig,
+    ViaCelestiaConfig,
+    ViaFeeModelConfig,
+    ViaBtcClientConfig,
+)> {
     let btc_watch_config =
         ViaBtcWatchConfig::from_env().context("Failed to load BTC watch config")?;
     let btc_sender_config =
         ViaBtcSenderConfig::from_env().context("Failed to load BTC sender config")?;
     let celestia_config =
         ViaCelestiaConfig::from_env().context("Failed to load celestia config")?;
+    let fee_model_config =
+        ViaFeeModelConfig::from_env().context("Failed to load fee model config")?;
+    let btc_client_config =
+        ViaBtcClientConfig::from_env().context("Failed to load BTC client config")?;
 
-    Ok((btc_watch_config, btc_sender_config, celestia_config))
+    Ok((
+        btc_watch_config,
+        btc_sender_config,
+        celestia_config,
+        fee_model_config,
+        btc_client_config,
+    ))
 }
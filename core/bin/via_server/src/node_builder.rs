@@ -176,23 +176,34 @@ impl ViaNodeBuilder {
     // VIA related layers
     fn add_btc_watcher_layer(mut self) -> anyhow::Result<Self> {
         let btc_watch_config = try_load_config!(self.configs.via_btc_watch_config);
-        self.node.add_layer(BtcWatchLayer::new(btc_watch_config));
+        let btc_client_config = try_load_config!(self.configs.via_btc_client_config);
+        self.node
+            .add_layer(BtcWatchLayer::new(btc_watch_config, btc_client_config));
         Ok(self)
     }
 
     fn add_btc_sender_layer(mut self) -> anyhow::Result<Self> {
         let btc_sender_config = try_load_config!(self.configs.via_btc_sender_config);
+        let btc_client_config = try_load_config!(self.configs.via_btc_client_config);
         self.node.add_layer(ViaBtcInscriptionAggregatorLayer::new(
             btc_sender_config.clone(),
+            btc_client_config.clone(),
+        ));
+        self.node.add_layer(ViaInscriptionManagerLayer::new(
+            btc_sender_config,
+            btc_client_config,
         ));
-        self.node
-            .add_layer(ViaInscriptionManagerLayer::new(btc_sender_config));
         Ok(self)
     }
 
     fn add_l1_gas_layer(mut self) -> anyhow::Result<Self> {
         let state_keeper_config = try_load_config!(self.configs.state_keeper_config);
-        let l1_gas_layer = ViaL1GasLayer::new(state_keeper_config);
+        let fee_model_config = self
+            .configs
+            .via_fee_model_config
+            .clone()
+            .unwrap_or_default();
+        let l1_gas_layer = ViaL1GasLayer::new(state_keeper_config, fee_model_config);
         self.node.add_layer(l1_gas_layer);
         Ok(self)
     }
@@ -257,6 +268,7 @@ impl ViaNodeBuilder {
             namespaces.push(Namespace::Debug)
         }
         namespaces.push(Namespace::Snapshots);
+        namespaces.push(Namespace::Via);
 
         let optional_config = Web3ServerOptionalConfig {
             namespaces: Some(namespaces),
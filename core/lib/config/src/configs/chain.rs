@@ -132,6 +132,9 @@ pub struct StateKeeperConfig {
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
 
+    /// Whether the state keeper reports Prometheus metrics. Enabled by default.
+    pub metrics_enabled: Option<bool>,
+
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
     #[deprecated(note = "Use GenesisConfig::bootloader_hash instead")]
@@ -176,11 +179,16 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            metrics_enabled: Some(true),
             bootloader_hash: None,
             default_aa_hash: None,
             l1_batch_commit_data_generator_mode: L1BatchCommitmentMode::Rollup,
         }
     }
+
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics_enabled.unwrap_or(true)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq)]
@@ -28,9 +28,11 @@ pub use self::{
     snapshot_recovery::SnapshotRecoveryConfig,
     snapshots_creator::SnapshotsCreatorConfig,
     utils::PrometheusConfig,
+    via_btc_client::ViaBtcClientConfig,
     via_btc_sender::ViaBtcSenderConfig,
     via_btc_watch::ViaBtcWatchConfig,
     via_celestia::ViaCelestiaConfig,
+    via_fee_model::ViaFeeModelConfig,
     via_general::ViaGeneralConfig,
     vm_runner::{BasicWitnessInputProducerConfig, ProtectiveReadsWriterConfig},
 };
@@ -68,9 +70,11 @@ pub mod secrets;
 pub mod snapshot_recovery;
 pub mod snapshots_creator;
 pub mod utils;
+pub mod via_btc_client;
 pub mod via_btc_sender;
 pub mod via_btc_watch;
 pub mod via_celestia;
+pub mod via_fee_model;
 pub mod via_general;
 pub mod vm_runner;
 pub mod wallets;
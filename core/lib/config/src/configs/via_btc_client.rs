@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Bitcoin RPC client.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ViaBtcClientConfig {
+    /// Maximum time the Bitcoin node is allowed to take to respond to a single RPC request.
+    /// Failed requests may be retried by the client. Value in milliseconds.
+    #[serde(default = "ViaBtcClientConfig::default_rpc_timeout_ms")]
+    pub rpc_timeout_ms: u64,
+}
+
+impl ViaBtcClientConfig {
+    const fn default_rpc_timeout_ms() -> u64 {
+        30_000
+    }
+
+    /// Converts `self.rpc_timeout_ms` into `Duration`.
+    pub fn rpc_timeout(&self) -> Duration {
+        Duration::from_millis(self.rpc_timeout_ms)
+    }
+
+    /// Creates a mock configuration object suitable for unit tests.
+    pub fn for_tests() -> Self {
+        Self {
+            rpc_timeout_ms: 1000,
+        }
+    }
+}
+
+impl Default for ViaBtcClientConfig {
+    fn default() -> Self {
+        Self {
+            rpc_timeout_ms: Self::default_rpc_timeout_ms(),
+        }
+    }
+}
@@ -9,6 +9,14 @@ pub enum ProofSendingMode {
     SkipEveryProof,
 }
 
+/// Script type of the change outputs created by the inscriber.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeOutputType {
+    #[default]
+    P2WPKH,
+    P2TR,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct ViaBtcSenderConfig {
     pub rpc_url: String,
@@ -43,9 +51,37 @@ pub struct ViaBtcSenderConfig {
 
     /// Number of block confirmations required to mark the inscription request as confirmed.
     pub block_confirmations: u32,
+
+    /// Script type used for the change outputs of the commit and reveal transactions.
+    #[serde(default)]
+    pub change_output_type: ChangeOutputType,
+
+    /// Minimum fee rate (sat/vB) of the inscription transactions, applied when the node estimate
+    /// is lower or unavailable. When unset, only non-mainnet networks get a default floor.
+    #[serde(default)]
+    pub min_fee_rate: Option<u64>,
+
+    /// Whether inflight inscriptions that were evicted from the mempool are re-broadcast
+    /// using the signed transactions stored in the inscription history.
+    #[serde(default)]
+    pub rebroadcast_evicted_inscriptions: bool,
+
+    /// How long an L1 batch may wait for its DA blob to be included before this is reported as
+    /// an error. Batches are never inscribed before their blob is included. Not reported if not set.
+    /// Value in seconds.
+    #[serde(default)]
+    pub da_inclusion_alert_timeout_secs: Option<u64>,
+
+    /// Whether the sender reports Prometheus metrics. Enabled by default.
+    #[serde(default = "ViaBtcSenderConfig::default_metrics_enabled")]
+    pub metrics_enabled: bool,
 }
 
 impl ViaBtcSenderConfig {
+    const fn default_metrics_enabled() -> bool {
+        true
+    }
+
     pub fn rpc_url(&self) -> &str {
         &self.rpc_url
     }
@@ -94,6 +130,29 @@ impl ViaBtcSenderConfig {
     pub fn block_confirmations(&self) -> u32 {
         self.block_confirmations
     }
+
+    pub fn change_output_type(&self) -> ChangeOutputType {
+        self.change_output_type
+    }
+
+    pub fn min_fee_rate(&self) -> Option<u64> {
+        self.min_fee_rate
+    }
+
+    pub fn rebroadcast_evicted_inscriptions(&self) -> bool {
+        self.rebroadcast_evicted_inscriptions
+    }
+
+    /// Converts `self.da_inclusion_alert_timeout_secs` into `Duration`.
+    pub fn da_inclusion_alert_timeout(&self) -> Option<Duration> {
+        self.da_inclusion_alert_timeout_secs
+            .map(Duration::from_secs)
+    }
+
+    /// Returns whether the sender reports Prometheus metrics.
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics_enabled
+    }
 }
 
 impl ViaBtcSenderConfig {
@@ -113,6 +172,11 @@ impl ViaBtcSenderConfig {
             da_identifier: "da_identifier_celestia".to_string(),
             proof_sending_mode: ProofSendingMode::SkipEveryProof,
             block_confirmations: 0,
+            change_output_type: ChangeOutputType::P2WPKH,
+            min_fee_rate: None,
+            rebroadcast_evicted_inscriptions: false,
+            da_inclusion_alert_timeout_secs: None,
+            metrics_enabled: true,
         }
     }
 }
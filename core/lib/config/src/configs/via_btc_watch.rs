@@ -38,14 +38,69 @@ pub struct ViaBtcWatchConfig {
 
     /// Number of blocks that we should wait before processing the new blocks.
     pub btc_blocks_lag: u32,
+
+    /// Maximum random deviation applied to each poll interval, so that the watcher doesn't poll
+    /// in lockstep with other components. The average interval is unchanged.
+    /// Value in milliseconds.
+    #[serde(default)]
+    pub poll_jitter_ms: u64,
+
+    /// Whether the watcher reports Prometheus metrics. Enabled by default.
+    #[serde(default = "ViaBtcWatchConfig::default_metrics_enabled")]
+    pub metrics_enabled: bool,
+
+    /// Approximate cap on the size of inscription messages held in memory during a single poll.
+    /// Once exceeded, the remaining blocks are left for the next poll. Unlimited if not set.
+    #[serde(default)]
+    pub max_message_bytes_per_iteration: Option<usize>,
+
+    /// Whether transactions carrying more than one inscription are rejected as a whole,
+    /// rather than having each of their inscriptions processed.
+    #[serde(default)]
+    pub strict_inscription_validation: bool,
+
+    /// ZMQ endpoint on which the Bitcoin node publishes new block hashes (`-zmqpubhashblock`),
+    /// e.g. `tcp://127.0.0.1:28332`. If set, the watcher polls as soon as a block is found;
+    /// it keeps polling on the interval regardless.
+    #[serde(default)]
+    pub zmq_block_endpoint: Option<String>,
+
+    /// Maximum allowed difference between the local clock and the timestamp of the Bitcoin chain tip,
+    /// on top of fixed allowances for the tip trailing (up to 1 hour) or running ahead (up to 2 hours)
+    /// of the wall clock. Not checked if not set.
+    /// Value in seconds.
+    #[serde(default)]
+    pub max_clock_skew_secs: Option<u64>,
+
+    /// Directory to persist the raw fetched blocks in, so that they can be replayed for debugging.
+    /// Blocks are not persisted if not set.
+    #[serde(default)]
+    pub block_archive_path: Option<String>,
+
+    /// Number of most recent block heights kept in the block archive.
+    #[serde(default = "ViaBtcWatchConfig::default_block_archive_capacity")]
+    pub block_archive_capacity: u32,
 }
 
 impl ViaBtcWatchConfig {
+    const fn default_metrics_enabled() -> bool {
+        true
+    }
+
+    const fn default_block_archive_capacity() -> u32 {
+        144
+    }
+
     /// Converts `self.btc_node_poll_interval` into `Duration`.
     pub fn poll_interval(&self) -> Duration {
         Duration::from_millis(self.btc_node_poll_interval)
     }
 
+    /// Converts `self.poll_jitter_ms` into `Duration`.
+    pub fn poll_jitter(&self) -> Duration {
+        Duration::from_millis(self.poll_jitter_ms)
+    }
+
     /// Returns the amount of confirmations for the Bitcoin message to be processed.
     pub fn confirmations_for_btc_msg(&self) -> Option<u64> {
         self.confirmations_for_btc_msg
@@ -81,6 +136,37 @@ impl ViaBtcWatchConfig {
         &self.network
     }
 
+    /// Returns whether the watcher reports Prometheus metrics.
+    pub fn metrics_enabled(&self) -> bool {
+        self.metrics_enabled
+    }
+
+    /// Returns the cap on the size of inscription messages processed in a single poll.
+    pub fn max_message_bytes_per_iteration(&self) -> Option<usize> {
+        self.max_message_bytes_per_iteration
+    }
+
+    /// Returns whether transactions with more than one inscription are rejected.
+    pub fn strict_inscription_validation(&self) -> bool {
+        self.strict_inscription_validation
+    }
+
+    /// Converts `self.max_clock_skew_secs` into `Duration`.
+    pub fn max_clock_skew(&self) -> Option<Duration> {
+        self.max_clock_skew_secs.map(Duration::from_secs)
+    }
+
+    /// Returns the ZMQ endpoint publishing new block hashes, if configured.
+    pub fn zmq_block_endpoint(&self) -> Option<&str> {
+        self.zmq_block_endpoint.as_deref()
+    }
+
+    /// Returns the directory and the capacity of the block archive, if enabled.
+    pub fn block_archive(&self) -> Option<(&str, u32)> {
+        let path = self.block_archive_path.as_deref()?;
+        Some((path, self.block_archive_capacity))
+    }
+
     /// Returns the list of transaction IDs to bootstrap the indexer.
     pub fn bootstrap_txids(&self) -> Vec<String> {
         self.bootstrap_txids.clone()
@@ -101,6 +187,14 @@ impl ViaBtcWatchConfig {
             bootstrap_txids: vec![],
             actor_role: ActorRole::Sequencer,
             btc_blocks_lag: 1,
+            poll_jitter_ms: 0,
+            metrics_enabled: true,
+            max_message_bytes_per_iteration: None,
+            strict_inscription_validation: false,
+            zmq_block_endpoint: None,
+            max_clock_skew_secs: None,
+            block_archive_path: None,
+            block_archive_capacity: Self::default_block_archive_capacity(),
         }
     }
 }
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the Via fee model.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct ViaFeeModelConfig {
+    /// L1 gas price reported by the fixed fee input. The fee input is only fixed if all
+    /// three `fixed_*` prices are set, which is intended for test and regtest deployments.
+    #[serde(default)]
+    pub fixed_l1_gas_price: Option<u64>,
+    /// L2 gas price reported by the fixed fee input.
+    #[serde(default)]
+    pub fixed_fair_l2_gas_price: Option<u64>,
+    /// Pubdata price reported by the fixed fee input.
+    #[serde(default)]
+    pub fixed_fair_pubdata_price: Option<u64>,
+}
+
+impl ViaFeeModelConfig {
+    /// Returns the fixed `(l1_gas_price, fair_l2_gas_price, fair_pubdata_price)` if the fee input
+    /// is fixed. Errors if only some of the prices are set.
+    pub fn fixed_fee_input(&self) -> anyhow::Result<Option<(u64, u64, u64)>> {
+        match (
+            self.fixed_l1_gas_price,
+            self.fixed_fair_l2_gas_price,
+            self.fixed_fair_pubdata_price,
+        ) {
+            (Some(l1_gas_price), Some(fair_l2_gas_price), Some(fair_pubdata_price)) => {
+                Ok(Some((l1_gas_price, fair_l2_gas_price, fair_pubdata_price)))
+            }
+            (None, None, None) => Ok(None),
+            _ => anyhow::bail!("either all or none of the fixed fee input prices must be set"),
+        }
+    }
+
+    /// Creates a mock configuration object suitable for unit tests.
+    pub fn for_tests() -> Self {
+        Self {
+            fixed_l1_gas_price: Some(1_000),
+            fixed_fair_l2_gas_price: Some(100_000_000),
+            fixed_fair_pubdata_price: Some(1_000),
+        }
+    }
+}
@@ -16,8 +16,8 @@ use crate::{
         ObservabilityConfig, PrometheusConfig, ProofDataHandlerConfig,
     },
     ApiConfig, ContractVerifierConfig, DBConfig, EthConfig, ExternalProofIntegrationApiConfig,
-    ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig, ViaBtcSenderConfig,
-    ViaBtcWatchConfig, ViaCelestiaConfig,
+    ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig, ViaBtcClientConfig,
+    ViaBtcSenderConfig, ViaBtcWatchConfig, ViaCelestiaConfig, ViaFeeModelConfig,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -56,9 +56,11 @@ pub struct ViaGeneralConfig {
     pub experimental_vm_config: Option<ExperimentalVmConfig>,
     pub prover_job_monitor_config: Option<ProverJobMonitorConfig>,
     // VIA specific configs
+    pub via_btc_client_config: Option<ViaBtcClientConfig>,
     pub via_btc_sender_config: Option<ViaBtcSenderConfig>,
     pub via_btc_watch_config: Option<ViaBtcWatchConfig>,
     pub via_celestia_config: Option<ViaCelestiaConfig>,
+    pub via_fee_model_config: Option<ViaFeeModelConfig>,
 }
 
 impl From<GeneralConfig> for ViaGeneralConfig {
@@ -98,9 +100,11 @@ impl From<GeneralConfig> for ViaGeneralConfig {
             experimental_vm_config: value.experimental_vm_config,
             prover_job_monitor_config: value.prover_job_monitor_config,
             // VIA specific configs
+            via_btc_client_config: None,
             via_btc_sender_config: None,
             via_btc_watch_config: None,
             via_celestia_config: None,
+            via_fee_model_config: None,
         }
     }
 }
@@ -4,7 +4,8 @@ pub use crate::configs::{
     ApiConfig, BaseTokenAdjusterConfig, ContractVerifierConfig, ContractsConfig,
     DADispatcherConfig, DBConfig, EthConfig, EthWatchConfig, ExternalProofIntegrationApiConfig,
     GasAdjusterConfig, GenesisConfig, ObjectStoreConfig, PostgresConfig, SnapshotsCreatorConfig,
-    ViaBtcSenderConfig, ViaBtcWatchConfig, ViaCelestiaConfig, ViaGeneralConfig,
+    ViaBtcClientConfig, ViaBtcSenderConfig, ViaBtcWatchConfig, ViaCelestiaConfig,
+    ViaFeeModelConfig, ViaGeneralConfig,
 };
 
 pub mod configs;
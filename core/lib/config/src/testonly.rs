@@ -181,6 +181,7 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            metrics_enabled: self.sample(rng),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -941,6 +942,7 @@ impl Distribution<configs::da_dispatcher::DADispatcherConfig> for EncodeDist {
             max_rows_to_dispatch: self.sample(rng),
             max_retries: self.sample(rng),
             use_dummy_inclusion_data: self.sample(rng),
+            metrics_enabled: self.sample(rng),
         }
     }
 }
@@ -79,9 +79,12 @@ impl ViaBtcSenderDal<'_, '_> {
         Ok(txs.into_iter().map(|tx| tx.into()).collect())
     }
 
+    /// Lists inscription requests that have not been sent yet, oldest first (by creation time,
+    /// then by ID). If `request_type` is specified, only requests of this type are returned.
     pub async fn list_new_inscription_request(
         &mut self,
         limit: i64,
+        request_type: Option<ViaBtcInscriptionRequestType>,
     ) -> sqlx::Result<Vec<ViaBtcInscriptionRequest>> {
         let txs = sqlx::query_as!(
             ViaStorageBtcInscriptionRequest,
@@ -93,12 +96,18 @@ impl ViaBtcSenderDal<'_, '_> {
                 LEFT JOIN via_btc_inscriptions_request_history ON via_btc_inscriptions_request.id = via_btc_inscriptions_request_history.inscription_request_id
             WHERE
                 via_btc_inscriptions_request_history.inscription_request_id IS NULL
+                AND (
+                    $2::VARCHAR IS NULL
+                    OR via_btc_inscriptions_request.request_type = $2
+                )
             ORDER BY
+                via_btc_inscriptions_request.created_at,
                 via_btc_inscriptions_request.id
             LIMIT
                 $1
             "#,
             limit,
+            request_type.map(|request_type| request_type.to_string()),
         )
         .fetch_all(self.storage.conn())
         .await?;
@@ -239,3 +248,87 @@ impl ViaBtcSenderDal<'_, '_> {
         transaction.commit().await.context("commit()")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash;
+
+    use super::*;
+    use crate::{ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn listing_new_inscription_requests() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        let request_types = [
+            ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+            ViaBtcInscriptionRequestType::CommitProofOnchain,
+            ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+            ViaBtcInscriptionRequestType::CommitProofOnchain,
+        ];
+        let mut request_ids = vec![];
+        for request_type in request_types {
+            let request = conn
+                .btc_sender_dal()
+                .via_save_btc_inscriptions_request(request_type, vec![], 0)
+                .await
+                .unwrap();
+            request_ids.push(request.id);
+        }
+
+        let requests = conn
+            .btc_sender_dal()
+            .list_new_inscription_request(10, None)
+            .await
+            .unwrap();
+        let ids: Vec<_> = requests.iter().map(|request| request.id).collect();
+        assert_eq!(ids, request_ids);
+
+        let requests = conn
+            .btc_sender_dal()
+            .list_new_inscription_request(1, None)
+            .await
+            .unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].id, request_ids[0]);
+
+        let requests = conn
+            .btc_sender_dal()
+            .list_new_inscription_request(
+                10,
+                Some(ViaBtcInscriptionRequestType::CommitProofOnchain),
+            )
+            .await
+            .unwrap();
+        let ids: Vec<_> = requests.iter().map(|request| request.id).collect();
+        assert_eq!(ids, [request_ids[1], request_ids[3]]);
+        assert!(requests.iter().all(
+            |request| request.request_type == ViaBtcInscriptionRequestType::CommitProofOnchain
+        ));
+
+        // Requests that were already sent must not be listed.
+        conn.btc_sender_dal()
+            .insert_inscription_request_history(
+                Txid::from_byte_array([1; 32]),
+                Txid::from_byte_array([2; 32]),
+                request_ids[1],
+                vec![],
+                vec![],
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+        let requests = conn
+            .btc_sender_dal()
+            .list_new_inscription_request(
+                10,
+                Some(ViaBtcInscriptionRequestType::CommitProofOnchain),
+            )
+            .await
+            .unwrap();
+        let ids: Vec<_> = requests.iter().map(|request| request.id).collect();
+        assert_eq!(ids, [request_ids[3]]);
+    }
+}
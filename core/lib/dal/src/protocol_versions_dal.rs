@@ -1,4 +1,4 @@
-use std::convert::TryInto;
+use std::{collections::HashMap, convert::TryInto};
 
 use anyhow::Context as _;
 use zksync_contracts::{BaseSystemContracts, BaseSystemContractsHashes};
@@ -10,7 +10,7 @@ use zksync_db_connection::{
 use zksync_types::{
     protocol_upgrade::{ProtocolUpgradeTx, ProtocolVersion},
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion, VersionPatch},
-    ProtocolVersionId, H256,
+    L1BatchNumber, ProtocolVersionId, H256,
 };
 
 use crate::{
@@ -370,6 +370,47 @@ impl ProtocolVersionsDal<'_, '_> {
         .await
     }
 
+    /// Returns the protocol version of each L1 batch in the inclusive range, with the latest known patch
+    /// of the batch minor version. Batches without a protocol version are omitted.
+    pub async fn protocol_versions_for_batches(
+        &mut self,
+        from: L1BatchNumber,
+        to: L1BatchNumber,
+    ) -> DalResult<HashMap<L1BatchNumber, ProtocolSemanticVersion>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                l1_batches.number,
+                protocol_patches.minor,
+                MAX(protocol_patches.patch) AS "patch!"
+            FROM
+                l1_batches
+                JOIN protocol_patches ON protocol_patches.minor = l1_batches.protocol_version
+            WHERE
+                l1_batches.number BETWEEN $1 AND $2
+            GROUP BY
+                l1_batches.number,
+                protocol_patches.minor
+            "#,
+            i64::from(from.0),
+            i64::from(to.0),
+        )
+        .try_map(|row| {
+            let version = ProtocolSemanticVersion {
+                minor: parse_protocol_version(row.minor)?,
+                patch: (row.patch as u32).into(),
+            };
+            Ok((L1BatchNumber(row.number as u32), version))
+        })
+        .instrument("protocol_versions_for_batches")
+        .with_arg("from", &from)
+        .with_arg("to", &to)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows.into_iter().collect())
+    }
+
     pub async fn last_used_version_id(&mut self) -> Option<ProtocolVersionId> {
         let id = sqlx::query!(
             r#"
@@ -459,3 +500,64 @@ impl ProtocolVersionsDal<'_, '_> {
         Ok(Some(tx))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::block::L1BatchHeader;
+
+    use super::*;
+    use crate::ConnectionPool;
+
+    #[tokio::test]
+    async fn getting_protocol_versions_for_batches_across_upgrade() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+
+        let old_version = ProtocolSemanticVersion {
+            minor: ProtocolVersionId::Version23,
+            patch: VersionPatch(0),
+        };
+        let new_versions = [0, 1].map(|patch| ProtocolSemanticVersion {
+            minor: ProtocolVersionId::Version24,
+            patch: VersionPatch(patch),
+        });
+        for version in [old_version].into_iter().chain(new_versions) {
+            conn.protocol_versions_dal()
+                .save_protocol_version_with_tx(&ProtocolVersion {
+                    version,
+                    ..ProtocolVersion::default()
+                })
+                .await
+                .unwrap();
+        }
+
+        // The upgrade to the new minor version happens at batch #3.
+        for number in 1..=4 {
+            let minor = if number < 3 {
+                old_version.minor
+            } else {
+                ProtocolVersionId::Version24
+            };
+            let header = L1BatchHeader {
+                protocol_version: Some(minor),
+                ..crate::tests::create_l1_batch_header(number)
+            };
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&header)
+                .await
+                .unwrap();
+        }
+
+        let versions = conn
+            .protocol_versions_dal()
+            .protocol_versions_for_batches(L1BatchNumber(2), L1BatchNumber(10))
+            .await
+            .unwrap();
+        let expected = HashMap::from([
+            (L1BatchNumber(2), old_version),
+            (L1BatchNumber(3), new_versions[1]),
+            (L1BatchNumber(4), new_versions[1]),
+        ]);
+        assert_eq!(versions, expected);
+    }
+}
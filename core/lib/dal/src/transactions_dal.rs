@@ -2147,6 +2147,30 @@ impl TransactionsDal<'_, '_> {
         .map(|call_trace| call_trace.into_call(protocol_version)))
     }
 
+    /// Returns the L1 batch that includes the transaction with the specified hash, or `None`
+    /// if the transaction is unknown or not yet included in a sealed L1 batch.
+    pub async fn get_l1_batch_for_tx(&mut self, tx_hash: H256) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number
+            FROM
+                transactions
+            WHERE
+                hash = $1
+            "#,
+            tx_hash.as_bytes()
+        )
+        .instrument("get_l1_batch_for_tx")
+        .with_arg("tx_hash", &tx_hash)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row
+            .and_then(|row| row.l1_batch_number)
+            .map(|number| L1BatchNumber(number as u32)))
+    }
+
     pub(crate) async fn get_tx_by_hash(&mut self, hash: H256) -> DalResult<Option<Transaction>> {
         sqlx::query_as!(
             StorageTransaction,
@@ -2174,7 +2198,10 @@ mod tests {
 
     use super::*;
     use crate::{
-        tests::{create_l2_block_header, mock_execution_result, mock_l2_transaction},
+        tests::{
+            create_l1_batch_header, create_l2_block_header, mock_execution_result,
+            mock_l2_transaction,
+        },
         ConnectionPool, Core, CoreDal,
     };
 
@@ -2255,4 +2282,66 @@ mod tests {
             .unwrap();
         assert_eq!(tx_from_db[0].hash, tx_hash);
     }
+
+    #[tokio::test]
+    async fn getting_l1_batch_for_transaction() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+
+        let tx = mock_l2_transaction();
+        let tx_hash = tx.hash();
+        conn.transactions_dal()
+            .insert_transaction_l2(&tx, TransactionExecutionMetrics::default())
+            .await
+            .unwrap();
+        let tx_result = mock_execution_result(tx);
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &[tx_result.clone()],
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+
+        let l1_batch_number = conn
+            .transactions_dal()
+            .get_l1_batch_for_tx(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(l1_batch_number, None);
+
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l1_batch(L1BatchNumber(1), &[tx_result])
+            .await
+            .unwrap();
+
+        let l1_batch_number = conn
+            .transactions_dal()
+            .get_l1_batch_for_tx(tx_hash)
+            .await
+            .unwrap();
+        assert_eq!(l1_batch_number, Some(L1BatchNumber(1)));
+
+        let unknown_l1_batch_number = conn
+            .transactions_dal()
+            .get_l1_batch_for_tx(H256::repeat_byte(0xff))
+            .await
+            .unwrap();
+        assert_eq!(unknown_l1_batch_number, None);
+    }
 }
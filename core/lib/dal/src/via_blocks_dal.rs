@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use bitcoin::Txid;
+use sqlx::types::chrono::NaiveDateTime;
 use zksync_db_connection::{
     connection::Connection,
     error::DalResult,
@@ -11,6 +15,39 @@ use zksync_types::{
 pub use crate::models::storage_block::{L1BatchMetadataError, L1BatchWithOptionalMetadata};
 use crate::{models::storage_btc_block::ViaBtcStorageL1BlockDetails, Core};
 
+/// Timestamps of the stages an L1 batch goes through until its proof is confirmed on Bitcoin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViaBatchLifecycle {
+    pub l1_batch_number: L1BatchNumber,
+    pub sealed_at: NaiveDateTime,
+    pub commit_confirmed_at: Option<NaiveDateTime>,
+    pub proof_confirmed_at: Option<NaiveDateTime>,
+}
+
+impl ViaBatchLifecycle {
+    /// Time between sealing the batch and the confirmation of its commit inscription.
+    pub fn time_to_commit(&self) -> Option<chrono::Duration> {
+        self.commit_confirmed_at.map(|at| at - self.sealed_at)
+    }
+
+    /// Time between the confirmation of the commit and the proof inscriptions.
+    pub fn time_to_prove(&self) -> Option<chrono::Duration> {
+        Some(self.proof_confirmed_at? - self.commit_confirmed_at?)
+    }
+}
+
+/// Inscription requested for an L1 batch together with its most relevant send attempt: the confirmed
+/// one if any, otherwise the latest one. Send attempt fields are `None` if the inscription was never sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViaBatchInscription {
+    pub request_id: i64,
+    pub request_type: ViaBtcInscriptionRequestType,
+    pub commit_tx_id: Option<Txid>,
+    pub reveal_tx_id: Option<Txid>,
+    pub sent_at_block: Option<u64>,
+    pub confirmed_at: Option<NaiveDateTime>,
+}
+
 #[derive(Debug)]
 pub struct ViaBlocksDal<'a, 'c> {
     pub(crate) storage: &'a mut Connection<'c, Core>,
@@ -207,4 +244,423 @@ impl ViaBlocksDal<'_, '_> {
 
         Ok(batches.into_iter().map(|details| details.into()).collect())
     }
+
+    /// Returns the L1 batch proven by the given proof inscription request.
+    pub async fn get_l1_batch_number_by_proof_inscription_id(
+        &mut self,
+        inscription_request_id: i64,
+    ) -> DalResult<Option<L1BatchNumber>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l1_batch_number
+            FROM
+                via_l1_batch_inscription_request
+            WHERE
+                commit_proof_inscription_id = $1
+            "#,
+            inscription_request_id,
+        )
+        .instrument("get_l1_batch_number_by_proof_inscription_id")
+        .with_arg("inscription_request_id", &inscription_request_id)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| L1BatchNumber(row.l1_batch_number as u32)))
+    }
+
+    pub async fn get_batch_lifecycle_timings(
+        &mut self,
+        batch_number: L1BatchNumber,
+    ) -> DalResult<Option<ViaBatchLifecycle>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                l1_batches.number,
+                l1_batches.created_at AS sealed_at,
+                commit_history.confirmed_at AS "commit_confirmed_at?",
+                proof_history.confirmed_at AS "proof_confirmed_at?"
+            FROM
+                l1_batches
+                LEFT JOIN via_l1_batch_inscription_request ON via_l1_batch_inscription_request.l1_batch_number = l1_batches.number
+                LEFT JOIN via_btc_inscriptions_request AS commit_request ON commit_request.id = via_l1_batch_inscription_request.commit_l1_batch_inscription_id
+                LEFT JOIN via_btc_inscriptions_request_history AS commit_history ON commit_history.id = commit_request.confirmed_inscriptions_request_history_id
+                LEFT JOIN via_btc_inscriptions_request AS proof_request ON proof_request.id = via_l1_batch_inscription_request.commit_proof_inscription_id
+                LEFT JOIN via_btc_inscriptions_request_history AS proof_history ON proof_history.id = proof_request.confirmed_inscriptions_request_history_id
+            WHERE
+                l1_batches.number = $1
+            "#,
+            i64::from(batch_number.0),
+        )
+        .instrument("get_batch_lifecycle_timings")
+        .with_arg("batch_number", &batch_number)
+        .fetch_optional(self.storage)
+        .await?;
+
+        Ok(row.map(|row| ViaBatchLifecycle {
+            l1_batch_number: L1BatchNumber(row.number as u32),
+            sealed_at: row.sealed_at,
+            commit_confirmed_at: row.commit_confirmed_at,
+            proof_confirmed_at: row.proof_confirmed_at,
+        }))
+    }
+
+    /// Returns the commit and (if requested) proof inscriptions of the given L1 batch, commit first.
+    pub async fn get_batch_inscriptions(
+        &mut self,
+        batch_number: L1BatchNumber,
+    ) -> DalResult<Vec<ViaBatchInscription>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                via_btc_inscriptions_request.id,
+                via_btc_inscriptions_request.request_type,
+                history.commit_tx_id AS "commit_tx_id?",
+                history.reveal_tx_id AS "reveal_tx_id?",
+                history.sent_at_block AS "sent_at_block?",
+                history.confirmed_at AS "confirmed_at?"
+            FROM
+                via_l1_batch_inscription_request
+                JOIN via_btc_inscriptions_request ON via_btc_inscriptions_request.id IN (
+                    via_l1_batch_inscription_request.commit_l1_batch_inscription_id,
+                    via_l1_batch_inscription_request.commit_proof_inscription_id
+                )
+                LEFT JOIN LATERAL (
+                    SELECT
+                        commit_tx_id,
+                        reveal_tx_id,
+                        sent_at_block,
+                        confirmed_at
+                    FROM
+                        via_btc_inscriptions_request_history
+                    WHERE
+                        inscription_request_id = via_btc_inscriptions_request.id
+                    ORDER BY
+                        id = via_btc_inscriptions_request.confirmed_inscriptions_request_history_id DESC,
+                        created_at DESC,
+                        id DESC
+                    LIMIT
+                        1
+                ) AS history ON TRUE
+            WHERE
+                via_l1_batch_inscription_request.l1_batch_number = $1
+            ORDER BY
+                via_btc_inscriptions_request.id
+            "#,
+            i64::from(batch_number.0),
+        )
+        .instrument("get_batch_inscriptions")
+        .with_arg("batch_number", &batch_number)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ViaBatchInscription {
+                request_id: row.id,
+                request_type: row.request_type.into(),
+                commit_tx_id: row.commit_tx_id.map(|txid| Txid::from_str(&txid).unwrap()),
+                reveal_tx_id: row.reveal_tx_id.map(|txid| Txid::from_str(&txid).unwrap()),
+                sent_at_block: row.sent_at_block.map(|block| block as u64),
+                confirmed_at: row.confirmed_at,
+            })
+            .collect())
+    }
+
+    /// Returns batches whose commit inscription is confirmed on Bitcoin, but which have no proof
+    /// inscription yet.
+    pub async fn list_committed_not_proven(&mut self, limit: i64) -> DalResult<Vec<L1BatchNumber>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                via_l1_batch_inscription_request.l1_batch_number
+            FROM
+                via_l1_batch_inscription_request
+                JOIN via_btc_inscriptions_request ON via_btc_inscriptions_request.id = via_l1_batch_inscription_request.commit_l1_batch_inscription_id
+            WHERE
+                via_btc_inscriptions_request.confirmed_inscriptions_request_history_id IS NOT NULL
+                AND via_l1_batch_inscription_request.commit_proof_inscription_id IS NULL
+            ORDER BY
+                via_l1_batch_inscription_request.l1_batch_number
+            LIMIT
+                $1
+            "#,
+            limit,
+        )
+        .instrument("list_committed_not_proven")
+        .with_arg("limit", &limit)
+        .fetch_all(self.storage)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| L1BatchNumber(row.l1_batch_number as u32))
+            .collect())
+    }
+
+    pub async fn count_committed_not_proven(&mut self) -> DalResult<u64> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT
+                COUNT(*) AS "count!"
+            FROM
+                via_l1_batch_inscription_request
+                JOIN via_btc_inscriptions_request ON via_btc_inscriptions_request.id = via_l1_batch_inscription_request.commit_l1_batch_inscription_id
+            WHERE
+                via_btc_inscriptions_request.confirmed_inscriptions_request_history_id IS NOT NULL
+                AND via_l1_batch_inscription_request.commit_proof_inscription_id IS NULL
+            "#
+        )
+        .instrument("count_committed_not_proven")
+        .fetch_one(self.storage)
+        .await?;
+
+        Ok(count as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash;
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, Core, CoreDal};
+
+    async fn confirm_new_inscription(
+        conn: &mut Connection<'_, Core>,
+        batch_number: L1BatchNumber,
+        request_type: ViaBtcInscriptionRequestType,
+        seed: u8,
+    ) -> i64 {
+        let request = conn
+            .btc_sender_dal()
+            .via_save_btc_inscriptions_request(request_type, vec![], 0)
+            .await
+            .unwrap();
+        conn.via_blocks_dal()
+            .insert_l1_batch_inscription_request_id(batch_number, request.id, request_type)
+            .await
+            .unwrap();
+        let history_id = conn
+            .btc_sender_dal()
+            .insert_inscription_request_history(
+                Txid::from_byte_array([seed; 32]),
+                Txid::from_byte_array([seed + 1; 32]),
+                request.id,
+                vec![],
+                vec![],
+                0,
+                0,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        conn.btc_sender_dal()
+            .confirm_inscription(request.id, history_id.into())
+            .await
+            .unwrap();
+        history_id.into()
+    }
+
+    async fn set_l1_batch_sealed_at(
+        conn: &mut Connection<'_, Core>,
+        batch_number: L1BatchNumber,
+        sealed_at: NaiveDateTime,
+    ) {
+        sqlx::query("UPDATE l1_batches SET created_at = $1 WHERE number = $2")
+            .bind(sealed_at)
+            .bind(i64::from(batch_number.0))
+            .execute(conn.conn())
+            .await
+            .unwrap();
+    }
+
+    async fn set_inscription_confirmed_at(
+        conn: &mut Connection<'_, Core>,
+        history_id: i64,
+        confirmed_at: NaiveDateTime,
+    ) {
+        sqlx::query(
+            "UPDATE via_btc_inscriptions_request_history SET confirmed_at = $1 WHERE id = $2",
+        )
+        .bind(confirmed_at)
+        .bind(history_id)
+        .execute(conn.conn())
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn getting_batch_lifecycle_timings() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let batch_number = L1BatchNumber(1);
+        assert!(conn
+            .via_blocks_dal()
+            .get_batch_lifecycle_timings(batch_number)
+            .await
+            .unwrap()
+            .is_none());
+
+        let sealed_at = chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let commit_confirmed_at = sealed_at + chrono::Duration::seconds(90);
+        let proof_confirmed_at = commit_confirmed_at + chrono::Duration::minutes(10);
+
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+        set_l1_batch_sealed_at(&mut conn, batch_number, sealed_at).await;
+        let lifecycle = conn
+            .via_blocks_dal()
+            .get_batch_lifecycle_timings(batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            lifecycle,
+            ViaBatchLifecycle {
+                l1_batch_number: batch_number,
+                sealed_at,
+                commit_confirmed_at: None,
+                proof_confirmed_at: None,
+            }
+        );
+        assert_eq!(lifecycle.time_to_commit(), None);
+
+        let commit_history_id = confirm_new_inscription(
+            &mut conn,
+            batch_number,
+            ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+            1,
+        )
+        .await;
+        set_inscription_confirmed_at(&mut conn, commit_history_id, commit_confirmed_at).await;
+        let lifecycle = conn
+            .via_blocks_dal()
+            .get_batch_lifecycle_timings(batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(lifecycle.commit_confirmed_at, Some(commit_confirmed_at));
+        assert_eq!(
+            lifecycle.time_to_commit(),
+            Some(chrono::Duration::seconds(90))
+        );
+        assert_eq!(lifecycle.proof_confirmed_at, None);
+        assert_eq!(lifecycle.time_to_prove(), None);
+
+        let proof_history_id = confirm_new_inscription(
+            &mut conn,
+            batch_number,
+            ViaBtcInscriptionRequestType::CommitProofOnchain,
+            3,
+        )
+        .await;
+        set_inscription_confirmed_at(&mut conn, proof_history_id, proof_confirmed_at).await;
+        let lifecycle = conn
+            .via_blocks_dal()
+            .get_batch_lifecycle_timings(batch_number)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            lifecycle,
+            ViaBatchLifecycle {
+                l1_batch_number: batch_number,
+                sealed_at,
+                commit_confirmed_at: Some(commit_confirmed_at),
+                proof_confirmed_at: Some(proof_confirmed_at),
+            }
+        );
+        assert_eq!(
+            lifecycle.time_to_commit(),
+            Some(chrono::Duration::seconds(90))
+        );
+        assert_eq!(
+            lifecycle.time_to_prove(),
+            Some(chrono::Duration::minutes(10))
+        );
+
+        let inscriptions = conn
+            .via_blocks_dal()
+            .get_batch_inscriptions(batch_number)
+            .await
+            .unwrap();
+        let [commit_inscription, proof_inscription] = inscriptions.as_slice() else {
+            panic!("unexpected inscriptions: {inscriptions:?}");
+        };
+        let proven_batch = conn
+            .via_blocks_dal()
+            .get_l1_batch_number_by_proof_inscription_id(proof_inscription.request_id)
+            .await
+            .unwrap();
+        assert_eq!(proven_batch, Some(batch_number));
+        let proven_batch = conn
+            .via_blocks_dal()
+            .get_l1_batch_number_by_proof_inscription_id(commit_inscription.request_id)
+            .await
+            .unwrap();
+        assert_eq!(proven_batch, None);
+    }
+
+    #[tokio::test]
+    async fn listing_committed_not_proven_batches() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        for number in 1..=3 {
+            conn.blocks_dal()
+                .insert_mock_l1_batch(&create_l1_batch_header(number))
+                .await
+                .unwrap();
+            confirm_new_inscription(
+                &mut conn,
+                L1BatchNumber(number),
+                ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+                number as u8 * 10,
+            )
+            .await;
+        }
+        confirm_new_inscription(
+            &mut conn,
+            L1BatchNumber(1),
+            ViaBtcInscriptionRequestType::CommitProofOnchain,
+            100,
+        )
+        .await;
+
+        let batches = conn
+            .via_blocks_dal()
+            .list_committed_not_proven(10)
+            .await
+            .unwrap();
+        assert_eq!(batches, [L1BatchNumber(2), L1BatchNumber(3)]);
+        let batches = conn
+            .via_blocks_dal()
+            .list_committed_not_proven(1)
+            .await
+            .unwrap();
+        assert_eq!(batches, [L1BatchNumber(2)]);
+
+        let count = conn
+            .via_blocks_dal()
+            .count_committed_not_proven()
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+    }
 }
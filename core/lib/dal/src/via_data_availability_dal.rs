@@ -311,6 +311,38 @@ impl ViaDataAvailabilityDal<'_, '_> {
         .map(DataAvailabilityBlob::from))
     }
 
+    /// Returns the DA blob dispatched for the given L1 batch, if any. `is_proof` selects
+    /// between the batch pubdata blob and the proof blob.
+    pub async fn get_da_blob(
+        &mut self,
+        number: L1BatchNumber,
+        is_proof: bool,
+    ) -> DalResult<Option<DataAvailabilityBlob>> {
+        Ok(sqlx::query_as!(
+            StorageDABlob,
+            r#"
+            SELECT
+                l1_batch_number,
+                blob_id,
+                inclusion_data,
+                sent_at
+            FROM
+                via_data_availability
+            WHERE
+                l1_batch_number = $1
+                AND is_proof = $2
+            "#,
+            i64::from(number.0),
+            is_proof,
+        )
+        .instrument("get_da_blob")
+        .with_arg("number", &number)
+        .with_arg("is_proof", &is_proof)
+        .fetch_optional(self.storage)
+        .await?
+        .map(DataAvailabilityBlob::from))
+    }
+
     /// Fetches the pubdata and `l1_batch_number` for the L1 batches that are ready for DA dispatch.
     /// This method handles the non-proof data (is_proof = FALSE).
     pub async fn get_ready_for_da_dispatch_l1_batches(
@@ -454,3 +486,74 @@ impl ViaDataAvailabilityDal<'_, '_> {
             .collect())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::ProtocolVersion;
+
+    use super::*;
+    use crate::{tests::create_l1_batch_header, ConnectionPool, CoreDal};
+
+    #[tokio::test]
+    async fn getting_da_blob_for_l1_batch() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+        conn.blocks_dal()
+            .insert_mock_l1_batch(&create_l1_batch_header(1))
+            .await
+            .unwrap();
+
+        let number = L1BatchNumber(1);
+        let blob = conn
+            .via_data_availability_dal()
+            .get_da_blob(number, false)
+            .await
+            .unwrap();
+        assert!(blob.is_none());
+
+        let sent_at = chrono::NaiveDateTime::from_timestamp_opt(1_700_000_000, 0).unwrap();
+        conn.via_data_availability_dal()
+            .insert_l1_batch_da(number, "blob_1", sent_at)
+            .await
+            .unwrap();
+        let blob = conn
+            .via_data_availability_dal()
+            .get_da_blob(number, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(blob.l1_batch_number, number);
+        assert_eq!(blob.blob_id, "blob_1");
+        assert_eq!(blob.sent_at, sent_at.and_utc());
+        assert_eq!(blob.inclusion_data, None);
+
+        conn.via_data_availability_dal()
+            .save_l1_batch_inclusion_data(number, &[1, 2, 3])
+            .await
+            .unwrap();
+        let blob = conn
+            .via_data_availability_dal()
+            .get_da_blob(number, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(blob.inclusion_data, Some(vec![1, 2, 3]));
+
+        let proof_blob = conn
+            .via_data_availability_dal()
+            .get_da_blob(number, true)
+            .await
+            .unwrap();
+        assert!(proof_blob.is_none());
+        let blob = conn
+            .via_data_availability_dal()
+            .get_da_blob(L1BatchNumber(2), false)
+            .await
+            .unwrap();
+        assert!(blob.is_none());
+    }
+}
@@ -1,10 +1,22 @@
+use bigdecimal::ToPrimitive;
 use sqlx::types::chrono::NaiveDateTime;
-use zksync_db_connection::{connection::Connection, error::DalResult, instrument::InstrumentExt};
+use zksync_db_connection::{
+    connection::Connection,
+    error::DalResult,
+    instrument::{InstrumentExt, Instrumented},
+};
 use zksync_types::{l1::L1Tx, Address, L1BlockNumber, PriorityOpId, H256};
 use zksync_utils::u256_to_big_decimal;
 
 use crate::Core;
 
+/// Amount (in satoshis) and number of bridge operations over a range of Bitcoin blocks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BridgeFlowSummary {
+    pub total_sats: u64,
+    pub count: u64,
+}
+
 #[derive(Debug)]
 pub struct ViaTransactionsDal<'c, 'a> {
     pub(crate) storage: &'c mut Connection<'a, Core>,
@@ -189,4 +201,203 @@ impl ViaTransactionsDal<'_, '_> {
 
         Ok(maybe_row.is_some())
     }
+
+    /// Sums the deposits observed in Bitcoin blocks `from..=to`. Only deposits that were already
+    /// included in an L2 block are counted.
+    pub async fn sum_deposits_in_range(
+        &mut self,
+        from: L1BlockNumber,
+        to: L1BlockNumber,
+    ) -> DalResult<BridgeFlowSummary> {
+        let instrumentation = Instrumented::new("sum_deposits_in_range")
+            .with_arg("from", &from)
+            .with_arg("to", &to);
+        let query = sqlx::query!(
+            r#"
+            SELECT
+                COALESCE(SUM(l1_tx_mint), 0) AS "total!",
+                COUNT(*) AS "count!"
+            FROM
+                transactions
+            WHERE
+                is_priority = TRUE
+                AND l1_block_number BETWEEN $1 AND $2
+                AND miniblock_number IS NOT NULL
+            "#,
+            from.0 as i32,
+            to.0 as i32,
+        );
+        let row = instrumentation
+            .clone()
+            .with(query)
+            .fetch_one(self.storage)
+            .await?;
+
+        let total_sats = row.total.to_u64().ok_or_else(|| {
+            instrumentation.constraint_error(anyhow::anyhow!(
+                "sum of deposited amounts {} doesn't fit into u64",
+                row.total
+            ))
+        })?;
+        Ok(BridgeFlowSummary {
+            total_sats,
+            count: row.count as u64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::{L2BlockNumber, ProtocolVersion, ProtocolVersionId, U256};
+    use zksync_vm_interface::{TransactionExecutionResult, TxExecutionStatus, VmExecutionMetrics};
+
+    use super::*;
+    use crate::{
+        tests::{create_l2_block_header, mock_l1_execute},
+        ConnectionPool, Core, CoreDal,
+    };
+
+    fn mock_deposit(serial_id: u64, amount: u64) -> L1Tx {
+        let mut tx = mock_l1_execute();
+        tx.common_data.serial_id = PriorityOpId(serial_id);
+        tx.common_data.canonical_tx_hash = H256::from_low_u64_be(serial_id);
+        tx.common_data.to_mint = U256::from(amount);
+        tx
+    }
+
+    fn mock_l1_execution_result(tx: L1Tx) -> TransactionExecutionResult {
+        TransactionExecutionResult {
+            hash: tx.hash(),
+            transaction: tx.into(),
+            execution_info: VmExecutionMetrics::default(),
+            execution_status: TxExecutionStatus::Success,
+            refunded_gas: 0,
+            operator_suggested_refund: 0,
+            compressed_bytecodes: vec![],
+            call_traces: vec![],
+            revert_reason: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn summing_deposits_in_range() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let deposits = [
+            (mock_deposit(0, 1_000), L1BlockNumber(10)),
+            (mock_deposit(1, 2_000), L1BlockNumber(20)),
+            (mock_deposit(2, 4_000), L1BlockNumber(30)),
+        ];
+        for (tx, block) in &deposits {
+            conn.via_transactions_dal()
+                .insert_transaction_l1(tx, *block, H256::random())
+                .await
+                .unwrap();
+        }
+
+        // Only the first two deposits are included in an L2 block.
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+        let executed: Vec<_> = deposits[..2]
+            .iter()
+            .map(|(tx, _)| mock_l1_execution_result(tx.clone()))
+            .collect();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &executed,
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+
+        let summary = conn
+            .via_transactions_dal()
+            .sum_deposits_in_range(L1BlockNumber(0), L1BlockNumber(100))
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            BridgeFlowSummary {
+                total_sats: 3_000,
+                count: 2,
+            }
+        );
+
+        let summary = conn
+            .via_transactions_dal()
+            .sum_deposits_in_range(L1BlockNumber(15), L1BlockNumber(30))
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            BridgeFlowSummary {
+                total_sats: 2_000,
+                count: 1,
+            }
+        );
+
+        let summary = conn
+            .via_transactions_dal()
+            .sum_deposits_in_range(L1BlockNumber(31), L1BlockNumber(100))
+            .await
+            .unwrap();
+        assert_eq!(summary, BridgeFlowSummary::default());
+    }
+
+    #[tokio::test]
+    async fn summing_deposits_errors_on_overflow() {
+        let connection_pool = ConnectionPool::<Core>::test_pool().await;
+        let mut conn = connection_pool.connection().await.unwrap();
+        conn.protocol_versions_dal()
+            .save_protocol_version_with_tx(&ProtocolVersion::default())
+            .await
+            .unwrap();
+
+        let deposits = [mock_deposit(0, u64::MAX), mock_deposit(1, u64::MAX)];
+        for tx in &deposits {
+            conn.via_transactions_dal()
+                .insert_transaction_l1(tx, L1BlockNumber(10), H256::random())
+                .await
+                .unwrap();
+        }
+        conn.blocks_dal()
+            .insert_l2_block(&create_l2_block_header(1))
+            .await
+            .unwrap();
+        let executed: Vec<_> = deposits
+            .iter()
+            .map(|tx| mock_l1_execution_result(tx.clone()))
+            .collect();
+        conn.transactions_dal()
+            .mark_txs_as_executed_in_l2_block(
+                L2BlockNumber(1),
+                &executed,
+                1.into(),
+                ProtocolVersionId::latest(),
+                false,
+            )
+            .await
+            .unwrap();
+
+        let err = conn
+            .via_transactions_dal()
+            .sum_deposits_in_range(L1BlockNumber(0), L1BlockNumber(100))
+            .await
+            .unwrap_err()
+            .generalize();
+        assert!(
+            format!("{err:#}").contains("doesn't fit into u64"),
+            "{err:#}"
+        );
+    }
 }
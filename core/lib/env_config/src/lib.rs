@@ -20,8 +20,10 @@ mod observability;
 mod proof_data_handler;
 mod snapshots_creator;
 mod utils;
+mod via_btc_client;
 mod via_btc_sender;
 mod via_celestia;
+mod via_fee_model;
 
 mod base_token_adjuster;
 mod da_dispatcher;
@@ -0,0 +1,9 @@
+use zksync_config::ViaFeeModelConfig;
+
+use crate::{envy_load, FromEnv};
+
+impl FromEnv for ViaFeeModelConfig {
+    fn from_env() -> anyhow::Result<Self> {
+        envy_load("via_fee_model", "VIA_FEE_MODEL_")
+    }
+}
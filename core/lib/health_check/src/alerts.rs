@@ -0,0 +1,93 @@
+//! Alerting for critical conditions detected by node components (e.g., reorgs or inconsistencies with L1).
+//! Unlike health checks, alerts are pushed by components as conditions arise.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Severity of an [`Alert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    /// Informational condition not requiring immediate action.
+    Info,
+    /// Abnormal condition the component may recover from on its own.
+    Warning,
+    /// Condition requiring operator intervention.
+    Critical,
+}
+
+/// Structured alert emitted by a component.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Alert {
+    /// Name of the emitting component, e.g. `reorg_detector`.
+    pub component: &'static str,
+    pub severity: AlertSeverity,
+    /// Human-readable one-line summary.
+    pub summary: String,
+    /// Machine-readable details specific to the alert.
+    pub details: serde_json::Value,
+}
+
+impl Alert {
+    pub fn new(
+        component: &'static str,
+        severity: AlertSeverity,
+        summary: impl Into<String>,
+    ) -> Self {
+        Self {
+            component,
+            severity,
+            summary: summary.into(),
+            details: serde_json::Value::Null,
+        }
+    }
+
+    /// Attaches details to the alert. If the details cannot be serialized, the serialization error
+    /// is attached instead, so that the alert is still delivered.
+    pub fn with_details<T: Serialize>(mut self, details: T) -> Self {
+        self.details = serde_json::to_value(details)
+            .unwrap_or_else(|err| serde_json::json!({ "serialization_error": err.to_string() }));
+        self
+    }
+}
+
+/// Destination of alerts emitted by components.
+///
+/// Sending an alert must not fail from the point of view of the emitting component; implementations
+/// are responsible for handling (e.g., logging) their own errors.
+#[async_trait]
+pub trait AlertSink: fmt::Debug + Send + Sync + 'static {
+    async fn send(&self, alert: Alert);
+}
+
+/// Sink discarding all alerts. Used by components if no other sink is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopAlertSink;
+
+#[async_trait]
+impl AlertSink for NoopAlertSink {
+    async fn send(&self, _alert: Alert) {}
+}
+
+/// Sink writing alerts to the log with the level corresponding to their severity.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogAlertSink;
+
+#[async_trait]
+impl AlertSink for LogAlertSink {
+    async fn send(&self, alert: Alert) {
+        let Alert {
+            component,
+            severity,
+            summary,
+            details,
+        } = &alert;
+        match severity {
+            AlertSeverity::Info => tracing::info!(component, %details, "Alert: {summary}"),
+            AlertSeverity::Warning => tracing::warn!(component, %details, "Alert: {summary}"),
+            AlertSeverity::Critical => tracing::error!(component, %details, "Alert: {summary}"),
+        }
+    }
+}
@@ -15,6 +15,7 @@ use tokio::sync::watch;
 use self::metrics::{CheckResult, METRICS};
 use crate::metrics::AppHealthCheckConfig;
 
+pub mod alerts;
 mod metrics;
 #[cfg(test)]
 mod tests;
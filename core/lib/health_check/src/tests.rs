@@ -152,3 +152,15 @@ fn adding_duplicate_component() {
         .unwrap_err();
     assert_matches!(err, AppHealthCheckError::RedefinedComponent("test"));
 }
+
+#[test]
+fn alert_with_unserializable_details() {
+    use std::collections::HashMap;
+
+    use self::alerts::{Alert, AlertSeverity};
+
+    // JSON maps only support string keys, so these details cannot be serialized.
+    let details = HashMap::from([((1_u8, 2_u8), 3_u8)]);
+    let alert = Alert::new("test", AlertSeverity::Warning, "test alert").with_details(details);
+    assert!(alert.details["serialization_error"].is_string());
+}
@@ -81,6 +81,7 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            metrics_enabled: self.metrics_enabled,
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -119,6 +120,7 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            metrics_enabled: this.metrics_enabled,
         }
     }
 }
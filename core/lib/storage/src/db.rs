@@ -479,6 +479,24 @@ impl<CF: NamedColumnFamily> RocksDB<CF> {
             .unwrap_or(0)
     }
 
+    /// Returns the number of SST files at the specified LSM tree `level` of a column family.
+    pub fn num_files_at_level(&self, cf: CF, level: usize) -> Option<u64> {
+        let cf = self.column_family(cf);
+        self.inner
+            .int_property(cf, &properties::num_files_at_level(level))
+    }
+
+    /// Compacts all column families in the DB, flushing memtables and dropping tombstones
+    /// for deleted keys. This is a blocking operation that may take a long time for large DBs.
+    pub fn compact(&self) {
+        for &cf in CF::ALL {
+            let cf = self.column_family(cf);
+            self.inner
+                .db
+                .compact_range_cf(cf, None::<&[u8]>, None::<&[u8]>);
+        }
+    }
+
     pub fn multi_get<K, I>(&self, keys: I) -> Vec<Result<Option<Vec<u8>>, rocksdb::Error>>
     where
         K: AsRef<[u8]>,
@@ -1007,6 +1025,33 @@ mod tests {
         assert_eq!(value.unwrap(), b"value");
     }
 
+    #[test]
+    fn compacting_db_drops_deleted_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDB::<NewColumnFamilies>::new(temp_dir.path())
+            .unwrap()
+            .with_sync_writes();
+        let mut batch = db.new_write_batch();
+        for i in 0_u32..100 {
+            batch.put_cf(NewColumnFamilies::Other, &i.to_be_bytes(), b"value");
+        }
+        db.write(batch).unwrap();
+        let mut batch = db.new_write_batch();
+        batch.delete_range_cf(
+            NewColumnFamilies::Other,
+            &0_u32.to_be_bytes()..&100_u32.to_be_bytes(),
+        );
+        db.write(batch).unwrap();
+
+        db.compact();
+
+        let num_level0_files = db.num_files_at_level(NewColumnFamilies::Other, 0);
+        assert_eq!(num_level0_files, Some(0));
+        assert_eq!(db.estimated_number_of_entries(NewColumnFamilies::Other), 0);
+        let value = db.get_cf(NewColumnFamilies::Other, &0_u32.to_be_bytes());
+        assert!(value.unwrap().is_none());
+    }
+
     #[test]
     fn write_batch_can_be_restored_from_bytes() {
         let temp_dir = TempDir::new().unwrap();
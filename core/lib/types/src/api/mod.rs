@@ -796,6 +796,39 @@ pub struct TeeProof {
     pub attestation: Option<Vec<u8>>,
 }
 
+/// Status of an L1 batch inscription on Bitcoin. Statuses other than `NotBroadcast` are based on
+/// the reveal transaction as seen by the Bitcoin node at the time of the request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViaInscriptionStatus {
+    /// The inscription was requested, but its transactions were not broadcast yet.
+    NotBroadcast,
+    /// The reveal transaction is in the node mempool.
+    InMempool,
+    /// The reveal transaction is included in a block and has the specified number of confirmations.
+    Confirmed(u32),
+    /// The inscription was broadcast, but its live status is unknown. Either the node doesn't know
+    /// the reveal transaction (e.g., it was evicted from the mempool or never relayed), or the node
+    /// couldn't be queried, or the API server has no Bitcoin client. `confirmed_at` still reports
+    /// whether the BTC sender has confirmed the inscription.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViaBatchInscriptionStatus {
+    pub l1_batch_number: L1BatchNumber,
+    /// Inscription type, e.g. `CommitL1BatchOnchain` or `CommitProofOnchain`.
+    pub inscription_type: String,
+    pub status: ViaInscriptionStatus,
+    pub commit_tx_id: Option<bitcoin::Txid>,
+    pub reveal_tx_id: Option<bitcoin::Txid>,
+    /// Bitcoin block height at the time the inscription was broadcast.
+    pub sent_at_block: Option<u64>,
+    /// Time at which the BTC sender confirmed the inscription.
+    pub confirmed_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionDetailedResult {
@@ -2,17 +2,28 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use bitcoin::{Address, Block, BlockHash, Network, OutPoint, Transaction, TxOut, Txid};
-use bitcoincore_rpc::json::EstimateMode;
+use bitcoincore_rpc::{json::EstimateMode, RawTx};
 use tracing::{debug, error, instrument};
+use zksync_config::ViaBtcClientConfig;
 
 mod rpc_client;
 
 use crate::{
     client::rpc_client::BitcoinRpcClient,
     traits::{BitcoinOps, BitcoinRpc},
-    types::{BitcoinClientResult, BitcoinError, BitcoinNetwork, NodeAuth},
+    types::{BitcoinClientResult, BitcoinError, BitcoinNetwork, BroadcastError, NodeAuth},
 };
 
+/// `RPC_VERIFY_ALREADY_IN_CHAIN` from Bitcoin Core.
+const RPC_VERIFY_ALREADY_IN_CHAIN: i32 = -27;
+
+/// Whether a `sendrawtransaction` rejection means the node already has the transaction.
+fn is_already_known_rejection(code: i32, reason: &str) -> bool {
+    code == RPC_VERIFY_ALREADY_IN_CHAIN
+        || reason.contains("txn-already-in-mempool")
+        || reason.contains("txn-already-known")
+}
+
 pub struct BitcoinClient {
     rpc: Arc<dyn BitcoinRpc>,
     network: BitcoinNetwork,
@@ -24,8 +35,21 @@ impl BitcoinClient {
     where
         Self: Sized,
     {
+        Self::new_with_config(rpc_url, network, auth, &ViaBtcClientConfig::default())
+    }
+
+    /// Creates a client whose RPC requests fail with [`BitcoinError::RpcTimeout`] if the node doesn't
+    /// respond within `config.rpc_timeout()`. The timeout is enforced by the HTTP transport, so it
+    /// applies to each request (including each retry) rather than to a whole client call.
+    #[instrument(skip(auth, config), target = "bitcoin_client")]
+    pub fn new_with_config(
+        rpc_url: &str,
+        network: BitcoinNetwork,
+        auth: NodeAuth,
+        config: &ViaBtcClientConfig,
+    ) -> BitcoinClientResult<Self> {
         debug!("Creating new BitcoinClient");
-        let rpc = BitcoinRpcClient::new(rpc_url, auth)?;
+        let rpc = BitcoinRpcClient::new(rpc_url, auth, config.rpc_timeout())?;
         Ok(Self {
             rpc: Arc::new(rpc),
             network,
@@ -60,6 +84,24 @@ impl BitcoinOps for BitcoinClient {
         Ok(txid)
     }
 
+    #[instrument(skip(self, tx), target = "bitcoin_client")]
+    async fn send_raw_transaction(&self, tx: &Transaction) -> Result<Txid, BroadcastError> {
+        let txid = tx.compute_txid();
+        match self.broadcast_signed_transaction(&tx.raw_hex()).await {
+            Ok(txid) => Ok(txid),
+            Err(BitcoinError::TransactionRejected(code, reason))
+                if is_already_known_rejection(code, &reason) =>
+            {
+                debug!("Transaction {txid} is already known to the node: {reason}");
+                Ok(txid)
+            }
+            Err(BitcoinError::TransactionRejected(code, reason)) => {
+                Err(BroadcastError::Rejected { code, reason })
+            }
+            Err(err) => Err(BroadcastError::Client(err)),
+        }
+    }
+
     // The address should be imported to the node
     // bitcoin-cli createwallet "watch-only" true
     // bitcoin-cli getdescriptorinfo "addr(p2wpkh address)"
@@ -86,6 +128,35 @@ impl BitcoinOps for BitcoinClient {
         Ok(utxos)
     }
 
+    #[instrument(skip(self), target = "bitcoin_client")]
+    async fn fetch_utxos_with_confirmations(
+        &self,
+        address: &Address,
+    ) -> BitcoinClientResult<Vec<((OutPoint, TxOut), u32)>> {
+        debug!("Fetching UTXOs with confirmations");
+        let outpoints = match self.network {
+            Network::Regtest => self.rpc.list_unspent(address).await?,
+            _ => self.rpc.list_unspent_based_on_node_wallet(address).await?,
+        };
+        let mut utxos = Vec::with_capacity(outpoints.len());
+
+        for outpoint in outpoints {
+            debug!("Fetching transaction info for outpoint");
+            let tx_info = self.rpc.get_raw_transaction_info(&outpoint.txid).await?;
+            let tx = tx_info
+                .transaction()
+                .map_err(|e| BitcoinError::InvalidTransaction(e.to_string()))?;
+            let txout = tx.output.get(outpoint.vout as usize).ok_or_else(|| {
+                error!("Invalid outpoint");
+                BitcoinError::InvalidOutpoint(outpoint.to_string())
+            })?;
+            let confirmations = tx_info.confirmations.unwrap_or(0);
+            utxos.push(((outpoint, txout.clone()), confirmations));
+        }
+
+        Ok(utxos)
+    }
+
     #[instrument(skip(self), target = "bitcoin_client")]
     async fn check_tx_confirmation(&self, txid: &Txid, conf_num: u32) -> BitcoinClientResult<bool> {
         debug!("Checking transaction confirmation");
@@ -97,6 +168,32 @@ impl BitcoinOps for BitcoinClient {
         }
     }
 
+    #[instrument(skip(self), target = "bitcoin_client")]
+    async fn fetch_tx_confirmations(&self, txid: &Txid) -> BitcoinClientResult<Option<u32>> {
+        debug!("Fetching transaction confirmations");
+        let tx_info = self.rpc.find_raw_transaction_info(txid).await?;
+        Ok(tx_info.map(|tx_info| tx_info.confirmations.unwrap_or(0)))
+    }
+
+    #[instrument(skip(self), target = "bitcoin_client")]
+    async fn fetch_node_network(&self) -> BitcoinClientResult<Network> {
+        debug!("Fetching node network");
+        let info = self.rpc.get_blockchain_info().await?;
+        Ok(info.chain)
+    }
+
+    #[instrument(skip(self), target = "bitcoin_client")]
+    async fn fetch_tip_time(&self) -> BitcoinClientResult<u64> {
+        debug!("Fetching chain tip time");
+        let tip_hash = self.rpc.get_best_block_hash().await?;
+        let tip = self
+            .rpc
+            .get_block_by_hash(&tip_hash)
+            .await?
+            .ok_or_else(|| BitcoinError::Rpc(format!("chain tip {tip_hash} is not found")))?;
+        Ok(tip.header.time.into())
+    }
+
     #[instrument(skip(self), target = "bitcoin_client")]
     async fn fetch_block_height(&self) -> BitcoinClientResult<u128> {
         debug!("Fetching block height");
@@ -152,7 +249,10 @@ impl BitcoinOps for BitcoinClient {
     }
 
     #[instrument(skip(self), target = "bitcoin_client")]
-    async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Block> {
+    async fn fetch_block_by_hash(
+        &self,
+        block_hash: &BlockHash,
+    ) -> BitcoinClientResult<Option<Block>> {
         debug!("Fetching block by hash");
         self.rpc.get_block_by_hash(block_hash).await
     }
@@ -169,7 +269,10 @@ impl Clone for BitcoinClient {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
+    use std::{
+        str::FromStr,
+        time::{Duration, Instant},
+    };
 
     use bitcoin::{absolute::LockTime, hashes::Hash, transaction::Version, Amount, Wtxid};
     use bitcoincore_rpc::{
@@ -193,9 +296,10 @@ mod tests {
             async fn get_transaction(&self, txid: &Txid) -> BitcoinClientResult<Transaction>;
             async fn get_block_count(&self) -> BitcoinClientResult<u64>;
             async fn get_block_by_height(&self, block_height: u128) -> BitcoinClientResult<Block>;
-            async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Block>;
+            async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Option<Block>>;
             async fn get_best_block_hash(&self) -> BitcoinClientResult<BlockHash>;
             async fn get_raw_transaction_info(&self, txid: &Txid) -> BitcoinClientResult<GetRawTransactionResult>;
+            async fn find_raw_transaction_info(&self, txid: &Txid) -> BitcoinClientResult<Option<GetRawTransactionResult>>;
             async fn estimate_smart_fee(&self, conf_target: u16, estimate_mode: Option<EstimateMode>) -> BitcoinClientResult<EstimateSmartFeeResult>;
             async fn get_blockchain_info(&self) -> BitcoinRpcResult<GetBlockchainInfoResult>;
         }
@@ -241,6 +345,41 @@ mod tests {
         assert_eq!(txid, expected_txid);
     }
 
+    #[tokio::test]
+    async fn test_send_raw_transaction_reports_reject_reason() {
+        let tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        };
+
+        let mut mock_rpc = MockBitcoinRpc::new();
+        mock_rpc.expect_send_raw_transaction().return_once(|_| {
+            Err(BitcoinError::TransactionRejected(
+                -26,
+                "min relay fee not met, 0 < 110".to_string(),
+            ))
+        });
+        let client = get_client_with_mock(mock_rpc);
+        let err = client.send_raw_transaction(&tx).await.unwrap_err();
+        assert!(matches!(
+            err,
+            BroadcastError::Rejected { code: -26, ref reason } if reason.starts_with("min relay fee not met")
+        ));
+
+        let mut mock_rpc = MockBitcoinRpc::new();
+        mock_rpc.expect_send_raw_transaction().return_once(|_| {
+            Err(BitcoinError::TransactionRejected(
+                RPC_VERIFY_ALREADY_IN_CHAIN,
+                "Transaction already in block chain".to_string(),
+            ))
+        });
+        let client = get_client_with_mock(mock_rpc);
+        let txid = client.send_raw_transaction(&tx).await.unwrap();
+        assert_eq!(txid, tx.compute_txid());
+    }
+
     #[tokio::test]
     async fn test_fetch_utxos() {
         let mut mock_rpc = MockBitcoinRpc::new();
@@ -305,6 +444,58 @@ mod tests {
         assert!(confirmed);
     }
 
+    #[tokio::test]
+    async fn test_fetch_tx_confirmations_of_unknown_tx() {
+        let mut mock_rpc = MockBitcoinRpc::new();
+        mock_rpc
+            .expect_find_raw_transaction_info()
+            .return_once(|_| Ok(None));
+
+        let client = get_client_with_mock(mock_rpc);
+
+        let confirmations = client
+            .fetch_tx_confirmations(&Txid::all_zeros())
+            .await
+            .unwrap();
+        assert_eq!(confirmations, None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_tx_confirmations_propagates_rpc_errors() {
+        let mut mock_rpc = MockBitcoinRpc::new();
+        mock_rpc
+            .expect_find_raw_transaction_info()
+            .return_once(|_| Err(BitcoinError::Rpc("connection refused".to_string())));
+
+        let client = get_client_with_mock(mock_rpc);
+
+        let err = client
+            .fetch_tx_confirmations(&Txid::all_zeros())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, BitcoinError::Rpc(_)), "{err:?}");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_tip_time() {
+        let tip = bitcoin::blockdata::constants::genesis_block(Network::Regtest);
+        let tip_hash = tip.block_hash();
+        let tip_time = tip.header.time;
+        let mut mock_rpc = MockBitcoinRpc::new();
+        mock_rpc
+            .expect_get_best_block_hash()
+            .return_once(move || Ok(tip_hash));
+        mock_rpc
+            .expect_get_block_by_hash()
+            .with(eq(tip_hash))
+            .return_once(move |_| Ok(Some(tip)));
+
+        let client = get_client_with_mock(mock_rpc);
+
+        let time = client.fetch_tip_time().await.unwrap();
+        assert_eq!(time, u64::from(tip_time));
+    }
+
     #[tokio::test]
     async fn test_fetch_block_height() {
         let mut mock_rpc = MockBitcoinRpc::new();
@@ -333,4 +524,85 @@ mod tests {
         // 1000 sat/kb = 1 sat/byte
         assert_eq!(fee_rate, 1);
     }
+
+    #[tokio::test]
+    async fn test_rpc_call_times_out_on_unresponsive_node() {
+        // The listener accepts connections, but never responds to requests.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let rpc_url = format!("http://{}", listener.local_addr().unwrap());
+        let config = ViaBtcClientConfig {
+            rpc_timeout_ms: 100,
+        };
+        let client = BitcoinClient::new_with_config(
+            &rpc_url,
+            BitcoinNetwork::Regtest,
+            NodeAuth::UserPass("user".to_string(), "password".to_string()),
+            &config,
+        )
+        .unwrap();
+
+        let started_at = Instant::now();
+        let err = client.fetch_block_height().await.unwrap_err();
+        assert!(matches!(err, BitcoinError::RpcTimeout(_)), "{err:?}");
+        // Each attempt must be bounded by the timeout; otherwise, the call would hang forever.
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+        drop(listener);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_utxos_with_confirmations() {
+        let mut mock_rpc = MockBitcoinRpc::new();
+        let tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::from_height(0u32).unwrap(),
+            input: vec![],
+            output: vec![TxOut {
+                value: Amount::from_sat(50000),
+                script_pubkey: Default::default(),
+            }],
+        };
+        let outpoint = OutPoint {
+            txid: tx.compute_txid(),
+            vout: 0,
+        };
+        mock_rpc
+            .expect_list_unspent_based_on_node_wallet()
+            .return_once(move |_| Ok(vec![outpoint]));
+        mock_rpc
+            .expect_get_raw_transaction_info()
+            .return_once(move |_| {
+                Ok(GetRawTransactionResult {
+                    in_active_chain: None,
+                    hex: bitcoin::consensus::encode::serialize(&tx),
+                    txid: outpoint.txid,
+                    hash: Wtxid::all_zeros(),
+                    size: 0,
+                    vsize: 0,
+                    version: 2,
+                    locktime: 0,
+                    vin: vec![],
+                    vout: vec![],
+                    blockhash: None,
+                    confirmations: Some(4),
+                    time: None,
+                    blocktime: None,
+                })
+            });
+
+        let client = get_client_with_mock(mock_rpc);
+
+        let address = Address::from_str("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .unwrap()
+            .require_network(BitcoinNetwork::Bitcoin)
+            .unwrap();
+        let utxos = client
+            .fetch_utxos_with_confirmations(&address)
+            .await
+            .unwrap();
+        assert_eq!(utxos.len(), 1);
+        let ((utxo_outpoint, txout), confirmations) = &utxos[0];
+        assert_eq!(*utxo_outpoint, outpoint);
+        assert_eq!(txout.value.to_sat(), 50000);
+        assert_eq!(*confirmations, 4);
+    }
 }
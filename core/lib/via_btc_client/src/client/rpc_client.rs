@@ -1,22 +1,26 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use async_trait::async_trait;
 use bitcoin::{Address, Block, BlockHash, OutPoint, Transaction, Txid};
 use bitcoincore_rpc::{
     bitcoincore_rpc_json::EstimateMode,
     json::{EstimateSmartFeeResult, GetBlockchainInfoResult, ScanTxOutRequest},
+    jsonrpc::{self, simple_http},
     Client, RpcApi,
 };
 use tracing::{debug, instrument};
 
 use crate::{
     traits::BitcoinRpc,
-    types::{BitcoinRpcResult, NodeAuth},
-    utils::with_retry,
+    types::{BitcoinError, BitcoinRpcResult, NodeAuth},
+    utils::{with_async_retry, with_retry},
 };
 
 const RPC_MAX_RETRIES: u8 = 3;
 const RPC_RETRY_DELAY_MS: u64 = 500;
+/// `RPC_INVALID_ADDRESS_OR_KEY` from Bitcoin Core; returned by `getblock` for unknown block hashes
+/// and by `getrawtransaction` for transactions unknown to the node.
+const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
 
 pub struct BitcoinRpcClient {
     client: Arc<Client>,
@@ -24,8 +28,22 @@ pub struct BitcoinRpcClient {
 
 impl BitcoinRpcClient {
     #[instrument(skip(auth), target = "bitcoin_client::rpc_client")]
-    pub fn new(url: &str, auth: NodeAuth) -> Result<Self, bitcoincore_rpc::Error> {
-        let client = Client::new(url, auth.clone())?;
+    pub fn new(
+        url: &str,
+        auth: NodeAuth,
+        timeout: Duration,
+    ) -> Result<Self, bitcoincore_rpc::Error> {
+        // Same as `Client::new()`, but with a custom timeout. RPC calls are blocking, so the timeout
+        // must be enforced by the transport; it cannot be imposed on the calling future.
+        let (user, pass) = auth.get_user_pass()?;
+        let mut transport = simple_http::Builder::new()
+            .timeout(timeout)
+            .url(url)
+            .map_err(|err| bitcoincore_rpc::Error::JsonRpc(err.into()))?;
+        if let Some(user) = user {
+            transport = transport.auth(user, pass);
+        }
+        let client = Client::from_jsonrpc(jsonrpc::Client::with_transport(transport.build()));
         Ok(Self {
             client: Arc::new(client),
         })
@@ -79,12 +97,25 @@ impl BitcoinRpc for BitcoinRpcClient {
 
     #[instrument(skip(self, tx_hex), target = "bitcoin_client::rpc_client")]
     async fn send_raw_transaction(&self, tx_hex: &str) -> BitcoinRpcResult<Txid> {
-        Self::retry_rpc(|| {
-            debug!("Sending raw transaction");
-            self.client
-                .send_raw_transaction(tx_hex)
-                .map_err(|e| e.into())
-        })
+        // A rejected transaction will be rejected again, so only transport errors are retried.
+        with_async_retry(
+            || async move {
+                debug!("Sending raw transaction");
+                self.client
+                    .send_raw_transaction(tx_hex)
+                    .map_err(|e| match e {
+                        // Keep the reject reason reported by the node instead of flattening it.
+                        bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Rpc(err)) => {
+                            BitcoinError::TransactionRejected(err.code, err.message)
+                        }
+                        e => e.into(),
+                    })
+            },
+            BitcoinError::is_transient,
+            RPC_MAX_RETRIES,
+            RPC_RETRY_DELAY_MS,
+            "Sending raw transaction",
+        )
         .await
     }
 
@@ -167,10 +198,18 @@ impl BitcoinRpc for BitcoinRpcClient {
     }
 
     #[instrument(skip(self), target = "bitcoin_client::rpc_client")]
-    async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinRpcResult<Block> {
+    async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinRpcResult<Option<Block>> {
         Self::retry_rpc(|| {
             debug!("Getting block by hash");
-            self.client.get_block(block_hash).map_err(|e| e.into())
+            match self.client.get_block(block_hash) {
+                Ok(block) => Ok(Some(block)),
+                Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::Error::Rpc(err)))
+                    if err.code == RPC_INVALID_ADDRESS_OR_KEY =>
+                {
+                    Ok(None)
+                }
+                Err(e) => Err(e.into()),
+            }
         })
         .await
     }
@@ -213,6 +252,26 @@ impl BitcoinRpc for BitcoinRpcClient {
         .await
     }
 
+    #[instrument(skip(self), target = "bitcoin_client::rpc_client")]
+    async fn find_raw_transaction_info(
+        &self,
+        txid: &Txid,
+    ) -> BitcoinRpcResult<Option<bitcoincore_rpc::json::GetRawTransactionResult>> {
+        Self::retry_rpc(|| {
+            debug!("Finding raw transaction info");
+            match self.client.get_raw_transaction_info(txid, None) {
+                Ok(info) => Ok(Some(info)),
+                Err(bitcoincore_rpc::Error::JsonRpc(bitcoincore_rpc::jsonrpc::Error::Rpc(err)))
+                    if err.code == RPC_INVALID_ADDRESS_OR_KEY =>
+                {
+                    Ok(None)
+                }
+                Err(e) => Err(e.into()),
+            }
+        })
+        .await
+    }
+
     #[instrument(skip(self), target = "bitcoin_client::rpc_client")]
     async fn get_blockchain_info(&self) -> BitcoinRpcResult<GetBlockchainInfoResult> {
         Self::retry_rpc(|| {
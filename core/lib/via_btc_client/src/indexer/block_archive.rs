@@ -0,0 +1,156 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use bitcoin::{consensus, Block, Network};
+
+use super::parser::MessageParser;
+use crate::types::FullInscriptionMessage;
+
+const BLOCK_FILE_EXTENSION: &str = "blk";
+
+/// On-disk ring buffer with the raw blocks fetched by the indexer for the most recent heights.
+/// Blocks are stored in consensus encoding, one file per slot, so that they can be inspected with
+/// the usual Bitcoin tooling or replayed through the parser after a reorg or a parsing bug.
+///
+/// The block at a height `h` is stored in the slot `h % capacity`, prefixed by `h`, so that a slot
+/// reused by a newer block is never mistaken for an older one.
+#[derive(Debug, Clone)]
+pub struct BlockArchive {
+    dir: PathBuf,
+    capacity: u32,
+}
+
+impl BlockArchive {
+    pub fn new(dir: impl Into<PathBuf>, capacity: u32) -> io::Result<Self> {
+        if capacity == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "block archive capacity must be positive",
+            ));
+        }
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, capacity })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn slot_path(&self, block_height: u32) -> PathBuf {
+        self.dir.join(format!(
+            "{}.{BLOCK_FILE_EXTENSION}",
+            block_height % self.capacity
+        ))
+    }
+
+    /// Persists the block, overwriting the block stored `capacity` heights below it (or the block
+    /// previously fetched at the same height, e.g. before a reorg).
+    pub fn save(&self, block_height: u32, block: &Block) -> io::Result<()> {
+        let mut bytes = block_height.to_le_bytes().to_vec();
+        bytes.extend(consensus::serialize(block));
+
+        // Write to a temporary file first, so that a crash never leaves a truncated block behind.
+        let path = self.slot_path(block_height);
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(tmp_path, path)
+    }
+
+    /// Loads the block at the given height, or `None` if it's not in the archive (anymore).
+    pub fn load(&self, block_height: u32) -> io::Result<Option<Block>> {
+        match Self::read_slot(&self.slot_path(block_height))? {
+            Some((height, block)) if height == block_height => Ok(Some(block)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns all archived blocks ordered by height.
+    pub fn blocks(&self) -> io::Result<Vec<(u32, Block)>> {
+        let mut blocks = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(BLOCK_FILE_EXTENSION) {
+                continue;
+            }
+            blocks.extend(Self::read_slot(&path)?);
+        }
+        blocks.sort_unstable_by_key(|(height, _)| *height);
+        Ok(blocks)
+    }
+
+    /// Parses all archived blocks in height order, as the indexer would have done when fetching them.
+    /// Messages are not filtered, so that messages rejected by the indexer show up as well.
+    pub fn replay(&self, network: Network) -> io::Result<Vec<(u32, Vec<FullInscriptionMessage>)>> {
+        let mut parser = MessageParser::new(network);
+        Ok(self
+            .blocks()?
+            .into_iter()
+            .map(|(height, block)| {
+                let messages = block
+                    .txdata
+                    .iter()
+                    .flat_map(|tx| parser.parse_transaction(tx, height))
+                    .collect();
+                (height, messages)
+            })
+            .collect())
+    }
+
+    fn read_slot(path: &Path) -> io::Result<Option<(u32, Block)>> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let Some((height, block)) = bytes.split_first_chunk::<4>() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("archived block file {} is truncated", path.display()),
+            ));
+        };
+        let block = consensus::deserialize(block)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        Ok(Some((u32::from_le_bytes(*height), block)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::blockdata::constants::genesis_block;
+
+    use super::*;
+
+    #[test]
+    fn block_is_persisted_and_reloaded() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let archive = BlockArchive::new(dir.path(), 2).unwrap();
+        let block = genesis_block(Network::Regtest);
+
+        assert_eq!(archive.load(10).unwrap(), None);
+        archive.save(10, &block).unwrap();
+        assert_eq!(archive.load(10).unwrap(), Some(block.clone()));
+
+        // Reopening the archive must not lose the persisted block.
+        let archive = BlockArchive::new(dir.path(), 2).unwrap();
+        assert_eq!(archive.load(10).unwrap(), Some(block.clone()));
+
+        // Height 12 reuses the slot of height 10.
+        archive.save(11, &block).unwrap();
+        archive.save(12, &block).unwrap();
+        assert_eq!(archive.load(10).unwrap(), None);
+        let heights: Vec<_> = archive
+            .blocks()
+            .unwrap()
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect();
+        assert_eq!(heights, [11, 12]);
+
+        let replayed = archive.replay(Network::Regtest).unwrap();
+        assert_eq!(replayed.len(), 2);
+        assert!(replayed.iter().all(|(_, messages)| messages.is_empty()));
+    }
+}
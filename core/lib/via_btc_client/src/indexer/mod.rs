@@ -1,12 +1,15 @@
 use std::{collections::HashMap, sync::Arc};
 
-use bitcoin::{Address, BlockHash, Network, Txid};
+use bitcoin::{address::NetworkUnchecked, Address, BlockHash, Network, Txid};
 use bitcoincore_rpc::Auth;
 use tracing::{debug, error, info, instrument, warn};
 
+mod block_archive;
 mod parser;
+pub use block_archive::BlockArchive;
 pub use parser::get_eth_address;
 use parser::MessageParser;
+use zksync_config::ViaBtcClientConfig;
 use zksync_types::H256;
 
 use crate::{
@@ -14,8 +17,12 @@ use crate::{
     traits::BitcoinOps,
     types,
     types::{BitcoinIndexerResult, FullInscriptionMessage, L1ToL2Message, Vote},
+    utils::with_async_retry,
 };
 
+const BOOTSTRAP_FETCH_MAX_RETRIES: u8 = 5;
+const BOOTSTRAP_FETCH_RETRY_DELAY_MS: u64 = 1_000;
+
 /// Represents the state during the bootstrap process
 #[derive(Debug)]
 struct BootstrapState {
@@ -73,6 +80,8 @@ pub struct BitcoinInscriptionIndexer {
     sequencer_address: Address,
     verifier_addresses: Vec<Address>,
     starting_block_number: u32,
+    strict_inscription_validation: bool,
+    block_archive: Option<BlockArchive>,
 }
 
 impl BitcoinInscriptionIndexer {
@@ -86,18 +95,113 @@ impl BitcoinInscriptionIndexer {
     where
         Self: Sized,
     {
+        Self::new_with_config(
+            rpc_url,
+            network,
+            auth,
+            bootstrap_txids,
+            &ViaBtcClientConfig::default(),
+        )
+        .await
+    }
+
+    #[instrument(
+        skip(rpc_url, network, bootstrap_txids, client_config),
+        target = "bitcoin_indexer"
+    )]
+    pub async fn new_with_config(
+        rpc_url: &str,
+        network: Network,
+        auth: Auth,
+        bootstrap_txids: Vec<Txid>,
+        client_config: &ViaBtcClientConfig,
+    ) -> BitcoinIndexerResult<Self> {
         info!("Creating new BitcoinInscriptionIndexer");
-        let client = Arc::new(BitcoinClient::new(rpc_url, network, auth)?);
+        let client = Arc::new(BitcoinClient::new_with_config(
+            rpc_url,
+            network,
+            auth,
+            client_config,
+        )?);
+        Self::verify_node_network(client.as_ref(), network).await?;
+        Self::bootstrap(
+            client,
+            network,
+            bootstrap_txids,
+            BOOTSTRAP_FETCH_MAX_RETRIES,
+            BOOTSTRAP_FETCH_RETRY_DELAY_MS,
+        )
+        .await
+    }
+
+    /// Enables strict inscription validation: a transaction carrying more than one inscription
+    /// is dropped as a whole instead of having each of its messages indexed.
+    pub fn with_strict_inscription_validation(mut self, strict: bool) -> Self {
+        self.strict_inscription_validation = strict;
+        self
+    }
+
+    /// Persists every fetched block to the given archive, so that it can be replayed later.
+    pub fn with_block_archive(mut self, archive: BlockArchive) -> Self {
+        self.block_archive = Some(archive);
+        self
+    }
+
+    /// Returns the client the indexer uses to query the Bitcoin node.
+    pub fn client(&self) -> Arc<dyn BitcoinOps> {
+        self.client.clone()
+    }
+
+    /// Checks that the connected node runs on the configured network, so that e.g. a mainnet
+    /// configuration is never used to index a testnet chain.
+    async fn verify_node_network(
+        client: &dyn BitcoinOps,
+        network: Network,
+    ) -> BitcoinIndexerResult<()> {
+        let node_network = client.fetch_node_network().await?;
+        if node_network != network {
+            error!(
+                "Bitcoin node runs on {node_network}, but the indexer is configured for {network}"
+            );
+            return Err(types::IndexerError::NetworkMismatch(format!(
+                "configured network is {network}, but the Bitcoin node runs on {node_network}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Builds the indexer state from the bootstrap transactions. Fetching a transaction is retried
+    /// with backoff, so that a Bitcoin node which is briefly unavailable doesn't abort startup.
+    async fn bootstrap(
+        client: Arc<dyn BitcoinOps>,
+        network: Network,
+        bootstrap_txids: Vec<Txid>,
+        max_retries: u8,
+        retry_delay_ms: u64,
+    ) -> BitcoinIndexerResult<Self> {
         let mut parser = MessageParser::new(network);
         let mut bootstrap_state = BootstrapState::new();
 
         for txid in bootstrap_txids {
             debug!("Processing bootstrap transaction: {}", txid);
-            let tx = client.get_transaction(&txid).await?;
+            // The RPC client already retries each call; here, only errors caused by an unavailable
+            // node are retried with a longer backoff.
+            let tx = with_async_retry(
+                || client.get_transaction(&txid),
+                types::BitcoinError::is_transient,
+                max_retries,
+                retry_delay_ms,
+                "Fetching bootstrap transaction",
+            )
+            .await
+            .map_err(|err| {
+                error!("Failed to fetch bootstrap transaction {txid}: {err}");
+                err
+            })?;
             let messages = parser.parse_transaction(&tx, 0);
 
             for message in messages {
-                Self::process_bootstrap_message(&mut bootstrap_state, message, txid, network);
+                Self::process_bootstrap_message(&mut bootstrap_state, message, txid, network)?;
             }
 
             if bootstrap_state.is_complete() {
@@ -139,14 +243,22 @@ impl BitcoinInscriptionIndexer {
         }
 
         let block = self.client.fetch_block(block_height as u128).await?;
+        if let Some(archive) = &self.block_archive {
+            // The archive is a debugging aid only, so failing to persist a block must not stop indexing.
+            if let Err(err) = archive.save(block_height, &block) {
+                warn!(
+                    "Failed archiving block {block_height} to {}: {err}",
+                    archive.dir().display()
+                );
+            }
+        }
 
-        let messages: Vec<_> = block
-            .txdata
-            .iter()
-            .flat_map(|tx| self.parser.parse_transaction(tx, block_height))
-            // TODO: Implement message validation
-            // .filter(|message| self.is_valid_message(message))
-            .collect();
+        let mut messages = Vec::new();
+        for tx in &block.txdata {
+            let tx_messages = self.parser.parse_transaction(tx, block_height);
+            // TODO: Implement message validation with `self.is_valid_message(message)`
+            messages.extend(self.accept_transaction_messages(&tx.compute_txid(), tx_messages));
+        }
 
         debug!(
             "Processed {} valid messages in block {}",
@@ -166,7 +278,10 @@ impl BitcoinInscriptionIndexer {
             "Checking if blocks are connected: parent {}, child {}",
             parent_hash, child_hash
         );
-        let child_block = self.client.fetch_block_by_hash(child_hash).await?;
+        let Some(child_block) = self.client.fetch_block_by_hash(child_hash).await? else {
+            debug!("Child block {child_hash} is unknown to the node");
+            return Ok(false);
+        };
         let are_connected = child_block.header.prev_blockhash == *parent_hash;
         debug!("Blocks connected: {}", are_connected);
         Ok(are_connected)
@@ -176,6 +291,11 @@ impl BitcoinInscriptionIndexer {
         self.client.fetch_block_height().await.map_err(|e| e.into())
     }
 
+    /// Returns the timestamp of the Bitcoin chain tip block, as a Unix timestamp in seconds.
+    pub async fn fetch_tip_time(&self) -> BitcoinIndexerResult<u64> {
+        self.client.fetch_tip_time().await.map_err(|e| e.into())
+    }
+
     pub fn get_state(&self) -> (Address, Address, Vec<Address>, u32) {
         (
             self.bridge_address.clone(),
@@ -205,6 +325,8 @@ impl BitcoinInscriptionIndexer {
                     sequencer_address: sequencer,
                     verifier_addresses: bootstrap_state.verifier_addresses,
                     starting_block_number: bootstrap_state.starting_block_number,
+                    strict_inscription_validation: false,
+                    block_archive: None,
                 })
             } else {
                 error!("Incomplete bootstrap process despite state being marked as complete");
@@ -223,6 +345,27 @@ impl BitcoinInscriptionIndexer {
         }
     }
 
+    /// Returns the messages parsed from a single transaction that should be indexed. In strict mode,
+    /// a transaction must carry at most one inscription; otherwise all of its messages are rejected.
+    fn accept_transaction_messages(
+        &self,
+        txid: &Txid,
+        messages: Vec<FullInscriptionMessage>,
+    ) -> Vec<FullInscriptionMessage> {
+        if !self.strict_inscription_validation || messages.len() <= 1 {
+            return messages;
+        }
+
+        warn!(
+            "Rejecting transaction {txid} carrying {} inscriptions in strict mode",
+            messages.len()
+        );
+        for (index, message) in messages.iter().enumerate() {
+            warn!("Inscription #{index} in transaction {txid}: {message:?}");
+        }
+        vec![]
+    }
+
     #[instrument(skip(self, message), target = "bitcoin_indexer")]
     fn is_valid_message(&self, message: &FullInscriptionMessage) -> bool {
         match message {
@@ -258,28 +401,23 @@ impl BitcoinInscriptionIndexer {
         message: FullInscriptionMessage,
         txid: Txid,
         network: Network,
-    ) {
+    ) -> BitcoinIndexerResult<()> {
         match message {
             FullInscriptionMessage::SystemBootstrapping(sb) => {
                 debug!("Processing SystemBootstrapping message");
 
-                // convert the verifier addresses to the correct network
-                // scince the bootstrap message should run on the bootstrapping phase of sequencer
-                // i consume it's ok to using unwrap
+                // Addresses in the bootstrap message must belong to the configured network;
+                // otherwise the bootstrap transactions were made for another deployment.
                 let verifier_addresses = sb
                     .input
                     .verifier_p2wpkh_addresses
-                    .iter()
-                    .map(|addr| addr.clone().require_network(network).unwrap())
-                    .collect();
+                    .into_iter()
+                    .map(|addr| require_network(addr, network))
+                    .collect::<BitcoinIndexerResult<_>>()?;
 
                 state.verifier_addresses = verifier_addresses;
 
-                let bridge_address = sb
-                    .input
-                    .bridge_p2wpkh_mpc_address
-                    .require_network(network)
-                    .unwrap();
+                let bridge_address = require_network(sb.input.bridge_p2wpkh_mpc_address, network)?;
                 state.bridge_address = Some(bridge_address);
                 state.starting_block_number = sb.input.start_block_height;
                 state.bootloader_hash = Some(sb.input.bootloader_hash);
@@ -288,11 +426,8 @@ impl BitcoinInscriptionIndexer {
             FullInscriptionMessage::ProposeSequencer(ps) => {
                 debug!("Processing ProposeSequencer message");
                 if state.verifier_addresses.contains(&ps.common.p2wpkh_address) {
-                    let sequencer_address = ps
-                        .input
-                        .sequencer_new_p2wpkh_address
-                        .require_network(network)
-                        .unwrap();
+                    let sequencer_address =
+                        require_network(ps.input.sequencer_new_p2wpkh_address, network)?;
                     state.proposed_sequencer = Some(sequencer_address);
                     state.proposed_sequencer_txid = Some(txid);
                 }
@@ -314,6 +449,7 @@ impl BitcoinInscriptionIndexer {
                 debug!("Ignoring non-bootstrap message during bootstrap process");
             }
         }
+        Ok(())
     }
 
     #[instrument(skip(self, message), target = "bitcoin_indexer")]
@@ -327,19 +463,37 @@ impl BitcoinInscriptionIndexer {
     }
 }
 
+fn require_network(
+    address: Address<NetworkUnchecked>,
+    network: Network,
+) -> BitcoinIndexerResult<Address> {
+    address.require_network(network).map_err(|err| {
+        types::IndexerError::NetworkMismatch(format!(
+            "bootstrap address doesn't belong to {network}: {err}"
+        ))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
     use async_trait::async_trait;
     use bitcoin::{
-        block::Header, hashes::Hash, Amount, Block, OutPoint, ScriptBuf, Transaction, TxMerkleNode,
-        TxOut,
+        block::Header,
+        hashes::Hash,
+        secp256k1::{Keypair, Secp256k1, SecretKey},
+        taproot::LeafVersion,
+        Amount, Block, CompressedPublicKey, OutPoint, ScriptBuf, Transaction, TxIn, TxMerkleNode,
+        TxOut, Witness,
     };
     use mockall::{mock, predicate::*};
 
     use super::*;
-    use crate::types::{BitcoinClientResult, CommonFields};
+    use crate::{
+        inscriber::script_builder::InscriptionData,
+        types::{BitcoinClientResult, CommonFields},
+    };
 
     mock! {
         BitcoinOps {}
@@ -347,14 +501,19 @@ mod tests {
         impl BitcoinOps for BitcoinOps {
             async fn get_transaction(&self, txid: &Txid) -> BitcoinClientResult<Transaction>;
             async fn fetch_block(&self, block_height: u128) -> BitcoinClientResult<Block>;
-            async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Block>;
+            async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Option<Block>>;
             async fn get_balance(&self, address: &Address) -> BitcoinClientResult<u128>;
             async fn broadcast_signed_transaction(&self, signed_transaction: &str) -> BitcoinClientResult<Txid>;
+            async fn send_raw_transaction(&self, tx: &Transaction) -> Result<Txid, crate::types::BroadcastError>;
             async fn fetch_utxos(&self, address: &Address) -> BitcoinClientResult<Vec<(OutPoint, TxOut)>>;
+            async fn fetch_utxos_with_confirmations(&self, address: &Address) -> BitcoinClientResult<Vec<((OutPoint, TxOut), u32)>>;
             async fn check_tx_confirmation(&self, txid: &Txid, conf_num: u32) -> BitcoinClientResult<bool>;
+            async fn fetch_tx_confirmations(&self, txid: &Txid) -> BitcoinClientResult<Option<u32>>;
             async fn fetch_block_height(&self) -> BitcoinClientResult<u128>;
             async fn get_fee_rate(&self, conf_target: u16) -> BitcoinClientResult<u64>;
             fn get_network(&self) -> Network;
+            async fn fetch_node_network(&self) -> BitcoinClientResult<Network>;
+            async fn fetch_tip_time(&self) -> BitcoinClientResult<u64>;
         }
     }
 
@@ -387,6 +546,8 @@ mod tests {
             sequencer_address,
             verifier_addresses: vec![],
             starting_block_number: 0,
+            strict_inscription_validation: false,
+            block_archive: None,
         }
     }
 
@@ -410,7 +571,7 @@ mod tests {
         mock_client
             .expect_fetch_block_by_hash()
             .with(eq(child_hash))
-            .returning(move |_| Ok(mock_block.clone()));
+            .returning(move |_| Ok(Some(mock_block.clone())));
         mock_client
             .expect_get_network()
             .returning(|| Network::Testnet);
@@ -456,6 +617,42 @@ mod tests {
         assert_eq!(result.unwrap().len(), 0);
     }
 
+    #[test]
+    fn test_strict_inscription_validation() {
+        let txid = Txid::all_zeros();
+        let attestation =
+            FullInscriptionMessage::ValidatorAttestation(types::ValidatorAttestation {
+                common: get_test_common_fields(),
+                input: types::ValidatorAttestationInput {
+                    reference_txid: Txid::all_zeros(),
+                    attestation: Vote::Ok,
+                },
+            });
+        let extra = FullInscriptionMessage::ValidatorAttestation(types::ValidatorAttestation {
+            common: get_test_common_fields(),
+            input: types::ValidatorAttestationInput {
+                reference_txid: Txid::all_zeros(),
+                attestation: Vote::NotOk,
+            },
+        });
+        let messages = vec![attestation.clone(), extra];
+
+        let indexer = get_indexer_with_mock(MockBitcoinOps::new());
+        assert_eq!(
+            indexer.accept_transaction_messages(&txid, messages.clone()),
+            messages
+        );
+
+        let indexer = indexer.with_strict_inscription_validation(true);
+        assert!(indexer
+            .accept_transaction_messages(&txid, messages)
+            .is_empty());
+        assert_eq!(
+            indexer.accept_transaction_messages(&txid, vec![attestation.clone()]),
+            vec![attestation]
+        );
+    }
+
     #[tokio::test]
     async fn test_is_valid_message() {
         let indexer = get_indexer_with_mock(MockBitcoinOps::new());
@@ -568,4 +765,241 @@ mod tests {
         };
         assert!(!indexer.is_valid_l1_to_l2_transfer(&invalid_message));
     }
+
+    fn get_empty_transaction() -> Transaction {
+        Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_node_network() {
+        let mut mock_client = MockBitcoinOps::new();
+        mock_client
+            .expect_fetch_node_network()
+            .returning(|| Ok(Network::Testnet));
+
+        BitcoinInscriptionIndexer::verify_node_network(&mock_client, Network::Testnet)
+            .await
+            .unwrap();
+        let result =
+            BitcoinInscriptionIndexer::verify_node_network(&mock_client, Network::Bitcoin).await;
+        assert!(matches!(
+            result,
+            Err(types::IndexerError::NetworkMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn test_bootstrap_message_from_another_network_is_rejected() {
+        let system_bootstrapping =
+            FullInscriptionMessage::SystemBootstrapping(types::SystemBootstrapping {
+                common: get_test_common_fields(),
+                input: types::SystemBootstrappingInput {
+                    start_block_height: 1,
+                    bridge_p2wpkh_mpc_address: get_test_addr().as_unchecked().to_owned(),
+                    verifier_p2wpkh_addresses: vec![get_test_addr().as_unchecked().to_owned()],
+                    bootloader_hash: H256::zero(),
+                    abstract_account_hash: H256::zero(),
+                },
+            });
+
+        let mut state = BootstrapState::new();
+        BitcoinInscriptionIndexer::process_bootstrap_message(
+            &mut state,
+            system_bootstrapping.clone(),
+            Txid::all_zeros(),
+            Network::Testnet,
+        )
+        .unwrap();
+        assert_eq!(state.bridge_address, Some(get_test_addr()));
+
+        // Testnet addresses must not be accepted by a mainnet deployment.
+        let mut state = BootstrapState::new();
+        let result = BitcoinInscriptionIndexer::process_bootstrap_message(
+            &mut state,
+            system_bootstrapping,
+            Txid::all_zeros(),
+            Network::Bitcoin,
+        );
+        assert!(matches!(
+            result,
+            Err(types::IndexerError::NetworkMismatch(_))
+        ));
+        assert!(state.bridge_address.is_none());
+    }
+
+    /// Builds a transaction which carries `message` as an inscription and is sent from the P2WPKH address
+    /// of `sender`, so that the parser picks it up like a real reveal transaction.
+    fn get_inscription_transaction(
+        message: &types::InscriptionMessage,
+        sender: &SecretKey,
+    ) -> Transaction {
+        let secp = Secp256k1::new();
+        let (internal_key, _) = Keypair::from_secret_key(&secp, sender).x_only_public_key();
+        let inscription =
+            InscriptionData::new(message, &secp, internal_key, Network::Testnet).unwrap();
+        let control_block = inscription
+            .taproot_spend_info
+            .control_block(&(
+                inscription.inscription_script.clone(),
+                LeafVersion::TapScript,
+            ))
+            .unwrap();
+
+        let sender_pubkey = CompressedPublicKey(sender.public_key(&secp));
+        let p2wpkh_witness = Witness::from_slice(&[vec![0; 72], sender_pubkey.to_bytes().to_vec()]);
+        let reveal_witness = Witness::from_slice(&[
+            vec![0; 64],
+            inscription.inscription_script.to_bytes(),
+            control_block.serialize(),
+        ]);
+        let input = |witness| TxIn {
+            witness,
+            ..TxIn::default()
+        };
+
+        Transaction {
+            input: vec![input(p2wpkh_witness), input(reveal_witness)],
+            ..get_empty_transaction()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_retries_transient_rpc_failures() {
+        let secp = Secp256k1::new();
+        let verifier_key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let verifier_address = Address::p2wpkh(
+            &CompressedPublicKey(verifier_key.public_key(&secp)),
+            Network::Testnet,
+        );
+        let sequencer_address = get_test_addr();
+
+        let bootstrap_txid = Txid::from_byte_array([1; 32]);
+        let propose_sequencer_txid = Txid::from_byte_array([2; 32]);
+        let attestation_txid = Txid::from_byte_array([3; 32]);
+        let messages = [
+            (
+                bootstrap_txid,
+                types::InscriptionMessage::SystemBootstrapping(types::SystemBootstrappingInput {
+                    start_block_height: 1,
+                    verifier_p2wpkh_addresses: vec![verifier_address.as_unchecked().clone()],
+                    bridge_p2wpkh_mpc_address: get_test_addr().as_unchecked().clone(),
+                    bootloader_hash: H256::repeat_byte(1),
+                    abstract_account_hash: H256::repeat_byte(2),
+                }),
+            ),
+            (
+                propose_sequencer_txid,
+                types::InscriptionMessage::ProposeSequencer(types::ProposeSequencerInput {
+                    sequencer_new_p2wpkh_address: sequencer_address.as_unchecked().clone(),
+                }),
+            ),
+            (
+                attestation_txid,
+                types::InscriptionMessage::ValidatorAttestation(types::ValidatorAttestationInput {
+                    reference_txid: propose_sequencer_txid,
+                    attestation: Vote::Ok,
+                }),
+            ),
+        ];
+        let transactions: HashMap<_, _> = messages
+            .iter()
+            .map(|(txid, message)| (*txid, get_inscription_transaction(message, &verifier_key)))
+            .collect();
+
+        // The node is unavailable for the first two calls.
+        let mut calls = 0;
+        let mut mock_client = MockBitcoinOps::new();
+        mock_client
+            .expect_get_transaction()
+            .times(messages.len() + 2)
+            .returning(move |txid| {
+                calls += 1;
+                if calls <= 2 {
+                    return Err(types::BitcoinError::RpcUnavailable(
+                        "connection refused".to_string(),
+                    ));
+                }
+                Ok(transactions[txid].clone())
+            });
+
+        let indexer = BitcoinInscriptionIndexer::bootstrap(
+            Arc::new(mock_client),
+            Network::Testnet,
+            messages.iter().map(|(txid, _)| *txid).collect(),
+            3,
+            0,
+        )
+        .await
+        .unwrap();
+
+        let (bridge_address, indexed_sequencer_address, verifier_addresses, starting_block) =
+            indexer.get_state();
+        assert_eq!(bridge_address, get_test_addr());
+        assert_eq!(indexed_sequencer_address, sequencer_address);
+        assert_eq!(verifier_addresses, [verifier_address]);
+        assert_eq!(starting_block, 1);
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_does_not_retry_non_transient_errors() {
+        let mut mock_client = MockBitcoinOps::new();
+        mock_client
+            .expect_get_transaction()
+            .times(1)
+            .returning(|_| {
+                Err(types::BitcoinError::InvalidTransaction(
+                    "malformed transaction".to_string(),
+                ))
+            });
+
+        let result = BitcoinInscriptionIndexer::bootstrap(
+            Arc::new(mock_client),
+            Network::Testnet,
+            vec![Txid::all_zeros()],
+            3,
+            0,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(types::IndexerError::BitcoinClientError(
+                types::BitcoinError::InvalidTransaction(_)
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_bootstrap_fails_after_exhausting_retries() {
+        let mut mock_client = MockBitcoinOps::new();
+        mock_client
+            .expect_get_transaction()
+            .times(4)
+            .returning(|_| {
+                Err(types::BitcoinError::RpcUnavailable(
+                    "connection refused".to_string(),
+                ))
+            });
+
+        let result = BitcoinInscriptionIndexer::bootstrap(
+            Arc::new(mock_client),
+            Network::Testnet,
+            vec![Txid::all_zeros()],
+            3,
+            0,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(types::IndexerError::BitcoinClientError(
+                types::BitcoinError::RpcUnavailable(_)
+            ))
+        ));
+    }
 }
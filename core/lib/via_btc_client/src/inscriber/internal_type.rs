@@ -5,7 +5,9 @@ pub struct CommitTxInputRes {
     pub commit_tx_inputs: Vec<TxIn>,
     pub unlocked_value: Amount,
     pub inputs_count: u32,
+    pub p2tr_inputs_count: u32,
     pub utxo_amounts: Vec<Amount>,
+    pub prev_outs: Vec<TxOut>,
 }
 
 #[derive(Debug)]
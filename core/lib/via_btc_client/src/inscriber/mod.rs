@@ -4,8 +4,8 @@ use std::{borrow::Borrow, collections::HashMap, sync::Arc};
 
 use anyhow::{Context, Result};
 use bitcoin::{
-    absolute,
-    hashes::Hash,
+    absolute, consensus,
+    hashes::{hex::FromHex, Hash},
     sighash::{Prevouts, SighashCache},
     taproot::{ControlBlock, LeafVersion},
     transaction, Address, Amount, EcdsaSighashType, OutPoint, ScriptBuf, Sequence, TapLeafHash,
@@ -14,6 +14,7 @@ use bitcoin::{
 use bitcoincore_rpc::{Auth, RawTx};
 use secp256k1::Message;
 use tracing::{debug, info, instrument, warn};
+use zksync_config::{configs::via_btc_sender::ChangeOutputType, ViaBtcClientConfig};
 
 use crate::{
     client::BitcoinClient,
@@ -27,16 +28,21 @@ use crate::{
     },
     signer::KeyManager,
     traits::{BitcoinOps, BitcoinSigner},
-    types::{BitcoinNetwork, InscriberContext, InscriptionConfig, InscriptionMessage, Recipient},
+    types::{
+        BitcoinError, BitcoinNetwork, InscribeError, InscriberContext, InscriptionConfig,
+        InscriptionMessage, InscriptionRequest, Recipient,
+    },
 };
 
 mod fee;
 mod internal_type;
-mod script_builder;
+pub(crate) mod script_builder;
 pub mod test_utils;
 
 const CTX_REQUIRED_CONFIRMATIONS: u32 = 1;
 const FEE_RATE_CONF_TARGET: u16 = 1;
+// sat/vB, the default minimum relay fee of Bitcoin Core.
+const TEST_NETWORK_MIN_FEE_RATE: u64 = 1;
 
 const COMMIT_TX_CHANGE_OUTPUT_INDEX: u32 = 0;
 const COMMIT_TX_TAPSCRIPT_OUTPUT_INDEX: u32 = 1;
@@ -46,13 +52,14 @@ const REVEAL_TX_TAPSCRIPT_REVEAL_INDEX: u32 = 1;
 
 const FEE_RATE_INCREASE_PER_PENDING_TX: u64 = 5; // percentage
 
-const COMMIT_TX_P2TR_OUTPUT_COUNT: u32 = 1;
-const COMMIT_TX_P2WPKH_OUTPUT_COUNT: u32 = 1;
-const COMMIT_TX_P2TR_INPUT_COUNT: u32 = 0;
-const REVEAL_TX_P2TR_OUTPUT_COUNT: u32 = 0;
-const REVEAL_TX_P2WPKH_OUTPUT_COUNT: u32 = 1;
-const REVEAL_TX_P2TR_INPUT_COUNT: u32 = 1;
-const REVEAL_TX_P2WPKH_INPUT_COUNT: u32 = 1;
+const COMMIT_TX_TAPSCRIPT_OUTPUT_COUNT: u32 = 1;
+const COMMIT_TX_CHANGE_OUTPUT_COUNT: u32 = 1;
+const REVEAL_TX_CHANGE_OUTPUT_COUNT: u32 = 1;
+const REVEAL_TX_TAPSCRIPT_INPUT_COUNT: u32 = 1;
+const REVEAL_TX_FEE_PAYER_INPUT_COUNT: u32 = 1;
+
+// A key path spend only carries the schnorr signature, which the P2TR input base size already covers.
+const P2TR_KEY_SPEND_WITNESS_SIZE: usize = 0;
 
 const BROADCAST_RETRY_COUNT: u32 = 3;
 
@@ -61,6 +68,8 @@ pub struct Inscriber {
     client: Arc<dyn BitcoinOps>,
     signer: Arc<dyn BitcoinSigner>,
     context: InscriberContext,
+    change_output_type: ChangeOutputType,
+    min_fee_rate: Option<u64>,
 }
 
 impl Inscriber {
@@ -71,9 +80,37 @@ impl Inscriber {
         auth: Auth,
         signer_private_key: &str,
         persisted_ctx: Option<InscriberContext>,
+    ) -> Result<Self> {
+        Self::new_with_config(
+            rpc_url,
+            network,
+            auth,
+            signer_private_key,
+            persisted_ctx,
+            &ViaBtcClientConfig::default(),
+        )
+        .await
+    }
+
+    #[instrument(
+        skip(rpc_url, auth, signer_private_key, client_config),
+        target = "bitcoin_inscriber"
+    )]
+    pub async fn new_with_config(
+        rpc_url: &str,
+        network: BitcoinNetwork,
+        auth: Auth,
+        signer_private_key: &str,
+        persisted_ctx: Option<InscriberContext>,
+        client_config: &ViaBtcClientConfig,
     ) -> Result<Self> {
         info!("Creating new Inscriber");
-        let client = Arc::new(BitcoinClient::new(rpc_url, network, auth)?);
+        let client = Arc::new(BitcoinClient::new_with_config(
+            rpc_url,
+            network,
+            auth,
+            client_config,
+        )?);
         let signer = Arc::new(KeyManager::new(signer_private_key, network)?);
         let context = persisted_ctx.unwrap_or_default();
 
@@ -81,14 +118,36 @@ impl Inscriber {
             client,
             signer,
             context,
+            change_output_type: ChangeOutputType::default(),
+            min_fee_rate: None,
         })
     }
 
+    /// Selects the script type of the change outputs of the commit and reveal transactions.
+    pub fn with_change_output_type(mut self, change_output_type: ChangeOutputType) -> Self {
+        self.change_output_type = change_output_type;
+        self
+    }
+
+    /// Sets the fee rate floor (sat/vB). `None` keeps the network default, see [`Self::fee_rate_floor`].
+    pub fn with_min_fee_rate(mut self, min_fee_rate: Option<u64>) -> Self {
+        self.min_fee_rate = min_fee_rate;
+        self
+    }
+
+    /// Returns the total balance of the signer's P2WPKH and P2TR addresses.
     #[instrument(skip(self), target = "bitcoin_inscriber")]
     pub async fn get_balance(&self) -> Result<u128> {
         debug!("Getting balance");
-        let address_ref = &self.signer.get_p2wpkh_address()?;
-        let balance = self.client.get_balance(address_ref).await?;
+        let p2wpkh_balance = self
+            .client
+            .get_balance(&self.signer.get_p2wpkh_address()?)
+            .await?;
+        let p2tr_balance = self
+            .client
+            .get_balance(&self.signer.get_p2tr_address()?)
+            .await?;
+        let balance = p2wpkh_balance + p2tr_balance;
         debug!("Balance obtained: {}", balance);
         Ok(balance)
     }
@@ -151,7 +210,7 @@ impl Inscriber {
         input: InscriptionMessage,
         config: InscriptionConfig,
         recipient: Option<Recipient>,
-    ) -> Result<InscriberInfo> {
+    ) -> Result<InscriberInfo, InscribeError> {
         info!("Starting inscription process");
 
         let inscriber_info = self
@@ -176,7 +235,7 @@ impl Inscriber {
         &mut self,
         input: InscriptionMessage,
         config: InscriptionConfig,
-    ) -> Result<InscriberInfo> {
+    ) -> Result<InscriberInfo, InscribeError> {
         self.inscribe_with_recipient(input, config, None).await
     }
 
@@ -212,11 +271,18 @@ impl Inscriber {
         let mut commit_tx_inputs: Vec<TxIn> = Vec::new();
         let mut unlocked_value: Amount = Amount::ZERO;
         let mut inputs_count: u32 = 0;
+        let mut p2tr_inputs_count: u32 = 0;
         let mut utxo_amounts: Vec<Amount> = Vec::new();
+        let mut prev_outs: Vec<TxOut> = Vec::new();
 
         let address_ref = &self.signer.get_p2wpkh_address()?;
         let mut utxos = self.client.fetch_utxos(address_ref).await?;
 
+        // P2TR change lands on the signer's taproot address. It's collected regardless of the current
+        // change output type, so that switching the type doesn't strand the funds.
+        let p2tr_address = self.signer.get_p2tr_address()?;
+        utxos.extend(self.client.fetch_utxos(&p2tr_address).await?);
+
         /*
             adjust utxos list based on unconfirmed utxos in context
 
@@ -267,13 +333,14 @@ impl Inscriber {
             }
         }
 
-        // iterate over utxos and filter out spent utxos and non p2wpkh utxos
+        // iterate over utxos and filter out spent utxos and utxos not locked to the signer keys
 
         utxos.retain(|utxo| {
             let is_spent = spent_utxos.contains_key(&utxo.0);
             let is_p2wpkh = self.is_p2wpkh(&utxo.1.script_pubkey);
+            let is_p2tr = self.is_p2tr(&utxo.1.script_pubkey);
 
-            !is_spent && is_p2wpkh
+            !is_spent && (is_p2wpkh || is_p2tr)
         });
 
         // add context available utxo (head utxo) to spendable utxos list
@@ -288,7 +355,7 @@ impl Inscriber {
 
                 let reveal_txout = TxOut {
                     value: head_inscription.fee_payer_ctx.fee_payer_utxo_value,
-                    script_pubkey: self.signer.get_p2wpkh_script_pubkey().clone(),
+                    script_pubkey: Self::fee_payer_script_pubkey(head_inscription)?,
                 };
 
                 utxos.push((reveal_change_output, reveal_txout));
@@ -298,15 +365,20 @@ impl Inscriber {
         for (outpoint, txout) in utxos {
             let txin = TxIn {
                 previous_output: outpoint,
-                script_sig: ScriptBuf::default(), // For a p2wpkh script_sig is empty.
+                script_sig: ScriptBuf::default(), // For p2wpkh and p2tr the script_sig is empty.
                 sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
                 witness: Witness::default(), // Get filled in after signing.
             };
 
+            if self.is_p2tr(&txout.script_pubkey) {
+                p2tr_inputs_count += 1;
+            }
+
             commit_tx_inputs.push(txin);
             unlocked_value += txout.value;
             inputs_count += 1;
             utxo_amounts.push(txout.value);
+            prev_outs.push(txout);
         }
 
         debug!("Commit transaction input prepared");
@@ -315,7 +387,9 @@ impl Inscriber {
             commit_tx_inputs,
             unlocked_value,
             inputs_count,
+            p2tr_inputs_count,
             utxo_amounts,
+            prev_outs,
         };
 
         Ok(res)
@@ -328,6 +402,41 @@ impl Inscriber {
         script_pubkey == p2wpkh_script
     }
 
+    // this method checks if the script_pubkey matches with signer's key path p2tr script_pubkey or not
+    fn is_p2tr(&self, script_pubkey: &ScriptBuf) -> bool {
+        script_pubkey == self.signer.get_p2tr_script_pubkey()
+    }
+
+    /// Returns the script of the fee payer UTXO of a pending inscription. It's taken from the reveal tx
+    /// rather than from the current change output type, which may have changed since the inscription.
+    fn fee_payer_script_pubkey(inscription: &InscriptionRequest) -> Result<ScriptBuf> {
+        let reveal_raw_tx = Vec::<u8>::from_hex(&inscription.inscriber_output.reveal_raw_tx)
+            .context("Invalid reveal tx hex in context")?;
+        let reveal_tx: Transaction =
+            consensus::deserialize(&reveal_raw_tx).context("Invalid reveal tx in context")?;
+        let vout = inscription.fee_payer_ctx.fee_payer_utxo_vout;
+        let txout = reveal_tx
+            .output
+            .get(vout as usize)
+            .with_context(|| format!("Reveal tx in context has no output #{vout}"))?;
+        Ok(txout.script_pubkey.clone())
+    }
+
+    fn change_script_pubkey(&self) -> &ScriptBuf {
+        match self.change_output_type {
+            ChangeOutputType::P2WPKH => self.signer.get_p2wpkh_script_pubkey(),
+            ChangeOutputType::P2TR => self.signer.get_p2tr_script_pubkey(),
+        }
+    }
+
+    /// Splits `count` change outputs into `(p2wpkh, p2tr)` counts for the fee calculator.
+    fn split_by_change_type(&self, count: u32) -> (u32, u32) {
+        match self.change_output_type {
+            ChangeOutputType::P2WPKH => (count, 0),
+            ChangeOutputType::P2TR => (0, count),
+        }
+    }
+
     #[instrument(
         skip(self, tx_input_data, inscription_pubkey),
         target = "bitcoin_inscriber"
@@ -353,29 +462,30 @@ impl Inscriber {
             * pending_tx_in_context as u64;
         fee_rate += fee_rate * increase_factor / 100;
 
+        let (change_p2wpkh_outputs, change_p2tr_outputs) =
+            self.split_by_change_type(COMMIT_TX_CHANGE_OUTPUT_COUNT);
+        let p2tr_inputs_count = tx_input_data.p2tr_inputs_count;
+
         let fee_amount = InscriberFeeCalculator::estimate_fee(
-            tx_input_data.inputs_count,
-            COMMIT_TX_P2TR_INPUT_COUNT,
-            COMMIT_TX_P2WPKH_OUTPUT_COUNT,
-            COMMIT_TX_P2TR_OUTPUT_COUNT,
-            vec![],
+            tx_input_data.inputs_count - p2tr_inputs_count,
+            p2tr_inputs_count,
+            change_p2wpkh_outputs,
+            COMMIT_TX_TAPSCRIPT_OUTPUT_COUNT + change_p2tr_outputs,
+            vec![P2TR_KEY_SPEND_WITNESS_SIZE; p2tr_inputs_count as usize],
             fee_rate,
         )?;
 
         let commit_tx_change_output_value = tx_input_data
             .unlocked_value
             .checked_sub(fee_amount)
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Required Amount: {:?}, Spendable Amount: {:?} ",
-                    fee_amount,
-                    tx_input_data.unlocked_value
-                )
+            .ok_or(InscribeError::InsufficientFunds {
+                required: fee_amount,
+                available: tx_input_data.unlocked_value,
             })?;
 
         let commit_tx_change_output = TxOut {
             value: commit_tx_change_output_value,
-            script_pubkey: self.signer.get_p2wpkh_script_pubkey().clone(),
+            script_pubkey: self.change_script_pubkey().clone(),
         };
 
         debug!("Commit transaction output prepared");
@@ -393,11 +503,29 @@ impl Inscriber {
     #[instrument(skip(self), target = "bitcoin_inscriber")]
     async fn get_fee_rate(&self) -> Result<u64> {
         debug!("Getting fee rate");
-        let res = self.client.get_fee_rate(FEE_RATE_CONF_TARGET).await?;
+        let estimate = self.client.get_fee_rate(FEE_RATE_CONF_TARGET).await;
+        let res = match (estimate, self.fee_rate_floor()) {
+            (Ok(fee_rate), Some(floor)) => fee_rate.max(floor),
+            (Ok(fee_rate), None) => fee_rate,
+            (Err(BitcoinError::FeeEstimationFailed(err)), Some(floor)) => {
+                warn!("Fee estimation failed ({err}), using the fee rate floor {floor}");
+                floor
+            }
+            (Err(err), _) => return Err(err.into()),
+        };
         debug!("Fee rate obtained: {}", res);
         Ok(res)
     }
 
+    /// Configured fee rate floor, falling back to [`TEST_NETWORK_MIN_FEE_RATE`] outside of mainnet,
+    /// where the node often has too little data to estimate fees.
+    fn fee_rate_floor(&self) -> Option<u64> {
+        self.min_fee_rate.or(match self.client.get_network() {
+            BitcoinNetwork::Bitcoin => None,
+            _ => Some(TEST_NETWORK_MIN_FEE_RATE),
+        })
+    }
+
     #[instrument(skip(self, input, output), target = "bitcoin_inscriber")]
     fn sign_commit_tx(
         &self,
@@ -426,6 +554,31 @@ impl Inscriber {
 
         let commit_tx_input_len = input.commit_tx_inputs.len();
         for index in 0..commit_tx_input_len {
+            if self.is_p2tr(&input.prev_outs[index].script_pubkey) {
+                let sighash_type = TapSighashType::Default;
+                let sighash = commit_tx_sighasher
+                    .taproot_key_spend_signature_hash(
+                        index,
+                        &Prevouts::All(&input.prev_outs),
+                        sighash_type,
+                    )
+                    .context("Failed to create taproot sighash")?;
+
+                let msg = Message::from_digest(sighash.to_byte_array());
+                let signature = self.signer.sign_schnorr_tweaked(msg)?;
+
+                let signature = bitcoin::taproot::Signature {
+                    signature,
+                    sighash_type,
+                };
+
+                *commit_tx_sighasher
+                    .witness_mut(index)
+                    .ok_or_else(|| anyhow::anyhow!("Failed to get witness"))? =
+                    Witness::p2tr_key_spend(&signature);
+                continue;
+            }
+
             let sighash = commit_tx_sighasher
                 .p2wpkh_signature_hash(
                     index,
@@ -473,7 +626,7 @@ impl Inscriber {
         inscription_data: &InscriptionData,
     ) -> Result<RevealTxInputRes> {
         debug!("Preparing reveal transaction input");
-        let p2wpkh_script_pubkey = self.signer.get_p2wpkh_script_pubkey();
+        let change_script_pubkey = self.change_script_pubkey();
 
         let fee_payer_utxo_input: (OutPoint, TxOut) = (
             OutPoint {
@@ -482,7 +635,7 @@ impl Inscriber {
             },
             TxOut {
                 value: commit_output.commit_tx_change_output.value,
-                script_pubkey: p2wpkh_script_pubkey.clone(),
+                script_pubkey: change_script_pubkey.clone(),
             },
         );
 
@@ -562,12 +715,24 @@ impl Inscriber {
         debug!("Preparing reveal transaction output");
         let fee_rate = self.get_fee_rate().await?;
 
+        let fee_payer_prev_out = &tx_input_data.prev_outs[REVEAL_TX_FEE_INPUT_INDEX as usize];
+        let fee_payer_p2tr_inputs = u32::from(self.is_p2tr(&fee_payer_prev_out.script_pubkey));
+        let fee_payer_p2wpkh_inputs = REVEAL_TX_FEE_PAYER_INPUT_COUNT - fee_payer_p2tr_inputs;
+        let (change_p2wpkh_outputs, change_p2tr_outputs) =
+            self.split_by_change_type(REVEAL_TX_CHANGE_OUTPUT_COUNT);
+
+        let mut p2tr_witness_sizes = vec![inscription_data.script_size];
+        p2tr_witness_sizes.extend(vec![
+            P2TR_KEY_SPEND_WITNESS_SIZE;
+            fee_payer_p2tr_inputs as usize
+        ]);
+
         let fee_amount = InscriberFeeCalculator::estimate_fee(
-            REVEAL_TX_P2WPKH_INPUT_COUNT,
-            REVEAL_TX_P2TR_INPUT_COUNT,
-            REVEAL_TX_P2WPKH_OUTPUT_COUNT + recipient.as_ref().map_or(0, |_| 1),
-            REVEAL_TX_P2TR_OUTPUT_COUNT,
-            vec![inscription_data.script_size],
+            fee_payer_p2wpkh_inputs,
+            REVEAL_TX_TAPSCRIPT_INPUT_COUNT + fee_payer_p2tr_inputs,
+            change_p2wpkh_outputs + recipient.as_ref().map_or(0, |_| 1),
+            change_p2tr_outputs,
+            p2tr_witness_sizes,
             fee_rate,
         )?;
 
@@ -576,18 +741,15 @@ impl Inscriber {
         let reveal_change_amount = tx_input_data
             .unlock_value
             .checked_sub(fee_amount + recipient_amount)
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Required Amount:{:?} Spendable Amount: {:?} ",
-                    fee_amount + recipient_amount,
-                    tx_input_data.unlock_value
-                )
+            .ok_or(InscribeError::InsufficientFunds {
+                required: fee_amount + recipient_amount,
+                available: tx_input_data.unlock_value,
             })?;
 
         // Change output goes back to the inscriber
         let reveal_tx_change_output = TxOut {
             value: reveal_change_amount,
-            script_pubkey: self.signer.get_p2wpkh_script_pubkey().clone(),
+            script_pubkey: self.change_script_pubkey().clone(),
         };
 
         debug!("Reveal transaction output prepared");
@@ -635,41 +797,67 @@ impl Inscriber {
         };
 
         let mut sighasher = SighashCache::new(&mut unsigned_reveal_tx);
-        let sighash_type = EcdsaSighashType::All;
+        let prevouts = Prevouts::All(&input.prev_outs);
 
-        let script_pubkey = self.signer.get_p2wpkh_script_pubkey();
+        let fee_payer_prev_out = &input.prev_outs[REVEAL_TX_FEE_INPUT_INDEX as usize];
+        let fee_payer_witness = if self.is_p2tr(&fee_payer_prev_out.script_pubkey) {
+            let sighash_type = TapSighashType::Default;
 
-        let fee_payer_input_sighash = sighasher
-            .p2wpkh_signature_hash(
-                REVEAL_TX_FEE_INPUT_INDEX as usize,
-                script_pubkey,
-                input.unlock_value,
+            let fee_payer_input_sighash = sighasher
+                .taproot_key_spend_signature_hash(
+                    REVEAL_TX_FEE_INPUT_INDEX as usize,
+                    &prevouts,
+                    sighash_type,
+                )
+                .context("Failed to create taproot sighash")?;
+
+            // Sign the fee payer sighash with the tweaked key
+            let fee_payer_msg = Message::from_digest(fee_payer_input_sighash.to_byte_array());
+            let fee_payer_signature = self.signer.sign_schnorr_tweaked(fee_payer_msg)?;
+
+            let fee_payer_signature = bitcoin::taproot::Signature {
+                signature: fee_payer_signature,
                 sighash_type,
-            )
-            .context("Failed to create sighash")?;
+            };
 
-        // Sign the fee payer sighash using the signer
-        let fee_payer_msg = Message::from(fee_payer_input_sighash);
-        let fee_payer_signature = self.signer.sign_ecdsa(fee_payer_msg)?;
+            Witness::p2tr_key_spend(&fee_payer_signature)
+        } else {
+            let sighash_type = EcdsaSighashType::All;
 
-        // Update the witness stack.
+            let script_pubkey = self.signer.get_p2wpkh_script_pubkey();
 
-        let fee_payer_signature = bitcoin::ecdsa::Signature {
-            signature: fee_payer_signature,
-            sighash_type,
+            let fee_payer_input_sighash = sighasher
+                .p2wpkh_signature_hash(
+                    REVEAL_TX_FEE_INPUT_INDEX as usize,
+                    script_pubkey,
+                    fee_payer_prev_out.value,
+                    sighash_type,
+                )
+                .context("Failed to create sighash")?;
+
+            // Sign the fee payer sighash using the signer
+            let fee_payer_msg = Message::from(fee_payer_input_sighash);
+            let fee_payer_signature = self.signer.sign_ecdsa(fee_payer_msg)?;
+
+            let fee_payer_signature = bitcoin::ecdsa::Signature {
+                signature: fee_payer_signature,
+                sighash_type,
+            };
+
+            let fee_payer_pk = self.signer.get_public_key();
+
+            Witness::p2wpkh(&fee_payer_signature, &fee_payer_pk)
         };
 
-        let fee_payer_pk = self.signer.get_public_key();
+        // Update the witness stack.
 
         *sighasher
             .witness_mut(REVEAL_TX_FEE_INPUT_INDEX as usize)
-            .ok_or_else(|| anyhow::anyhow!("Failed to get witness"))? =
-            Witness::p2wpkh(&fee_payer_signature, &fee_payer_pk);
+            .ok_or_else(|| anyhow::anyhow!("Failed to get witness"))? = fee_payer_witness;
 
         // sign tapscript reveal input
 
         let sighash_type = TapSighashType::All;
-        let prevouts = Prevouts::All(&input.prev_outs);
 
         let reveal_input_sighash = sighasher
             .taproot_script_spend_signature_hash(
@@ -812,7 +1000,8 @@ mod tests {
 
     use super::*;
     use crate::types::{
-        BitcoinClientResult, BitcoinSignerResult, InscriptionMessage, L1BatchDAReferenceInput,
+        BitcoinClientResult, BitcoinError, BitcoinSignerResult, InscriptionMessage,
+        L1BatchDAReferenceInput,
     };
 
     mock! {
@@ -821,14 +1010,19 @@ mod tests {
         impl BitcoinOps for BitcoinOps {
             async fn get_transaction(&self, txid: &Txid) -> BitcoinClientResult<Transaction>;
             async fn fetch_block(&self, block_height: u128) -> BitcoinClientResult<Block>;
-            async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Block>;
+            async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Option<Block>>;
             async fn get_balance(&self, address: &Address) -> BitcoinClientResult<u128>;
             async fn broadcast_signed_transaction(&self, signed_transaction: &str) -> BitcoinClientResult<Txid>;
+            async fn send_raw_transaction(&self, tx: &Transaction) -> Result<Txid, crate::types::BroadcastError>;
             async fn fetch_utxos(&self, address: &Address) -> BitcoinClientResult<Vec<(OutPoint, TxOut)>>;
+            async fn fetch_utxos_with_confirmations(&self, address: &Address) -> BitcoinClientResult<Vec<((OutPoint, TxOut), u32)>>;
             async fn check_tx_confirmation(&self, txid: &Txid, conf_num: u32) -> BitcoinClientResult<bool>;
+            async fn fetch_tx_confirmations(&self, txid: &Txid) -> BitcoinClientResult<Option<u32>>;
             async fn fetch_block_height(&self) -> BitcoinClientResult<u128>;
             async fn get_fee_rate(&self, conf_target: u16) -> BitcoinClientResult<u64>;
             fn get_network(&self) -> BitcoinNetwork;
+            async fn fetch_node_network(&self) -> BitcoinClientResult<BitcoinNetwork>;
+            async fn fetch_tip_time(&self) -> BitcoinClientResult<u64>;
         }
     }
 
@@ -837,8 +1031,11 @@ mod tests {
         impl BitcoinSigner for BitcoinSigner {
             fn sign_ecdsa(&self, msg: Message) -> BitcoinSignerResult<ECDSASignature>;
             fn sign_schnorr(&self, msg: Message) -> BitcoinSignerResult<SchnorrSignature>;
+            fn sign_schnorr_tweaked(&self, msg: Message) -> BitcoinSignerResult<SchnorrSignature>;
             fn get_p2wpkh_address(&self) -> BitcoinSignerResult<Address>;
             fn get_p2wpkh_script_pubkey(&self) -> &ScriptBuf;
+            fn get_p2tr_address(&self) -> BitcoinSignerResult<Address>;
+            fn get_p2tr_script_pubkey(&self) -> &ScriptBuf;
             fn get_secp_ref(&self) -> &Secp256k1<All>;
             fn get_internal_key(&self) -> BitcoinSignerResult<UntweakedPublicKey>;
             fn get_public_key(&self) -> PublicKey;
@@ -859,6 +1056,8 @@ mod tests {
         let address = Address::p2wpkh(&compressed_pk, BitcoinNetwork::Regtest);
         let internal_key = keypair.x_only_public_key().0;
         let script_pubkey = address.script_pubkey();
+        let p2tr_address = Address::p2tr(&secp, internal_key, None, BitcoinNetwork::Regtest);
+        let p2tr_script_pubkey = p2tr_address.script_pubkey();
 
         // Setup mock for get_secp_ref
         signer.expect_get_secp_ref().return_const(secp.clone()); // Returning a reference to a Secp256k1 instance
@@ -869,15 +1068,23 @@ mod tests {
             .returning(move || Ok(internal_key));
 
         // Setup mock for get_p2wpkh_address
+        let p2wpkh_address = address.clone();
         signer
             .expect_get_p2wpkh_address()
-            .returning(move || Ok(address.clone()));
+            .returning(move || Ok(p2wpkh_address.clone()));
 
         // Setup mock for get_p2wpkh_script_pubkey
         signer
             .expect_get_p2wpkh_script_pubkey()
             .return_const(script_pubkey.clone());
 
+        signer
+            .expect_get_p2tr_address()
+            .returning(move || Ok(p2tr_address.clone()));
+        signer
+            .expect_get_p2tr_script_pubkey()
+            .return_const(p2tr_script_pubkey);
+
         // sign_ecdsa
         signer
             .expect_sign_ecdsa()
@@ -894,13 +1101,17 @@ mod tests {
         let pk = sk.public_key(&secp);
         signer.expect_get_public_key().return_const(pk.inner);
 
-        // Setup Client
+        // Setup Client: the network is queried for the inscription data and the fee rate floor
+        // of both transactions.
         client
             .expect_get_network()
-            .times(2)
+            .times(4)
             .return_const(BitcoinNetwork::Regtest);
 
-        client.expect_fetch_utxos().returning(move |_| {
+        client.expect_fetch_utxos().returning(move |utxo_address| {
+            if *utxo_address != address {
+                return Ok(vec![]);
+            }
             let fake_outpoint = OutPoint {
                 txid: Txid::all_zeros(),
                 vout: 0,
@@ -924,6 +1135,8 @@ mod tests {
             client: Arc::new(client),
             signer: Arc::new(signer),
             context,
+            change_output_type: ChangeOutputType::P2WPKH,
+            min_fee_rate: None,
         }
     }
 
@@ -948,4 +1161,402 @@ mod tests {
         assert_ne!(res.final_commit_tx.txid, Txid::all_zeros());
         assert_ne!(res.final_reveal_tx.txid, Txid::all_zeros());
     }
+
+    #[tokio::test]
+    async fn test_inscriber_inscribe_with_p2tr_change() {
+        let signer = test_utils::MockBitcoinSigner::new();
+        let p2wpkh_address = signer.address.clone();
+        let p2wpkh_script = signer.script.clone();
+        let p2tr_script = signer.p2tr_script.clone();
+
+        let mut client = MockBitcoinOps::new();
+        client
+            .expect_get_network()
+            .return_const(BitcoinNetwork::Regtest);
+        client.expect_fetch_utxos().returning(move |address| {
+            if *address != p2wpkh_address {
+                return Ok(vec![]);
+            }
+            let fake_outpoint = OutPoint {
+                txid: Txid::all_zeros(),
+                vout: 0,
+            };
+            let fake_txout = TxOut {
+                value: Amount::from_btc(2.0).unwrap(),
+                script_pubkey: p2wpkh_script.clone(),
+            };
+            Ok(vec![(fake_outpoint, fake_txout)])
+        });
+        client.expect_get_fee_rate().returning(|_| Ok(10));
+        client
+            .expect_check_tx_confirmation()
+            .returning(|_, _| Ok(false));
+        client
+            .expect_broadcast_signed_transaction()
+            .returning(|_| Ok(Txid::all_zeros()));
+
+        let mut inscriber = Inscriber {
+            client: Arc::new(client),
+            signer: Arc::new(signer),
+            context: InscriberContext::default(),
+            change_output_type: ChangeOutputType::P2WPKH,
+            min_fee_rate: None,
+        }
+        .with_change_output_type(ChangeOutputType::P2TR);
+
+        let inscribe_message = InscriptionMessage::L1BatchDAReference(L1BatchDAReferenceInput {
+            l1_batch_hash: zksync_basic_types::H256([0; 32]),
+            l1_batch_index: zksync_basic_types::L1BatchNumber(0_u32),
+            da_identifier: "da_identifier_celestia".to_string(),
+            blob_id: "batch_temp_blob_id".to_string(),
+        });
+
+        let first = inscriber
+            .inscribe(inscribe_message.clone(), InscriptionConfig::default())
+            .await
+            .unwrap();
+        // The second inscription is funded by the P2TR change of the first reveal tx.
+        let second = inscriber
+            .inscribe(inscribe_message, InscriptionConfig::default())
+            .await
+            .unwrap();
+
+        for info in [&first, &second] {
+            let commit_tx = &info.final_commit_tx.tx;
+            let reveal_tx = &info.final_reveal_tx.tx;
+            assert_eq!(
+                commit_tx.output[COMMIT_TX_CHANGE_OUTPUT_INDEX as usize].script_pubkey,
+                p2tr_script
+            );
+            assert_eq!(
+                reveal_tx.output[REVEAL_TX_CHANGE_OUTPUT_INDEX as usize].script_pubkey,
+                p2tr_script
+            );
+            // Key path spend of the P2TR fee payer only carries the signature.
+            assert_eq!(
+                reveal_tx.input[REVEAL_TX_FEE_INPUT_INDEX as usize]
+                    .witness
+                    .len(),
+                1
+            );
+
+            let reveal_fee_rate = info.reveal_tx_output_info.reveal_fee_rate;
+            assert!(
+                info.reveal_tx_output_info._reveal_fee.to_sat()
+                    >= reveal_tx.vsize() as u64 * reveal_fee_rate
+            );
+        }
+
+        let commit_tx = &second.final_commit_tx.tx;
+        assert_eq!(commit_tx.input.len(), 1);
+        assert_eq!(commit_tx.input[0].witness.len(), 1);
+
+        // The estimate must cover the actual size without overpaying by more than a few vbytes.
+        let commit_fee_rate = second.commit_tx_output_info.commit_tx_fee_rate;
+        let estimated_vsize =
+            second.commit_tx_output_info._commit_tx_fee.to_sat() / commit_fee_rate;
+        let actual_vsize = commit_tx.vsize() as u64;
+        assert!(estimated_vsize >= actual_vsize);
+        assert!(estimated_vsize - actual_vsize <= 8);
+    }
+
+    #[tokio::test]
+    async fn test_inscriber_spends_p2tr_context_utxo_after_switching_to_p2wpkh() {
+        let signer = test_utils::MockBitcoinSigner::new();
+        let p2wpkh_address = signer.address.clone();
+        let p2wpkh_script = signer.script.clone();
+        let p2tr_script = signer.p2tr_script.clone();
+
+        let mut client = MockBitcoinOps::new();
+        client
+            .expect_get_network()
+            .return_const(BitcoinNetwork::Regtest);
+        client.expect_fetch_utxos().returning(move |address| {
+            if *address != p2wpkh_address {
+                return Ok(vec![]);
+            }
+            let fake_outpoint = OutPoint {
+                txid: Txid::all_zeros(),
+                vout: 0,
+            };
+            let fake_txout = TxOut {
+                value: Amount::from_btc(2.0).unwrap(),
+                script_pubkey: p2wpkh_script.clone(),
+            };
+            Ok(vec![(fake_outpoint, fake_txout)])
+        });
+        client.expect_get_fee_rate().returning(|_| Ok(10));
+        client
+            .expect_check_tx_confirmation()
+            .returning(|_, _| Ok(false));
+        client
+            .expect_broadcast_signed_transaction()
+            .returning(|_| Ok(Txid::all_zeros()));
+
+        let mut inscriber = Inscriber {
+            client: Arc::new(client),
+            signer: Arc::new(signer),
+            context: InscriberContext::default(),
+            change_output_type: ChangeOutputType::P2TR,
+            min_fee_rate: None,
+        };
+        let first = inscriber
+            .inscribe(get_inscription_message(), InscriptionConfig::default())
+            .await
+            .unwrap();
+
+        // The P2TR change of the first reveal tx is still pending when the change type is switched.
+        let mut inscriber = inscriber.with_change_output_type(ChangeOutputType::P2WPKH);
+        let second = inscriber
+            .inscribe(get_inscription_message(), InscriptionConfig::default())
+            .await
+            .unwrap();
+
+        let commit_tx = &second.final_commit_tx.tx;
+        assert_eq!(commit_tx.input.len(), 1);
+        assert_eq!(
+            commit_tx.input[0].previous_output,
+            OutPoint {
+                txid: first.final_reveal_tx.txid,
+                vout: REVEAL_TX_CHANGE_OUTPUT_INDEX,
+            }
+        );
+        assert_eq!(
+            second.commit_tx_input_info.prev_outs[0].script_pubkey,
+            p2tr_script
+        );
+        assert_eq!(second.commit_tx_input_info.p2tr_inputs_count, 1);
+        // The context UTXO is still spent via the P2TR key path.
+        assert_eq!(commit_tx.input[0].witness.len(), 1);
+
+        let commit_fee_rate = second.commit_tx_output_info.commit_tx_fee_rate;
+        let estimated_vsize =
+            second.commit_tx_output_info._commit_tx_fee.to_sat() / commit_fee_rate;
+        let actual_vsize = commit_tx.vsize() as u64;
+        assert!(estimated_vsize >= actual_vsize);
+        assert!(estimated_vsize - actual_vsize <= 8);
+
+        // New change outputs use the new type, and the P2WPKH fee payer is signed accordingly.
+        let reveal_tx = &second.final_reveal_tx.tx;
+        assert_eq!(
+            commit_tx.output[COMMIT_TX_CHANGE_OUTPUT_INDEX as usize].script_pubkey,
+            *inscriber.signer.get_p2wpkh_script_pubkey()
+        );
+        assert_eq!(
+            reveal_tx.output[REVEAL_TX_CHANGE_OUTPUT_INDEX as usize].script_pubkey,
+            *inscriber.signer.get_p2wpkh_script_pubkey()
+        );
+        assert_eq!(
+            reveal_tx.input[REVEAL_TX_FEE_INPUT_INDEX as usize]
+                .witness
+                .len(),
+            2
+        );
+    }
+
+    fn get_inscriber_with_utxo_value(utxo_value: Amount, fail_rpc: bool) -> Inscriber {
+        let signer = test_utils::MockBitcoinSigner::new();
+        let p2wpkh_address = signer.address.clone();
+        let p2wpkh_script = signer.script.clone();
+
+        let mut client = MockBitcoinOps::new();
+        client
+            .expect_get_network()
+            .return_const(BitcoinNetwork::Regtest);
+        client.expect_fetch_utxos().returning(move |address| {
+            if fail_rpc {
+                return Err(BitcoinError::RpcUnavailable(
+                    "connection refused".to_string(),
+                ));
+            }
+            if *address != p2wpkh_address {
+                return Ok(vec![]);
+            }
+            let fake_outpoint = OutPoint {
+                txid: Txid::all_zeros(),
+                vout: 0,
+            };
+            let fake_txout = TxOut {
+                value: utxo_value,
+                script_pubkey: p2wpkh_script.clone(),
+            };
+            Ok(vec![(fake_outpoint, fake_txout)])
+        });
+        client.expect_get_fee_rate().returning(|_| Ok(10));
+
+        Inscriber {
+            client: Arc::new(client),
+            signer: Arc::new(signer),
+            context: InscriberContext::default(),
+            change_output_type: ChangeOutputType::P2WPKH,
+            min_fee_rate: None,
+        }
+    }
+
+    fn get_inscription_message() -> InscriptionMessage {
+        InscriptionMessage::L1BatchDAReference(L1BatchDAReferenceInput {
+            l1_batch_hash: zksync_basic_types::H256([0; 32]),
+            l1_batch_index: zksync_basic_types::L1BatchNumber(0_u32),
+            da_identifier: "da_identifier_celestia".to_string(),
+            blob_id: "batch_temp_blob_id".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_inscriber_inscribe_insufficient_funds() {
+        let mut inscriber = get_inscriber_with_utxo_value(Amount::from_sat(100), false);
+
+        let err = inscriber
+            .inscribe(get_inscription_message(), InscriptionConfig::default())
+            .await
+            .unwrap_err();
+
+        match &err {
+            InscribeError::InsufficientFunds {
+                required,
+                available,
+            } => {
+                assert_eq!(*available, Amount::from_sat(100));
+                assert!(required > available);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(!err.is_retriable());
+    }
+
+    #[tokio::test]
+    async fn test_inscriber_inscribe_rpc_failure() {
+        let mut inscriber = get_inscriber_with_utxo_value(Amount::from_btc(2.0).unwrap(), true);
+
+        let err = inscriber
+            .inscribe(get_inscription_message(), InscriptionConfig::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            InscribeError::Rpc(BitcoinError::RpcUnavailable(_))
+        ));
+        assert!(err.is_retriable());
+    }
+
+    #[test]
+    fn test_transient_bitcoin_errors_are_retriable() {
+        let errors = [
+            BitcoinError::RpcUnavailable("connection refused".to_string()),
+            BitcoinError::RpcTimeout("getblockcount".to_string()),
+        ];
+        for error in errors {
+            let err = InscribeError::from(error);
+            assert!(matches!(err, InscribeError::Rpc(_)), "{err:?}");
+            assert!(err.is_retriable(), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_node_error_is_not_retriable() {
+        let error = BitcoinError::Rpc("No such mempool or blockchain transaction".to_string());
+        let err = InscribeError::from(error);
+        assert!(matches!(err, InscribeError::Rpc(_)), "{err:?}");
+        assert!(!err.is_retriable(), "{err}");
+    }
+
+    #[test]
+    fn test_rejected_transaction_is_not_retriable() {
+        let error = BitcoinError::TransactionRejected(-26, "min relay fee not met".to_string());
+        let err = InscribeError::from(anyhow::Error::from(error));
+
+        assert!(matches!(
+            &err,
+            InscribeError::Rejected { code: -26, reason } if reason == "min relay fee not met"
+        ));
+        assert!(!err.is_retriable());
+    }
+
+    #[test]
+    fn test_client_errors_are_not_retriable() {
+        let errors = [
+            BitcoinError::InvalidAddress("bc1invalid".to_string()),
+            BitcoinError::SigningError("bad sighash".to_string()),
+            BitcoinError::TransactionBuildingError("no inputs".to_string()),
+            BitcoinError::FeeEstimationFailed("insufficient data".to_string()),
+        ];
+        for error in errors {
+            let err = InscribeError::from(error);
+            assert!(matches!(err, InscribeError::Client(_)), "{err:?}");
+            assert!(!err.is_retriable(), "{err}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inscriber_applies_fee_rate_floor() {
+        let signer = test_utils::MockBitcoinSigner::new();
+        let p2wpkh_address = signer.address.clone();
+        let p2wpkh_script = signer.script.clone();
+
+        let mut client = MockBitcoinOps::new();
+        client
+            .expect_get_network()
+            .return_const(BitcoinNetwork::Regtest);
+        client.expect_fetch_utxos().returning(move |address| {
+            if *address != p2wpkh_address {
+                return Ok(vec![]);
+            }
+            let fake_outpoint = OutPoint {
+                txid: Txid::all_zeros(),
+                vout: 0,
+            };
+            let fake_txout = TxOut {
+                value: Amount::from_btc(2.0).unwrap(),
+                script_pubkey: p2wpkh_script.clone(),
+            };
+            Ok(vec![(fake_outpoint, fake_txout)])
+        });
+        client.expect_get_fee_rate().returning(|_| {
+            Err(BitcoinError::FeeEstimationFailed(
+                "Insufficient data or no feerate found".to_string(),
+            ))
+        });
+        client
+            .expect_broadcast_signed_transaction()
+            .returning(|_| Ok(Txid::all_zeros()));
+
+        let mut inscriber = Inscriber {
+            client: Arc::new(client),
+            signer: Arc::new(signer),
+            context: InscriberContext::default(),
+            change_output_type: ChangeOutputType::P2WPKH,
+            min_fee_rate: None,
+        };
+
+        // Regtest falls back to the default floor when the node can't estimate fees.
+        let info = inscriber
+            .prepare_inscribe(
+                &get_inscription_message(),
+                InscriptionConfig::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            info.commit_tx_output_info.commit_tx_fee_rate,
+            TEST_NETWORK_MIN_FEE_RATE
+        );
+        assert_eq!(
+            info.reveal_tx_output_info.reveal_fee_rate,
+            TEST_NETWORK_MIN_FEE_RATE
+        );
+
+        let mut inscriber = inscriber.with_min_fee_rate(Some(25));
+        let info = inscriber
+            .prepare_inscribe(
+                &get_inscription_message(),
+                InscriptionConfig::default(),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(info.commit_tx_output_info.commit_tx_fee_rate, 25);
+        assert_eq!(info.reveal_tx_output_info.reveal_fee_rate, 25);
+    }
 }
@@ -3,7 +3,7 @@ use bitcoin::{
     hashes::Hash,
     key::UntweakedPublicKey,
     opcodes::{all, OP_0, OP_FALSE},
-    script::{Builder as ScriptBuilder, PushBytesBuf},
+    script::{Builder as ScriptBuilder, Instruction, PushBytesBuf},
     secp256k1::{Secp256k1, Signing, Verification},
     taproot::{TaprootBuilder, TaprootSpendInfo},
     Address, Network, ScriptBuf,
@@ -12,6 +12,12 @@ use tracing::{debug, instrument};
 
 use crate::types;
 
+/// Maximum size of a single data push; larger pushes fail tapscript execution.
+const MAX_SCRIPT_ELEMENT_SIZE: usize = 520;
+/// Maximum weight of a transaction relayed by Bitcoin Core. Witness bytes weigh 1 unit each,
+/// so the reveal script alone must fit into it.
+const MAX_STANDARD_TX_WEIGHT: usize = 400_000;
+
 pub struct InscriptionData {
     pub inscription_script: ScriptBuf,
     pub script_size: usize,
@@ -39,6 +45,7 @@ impl InscriptionData {
 
         let (inscription_script, script_size) =
             Self::complete_inscription(basic_script, inscription_message, network)?;
+        Self::check_standardness(&inscription_script)?;
 
         let (script_pubkey, taproot_spend_info) = Self::construct_inscription_commitment_data(
             secp,
@@ -56,6 +63,31 @@ impl InscriptionData {
         })
     }
 
+    /// Checks the inscription script against the limits that would otherwise only surface as
+    /// a rejection when broadcasting the reveal transaction.
+    fn check_standardness(inscription_script: &ScriptBuf) -> Result<()> {
+        if inscription_script.len() > MAX_STANDARD_TX_WEIGHT {
+            anyhow::bail!(
+                "Inscription script is {} bytes, exceeding the standard transaction weight of {}",
+                inscription_script.len(),
+                MAX_STANDARD_TX_WEIGHT
+            );
+        }
+        for instruction in inscription_script.instructions() {
+            let instruction = instruction.context("Inscription script is malformed")?;
+            if let Instruction::PushBytes(data) = instruction {
+                if data.len() > MAX_SCRIPT_ELEMENT_SIZE {
+                    anyhow::bail!(
+                        "Inscription script pushes a {}-byte element, exceeding the {}-byte limit",
+                        data.len(),
+                        MAX_SCRIPT_ELEMENT_SIZE
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     #[instrument(
         skip(secp, inscription_script, internal_key),
         target = "bitcoin_inscriber::script_builder"
@@ -298,3 +330,43 @@ impl InscriptionData {
         encoded
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::secp256k1::{Keypair, SecretKey};
+    use zksync_types::Address as EVMAddress;
+
+    use super::*;
+
+    fn l1_to_l2_message(call_data_len: usize) -> types::InscriptionMessage {
+        types::InscriptionMessage::L1ToL2Message(types::L1ToL2MessageInput {
+            receiver_l2_address: EVMAddress::zero(),
+            l2_contract_address: EVMAddress::zero(),
+            call_data: vec![0xab; call_data_len],
+        })
+    }
+
+    fn build(message: &types::InscriptionMessage) -> Result<InscriptionData> {
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+        let (internal_key, _) = Keypair::from_secret_key(&secp, &secret_key).x_only_public_key();
+        InscriptionData::new(message, &secp, internal_key, Network::Regtest)
+    }
+
+    #[test]
+    fn inscription_within_limits_is_built() {
+        let data = build(&l1_to_l2_message(MAX_SCRIPT_ELEMENT_SIZE)).unwrap();
+        assert_eq!(data.script_size, data.inscription_script.len());
+    }
+
+    #[test]
+    fn oversized_push_is_rejected() {
+        let err = build(&l1_to_l2_message(MAX_SCRIPT_ELEMENT_SIZE + 1))
+            .err()
+            .expect("oversized call data must be rejected");
+        assert_eq!(
+            err.to_string(),
+            "Inscription script pushes a 521-byte element, exceeding the 520-byte limit"
+        );
+    }
+}
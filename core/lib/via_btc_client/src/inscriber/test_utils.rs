@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
 use async_trait::async_trait;
 use bitcoin::{
@@ -11,6 +14,7 @@ use bitcoin::{
     Address, Block, BlockHash, CompressedPublicKey, Network, OutPoint, PrivateKey, ScriptBuf,
     Transaction, TxOut, Txid,
 };
+use zksync_config::configs::via_btc_sender::ChangeOutputType;
 
 use super::Inscriber;
 use crate::{
@@ -25,8 +29,14 @@ pub struct MockBitcoinOpsConfig {
     pub fee_rate: u64,
     pub block_height: u128,
     pub tx_confirmation: bool,
+    pub tx_in_mempool: bool,
+    pub tip_time: u64,
     pub transaction: Option<Transaction>,
+    /// Transactions returned by `get_transaction` by their ID. Takes precedence over `transaction`.
+    pub transactions: HashMap<Txid, Transaction>,
     pub block: Option<Block>,
+    /// IDs of the transactions passed to `send_raw_transaction`, shared by all clones of the config.
+    pub broadcast_txids: Arc<Mutex<Vec<Txid>>>,
 }
 
 impl MockBitcoinOpsConfig {
@@ -37,6 +47,18 @@ impl MockBitcoinOpsConfig {
     pub fn set_tx_confirmation(&mut self, tx_confirmation: bool) {
         self.tx_confirmation = tx_confirmation;
     }
+
+    pub fn set_tx_in_mempool(&mut self, tx_in_mempool: bool) {
+        self.tx_in_mempool = tx_in_mempool;
+    }
+
+    pub fn add_transaction(&mut self, tx: Transaction) {
+        self.transactions.insert(tx.compute_txid(), tx);
+    }
+
+    pub fn broadcast_txids(&self) -> Vec<Txid> {
+        self.broadcast_txids.lock().unwrap().clone()
+    }
 }
 
 #[derive(Debug, Default)]
@@ -46,8 +68,12 @@ pub struct MockBitcoinOps {
     pub fee_rate: u64,
     pub block_height: u128,
     pub tx_confirmation: bool,
+    pub tx_in_mempool: bool,
+    pub tip_time: u64,
     pub transaction: Option<Transaction>,
+    pub transactions: HashMap<Txid, Transaction>,
     pub block: Option<Block>,
+    pub broadcast_txids: Arc<Mutex<Vec<Txid>>>,
 }
 
 impl MockBitcoinOps {
@@ -58,8 +84,12 @@ impl MockBitcoinOps {
             fee_rate: config.fee_rate,
             block_height: config.block_height,
             tx_confirmation: config.tx_confirmation,
+            tx_in_mempool: config.tx_in_mempool,
+            tip_time: config.tip_time,
             transaction: config.transaction,
+            transactions: config.transactions,
             block: config.block,
+            broadcast_txids: config.broadcast_txids,
         }
     }
 }
@@ -77,8 +107,34 @@ impl BitcoinOps for MockBitcoinOps {
         BitcoinClientResult::Ok(Txid::from_slice(&[0u8; 32]).unwrap())
     }
 
-    async fn fetch_utxos(&self, _address: &Address) -> BitcoinClientResult<Vec<(OutPoint, TxOut)>> {
-        BitcoinClientResult::Ok(self.utxos.clone())
+    async fn send_raw_transaction(&self, tx: &Transaction) -> Result<Txid, types::BroadcastError> {
+        let txid = tx.compute_txid();
+        self.broadcast_txids.lock().unwrap().push(txid);
+        Ok(txid)
+    }
+
+    async fn fetch_utxos(&self, address: &Address) -> BitcoinClientResult<Vec<(OutPoint, TxOut)>> {
+        let utxos = self
+            .utxos
+            .iter()
+            .filter(|(_, txout)| address.matches_script_pubkey(&txout.script_pubkey))
+            .cloned()
+            .collect();
+        BitcoinClientResult::Ok(utxos)
+    }
+
+    async fn fetch_utxos_with_confirmations(
+        &self,
+        _address: &Address,
+    ) -> BitcoinClientResult<Vec<((OutPoint, TxOut), u32)>> {
+        let confirmations = if self.tx_confirmation { 1 } else { 0 };
+        BitcoinClientResult::Ok(
+            self.utxos
+                .iter()
+                .cloned()
+                .map(|utxo| (utxo, confirmations))
+                .collect(),
+        )
     }
 
     async fn check_tx_confirmation(
@@ -89,6 +145,17 @@ impl BitcoinOps for MockBitcoinOps {
         BitcoinClientResult::Ok(self.tx_confirmation)
     }
 
+    async fn fetch_tx_confirmations(&self, _txid: &Txid) -> BitcoinClientResult<Option<u32>> {
+        let confirmations = if self.tx_in_mempool {
+            Some(0)
+        } else if self.tx_confirmation {
+            Some(1)
+        } else {
+            None
+        };
+        BitcoinClientResult::Ok(confirmations)
+    }
+
     async fn fetch_block_height(&self) -> BitcoinClientResult<u128> {
         BitcoinClientResult::Ok(self.block_height)
     }
@@ -101,16 +168,28 @@ impl BitcoinOps for MockBitcoinOps {
         Network::Bitcoin
     }
 
+    async fn fetch_node_network(&self) -> BitcoinClientResult<Network> {
+        BitcoinClientResult::Ok(self.get_network())
+    }
+
+    async fn fetch_tip_time(&self) -> BitcoinClientResult<u64> {
+        BitcoinClientResult::Ok(self.tip_time)
+    }
+
     async fn fetch_block(&self, _block_height: u128) -> BitcoinClientResult<Block> {
         BitcoinClientResult::Ok(self.block.clone().expect("Block not set"))
     }
 
-    async fn get_transaction(&self, _txid: &Txid) -> BitcoinClientResult<Transaction> {
-        BitcoinClientResult::Ok(self.transaction.clone().expect("No transaction found"))
+    async fn get_transaction(&self, txid: &Txid) -> BitcoinClientResult<Transaction> {
+        let tx = self.transactions.get(txid).or(self.transaction.as_ref());
+        BitcoinClientResult::Ok(tx.cloned().expect("No transaction found"))
     }
 
-    async fn fetch_block_by_hash(&self, _block_hash: &BlockHash) -> BitcoinClientResult<Block> {
-        BitcoinClientResult::Ok(self.block.clone().expect("No block found"))
+    async fn fetch_block_by_hash(
+        &self,
+        _block_hash: &BlockHash,
+    ) -> BitcoinClientResult<Option<Block>> {
+        BitcoinClientResult::Ok(self.block.clone())
     }
 }
 
@@ -120,6 +199,8 @@ pub struct MockBitcoinSigner {
     pub schnorr_signature: SchnorrSignature,
     pub address: Address,
     pub script: ScriptBuf,
+    pub p2tr_address: Address,
+    pub p2tr_script: ScriptBuf,
     pub secp: Secp256k1<All>,
     pub internal_key: UntweakedPublicKey,
     pub public_key: PublicKey,
@@ -135,12 +216,16 @@ impl Default for MockBitcoinSigner {
         let address = Address::p2wpkh(&compressed_pk, Network::Regtest);
         let internal_key = keypair.x_only_public_key().0;
         let script = address.script_pubkey();
+        let p2tr_address = Address::p2tr(&secp, internal_key, None, Network::Regtest);
+        let p2tr_script = p2tr_address.script_pubkey();
 
         Self {
             ecdsa_signature: ECDSASignature::from_compact(&[0; 64]).unwrap(),
             schnorr_signature: SchnorrSignature::from_slice(&[0; 64]).unwrap(),
             address,
             script,
+            p2tr_address,
+            p2tr_script,
             secp,
             internal_key,
             public_key: compressed_pk.0,
@@ -158,12 +243,16 @@ impl MockBitcoinSigner {
         let address = Address::p2wpkh(&compressed_pk, Network::Regtest);
         let internal_key = keypair.x_only_public_key().0;
         let script = address.script_pubkey();
+        let p2tr_address = Address::p2tr(&secp, internal_key, None, Network::Regtest);
+        let p2tr_script = p2tr_address.script_pubkey();
 
         Self {
             ecdsa_signature: ECDSASignature::from_compact(&[0; 64]).unwrap(),
             schnorr_signature: SchnorrSignature::from_slice(&[0; 64]).unwrap(),
             address,
             script,
+            p2tr_address,
+            p2tr_script,
             secp,
             internal_key,
             public_key: compressed_pk.0,
@@ -181,6 +270,10 @@ impl BitcoinSigner for MockBitcoinSigner {
         BitcoinClientResult::Ok(self.schnorr_signature)
     }
 
+    fn sign_schnorr_tweaked(&self, _: Message) -> types::BitcoinSignerResult<SchnorrSignature> {
+        BitcoinClientResult::Ok(self.schnorr_signature)
+    }
+
     fn get_p2wpkh_address(&self) -> types::BitcoinSignerResult<Address> {
         BitcoinClientResult::Ok(self.address.clone())
     }
@@ -189,6 +282,14 @@ impl BitcoinSigner for MockBitcoinSigner {
         &self.script
     }
 
+    fn get_p2tr_address(&self) -> types::BitcoinSignerResult<Address> {
+        BitcoinClientResult::Ok(self.p2tr_address.clone())
+    }
+
+    fn get_p2tr_script_pubkey(&self) -> &ScriptBuf {
+        &self.p2tr_script
+    }
+
     fn get_secp_ref(&self) -> &Secp256k1<All> {
         &self.secp
     }
@@ -211,5 +312,7 @@ pub fn get_mock_inscriber_and_conditions(config: MockBitcoinOpsConfig) -> Inscri
         client: Arc::new(client),
         signer: Arc::new(signer),
         context,
+        change_output_type: ChangeOutputType::P2WPKH,
+        min_fee_rate: None,
     }
 }
@@ -8,3 +8,5 @@ pub mod inscriber;
 pub mod regtest;
 pub(crate) mod signer;
 pub(crate) mod utils;
+#[cfg(feature = "zmq")]
+pub mod zmq;
@@ -102,12 +102,24 @@ impl Drop for BitcoinRegtest {
 
 #[cfg(test)]
 mod tests {
-    use bitcoin::CompressedPublicKey;
-    use bitcoincore_rpc::Auth;
-    use secp256k1::Secp256k1;
+    use bitcoin::{
+        absolute::LockTime, hashes::Hash, sighash::SighashCache, transaction::Version, Amount,
+        BlockHash, CompressedPublicKey, EcdsaSighashType, OutPoint, ScriptBuf, Sequence,
+        Transaction, TxIn, TxOut, Txid, Witness,
+    };
+    use bitcoincore_rpc::{Auth, RpcApi};
+    use secp256k1::{Message, Secp256k1};
+    use zksync_basic_types::{L1BatchNumber, H256};
+    use zksync_config::configs::via_btc_sender::ChangeOutputType;
 
     use super::*;
-    use crate::{client::BitcoinClient, traits::BitcoinOps};
+    use crate::{
+        client::BitcoinClient,
+        inscriber::Inscriber,
+        signer::KeyManager,
+        traits::{BitcoinOps, BitcoinSigner},
+        types::{BroadcastError, InscriptionConfig, InscriptionMessage, L1BatchDAReferenceInput},
+    };
 
     #[tokio::test]
     async fn test_bitcoin_regtest() {
@@ -140,4 +152,383 @@ mod tests {
             .expect("Failed to get balance of test address");
         assert!(balance > 300000);
     }
+
+    /// Signs a transaction spending a P2WPKH output of the test address back to it.
+    fn build_spend_to_self(
+        regtest: &BitcoinRegtest,
+        outpoint: OutPoint,
+        txout: &TxOut,
+        fee: Amount,
+    ) -> Transaction {
+        let address = regtest.get_address();
+        let private_key = regtest.get_private_key();
+        let secp = Secp256k1::new();
+        let compressed_public_key = CompressedPublicKey::from_private_key(&secp, private_key)
+            .expect("Failed to generate address from test private_key");
+
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: outpoint,
+                script_sig: ScriptBuf::default(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::default(),
+            }],
+            output: vec![TxOut {
+                value: txout.value - fee,
+                script_pubkey: address.script_pubkey(),
+            }],
+        };
+        let sighash = SighashCache::new(&tx)
+            .p2wpkh_signature_hash(0, &txout.script_pubkey, txout.value, EcdsaSighashType::All)
+            .expect("Failed to compute sighash");
+        let signature = bitcoin::ecdsa::Signature {
+            signature: secp.sign_ecdsa(&Message::from(sighash), &private_key.inner),
+            sighash_type: EcdsaSighashType::All,
+        };
+        tx.input[0].witness = Witness::p2wpkh(&signature, &compressed_public_key.0);
+        tx
+    }
+
+    /// Returns the chain height together with the depth reported for `outpoint` at that height.
+    /// The regtest node mines blocks in the background, so the height is re-read until it's stable.
+    async fn utxo_depth_at_tip(
+        client: &BitcoinClient,
+        address: &Address,
+        outpoint: OutPoint,
+    ) -> (u32, Option<u32>) {
+        loop {
+            let height = client.fetch_block_height().await.unwrap();
+            let depth = client
+                .fetch_utxos_with_confirmations(address)
+                .await
+                .expect("Failed to fetch utxos with confirmations")
+                .into_iter()
+                .find_map(|((utxo, _), confirmations)| (utxo == outpoint).then_some(confirmations));
+            if client.fetch_block_height().await.unwrap() == height {
+                return (height as u32, depth);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_utxos_with_confirmations() {
+        const BLOCKS_TO_MINE: u64 = 5;
+
+        let regtest = BitcoinRegtest::new().expect("Failed to create BitcoinRegtest");
+        let auth = Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string());
+        let client = BitcoinClient::new(&regtest.get_url(), Network::Regtest, auth.clone())
+            .expect("Failed create rpc client");
+        let rpc = bitcoincore_rpc::Client::new(&regtest.get_url(), auth)
+            .expect("Failed create raw rpc client");
+        let miner_address = regtest
+            .get_miner_address()
+            .expect("Failed to get miner address");
+        let address = regtest.get_address();
+
+        // Create a fresh UTXO by spending an existing one back to the test address.
+        let (outpoint, txout) = client
+            .fetch_utxos(address)
+            .await
+            .expect("Failed to fetch utxos")
+            .into_iter()
+            .next()
+            .expect("No utxos for the test address");
+        let tx = build_spend_to_self(&regtest, outpoint, &txout, Amount::from_sat(10_000));
+        let txid = client
+            .send_raw_transaction(&tx)
+            .await
+            .expect("Failed to broadcast tx");
+        let fresh_outpoint = OutPoint { txid, vout: 0 };
+
+        let (_, depth) = utxo_depth_at_tip(&client, address, fresh_outpoint).await;
+        // The tx may already be mined by the background miner.
+        assert!(depth.unwrap_or(0) <= 1, "{depth:?}");
+
+        rpc.generate_to_address(1, &miner_address)
+            .expect("Failed to mine block");
+        let inclusion_block = rpc
+            .get_raw_transaction_info(&txid, None)
+            .expect("Failed to get tx info")
+            .blockhash
+            .expect("Tx is not mined");
+        let inclusion_height = rpc
+            .get_block_header_info(&inclusion_block)
+            .expect("Failed to get block header")
+            .height as u32;
+        let (height, depth) = utxo_depth_at_tip(&client, address, fresh_outpoint).await;
+        let depth = depth.expect("Mined UTXO is not reported");
+        assert_eq!(depth, height - inclusion_height + 1);
+
+        rpc.generate_to_address(BLOCKS_TO_MINE, &miner_address)
+            .expect("Failed to mine blocks");
+        let (new_height, new_depth) = utxo_depth_at_tip(&client, address, fresh_outpoint).await;
+        let new_depth = new_depth.expect("Mined UTXO is not reported");
+        assert!(new_height >= height + BLOCKS_TO_MINE as u32);
+        // Without blocks from the background miner, this is `BLOCKS_TO_MINE + 1`.
+        assert_eq!(new_depth, new_height - inclusion_height + 1);
+        assert!(new_depth > BLOCKS_TO_MINE as u32);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_block_by_hash() {
+        let regtest = BitcoinRegtest::new().expect("Failed to create BitcoinRegtest");
+        let client = BitcoinClient::new(
+            &regtest.get_url(),
+            Network::Regtest,
+            Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string()),
+        )
+        .expect("Failed create rpc client");
+
+        let block_height = client
+            .fetch_block_height()
+            .await
+            .expect("Failed to get block count");
+        let block = client
+            .fetch_block(block_height as u128)
+            .await
+            .expect("Failed to fetch block by height");
+
+        let fetched = client
+            .fetch_block_by_hash(&block.block_hash())
+            .await
+            .expect("Failed to fetch block by hash");
+        assert_eq!(fetched, Some(block));
+
+        let unknown = client
+            .fetch_block_by_hash(&BlockHash::all_zeros())
+            .await
+            .expect("Unknown block hash should not be an error");
+        assert_eq!(unknown, None);
+    }
+
+    fn test_inscription_message() -> InscriptionMessage {
+        InscriptionMessage::L1BatchDAReference(L1BatchDAReferenceInput {
+            l1_batch_hash: H256::zero(),
+            l1_batch_index: L1BatchNumber(1),
+            da_identifier: "da_identifier_celestia".to_string(),
+            blob_id: "blob_id".to_string(),
+        })
+    }
+
+    /// Mines a block and checks that all `txids` are included, i.e. the node considered them valid.
+    fn assert_mined(regtest: &BitcoinRegtest, rpc: &bitcoincore_rpc::Client, txids: &[Txid]) {
+        let miner_address = regtest
+            .get_miner_address()
+            .expect("Failed to get miner address");
+        rpc.generate_to_address(1, &miner_address)
+            .expect("Failed to mine block");
+        for txid in txids {
+            let tx_info = rpc
+                .get_raw_transaction_info(txid, None)
+                .expect("Failed to get tx info");
+            assert!(
+                tx_info.confirmations.unwrap_or(0) >= 1,
+                "{txid} is not mined"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_inscribe_with_p2tr_change() {
+        let regtest = BitcoinRegtest::new().expect("Failed to create BitcoinRegtest");
+        let auth = Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string());
+        let rpc = bitcoincore_rpc::Client::new(&regtest.get_url(), auth.clone())
+            .expect("Failed create raw rpc client");
+        let private_key = regtest.get_private_key().to_wif();
+        let signer =
+            KeyManager::new(&private_key, Network::Regtest).expect("Failed to create signer");
+        let mut inscriber = Inscriber::new(
+            &regtest.get_url(),
+            Network::Regtest,
+            auth,
+            &private_key,
+            None,
+        )
+        .await
+        .expect("Failed to create inscriber")
+        .with_change_output_type(ChangeOutputType::P2TR);
+
+        let mut infos = vec![];
+        for _ in 0..2 {
+            let info = inscriber
+                .inscribe(test_inscription_message(), InscriptionConfig::default())
+                .await
+                .expect("Failed to inscribe with P2TR change");
+
+            let commit_tx = &info.final_commit_tx.tx;
+            let reveal_tx = &info.final_reveal_tx.tx;
+            assert_eq!(
+                commit_tx.output[0].script_pubkey,
+                *signer.get_p2tr_script_pubkey()
+            );
+            assert_eq!(
+                reveal_tx.output[0].script_pubkey,
+                *signer.get_p2tr_script_pubkey()
+            );
+
+            // The estimated fees must be the ones actually paid, and cover the actual sizes.
+            let commit_outputs_value: Amount = commit_tx.output.iter().map(|out| out.value).sum();
+            let commit_fee = info.commit_tx_input_info.unlocked_value - commit_outputs_value;
+            assert_eq!(commit_fee, info.commit_tx_output_info._commit_tx_fee);
+            let commit_fee_rate = info.commit_tx_output_info.commit_tx_fee_rate;
+            assert!(commit_fee.to_sat() >= commit_tx.vsize() as u64 * commit_fee_rate);
+
+            let reveal_outputs_value: Amount = reveal_tx.output.iter().map(|out| out.value).sum();
+            let reveal_fee = commit_outputs_value - reveal_outputs_value;
+            assert_eq!(reveal_fee, info.reveal_tx_output_info._reveal_fee);
+            let reveal_fee_rate = info.reveal_tx_output_info.reveal_fee_rate;
+            assert!(reveal_fee.to_sat() >= reveal_tx.vsize() as u64 * reveal_fee_rate);
+
+            infos.push(info);
+        }
+
+        // The second inscription spends the P2TR change of the first one via the key path.
+        let change_outpoint = OutPoint {
+            txid: infos[0].final_reveal_tx.txid,
+            vout: 0,
+        };
+        let change_input = infos[1]
+            .final_commit_tx
+            .tx
+            .input
+            .iter()
+            .find(|input| input.previous_output == change_outpoint)
+            .expect("P2TR change is not spent");
+        assert_eq!(change_input.witness.len(), 1);
+
+        let txids: Vec<_> = infos
+            .iter()
+            .flat_map(|info| [info.final_commit_tx.txid, info.final_reveal_tx.txid])
+            .collect();
+        assert_mined(&regtest, &rpc, &txids);
+    }
+
+    #[tokio::test]
+    async fn test_inscribe_after_switching_from_p2tr_to_p2wpkh_change() {
+        let regtest = BitcoinRegtest::new().expect("Failed to create BitcoinRegtest");
+        let auth = Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string());
+        let rpc = bitcoincore_rpc::Client::new(&regtest.get_url(), auth.clone())
+            .expect("Failed create raw rpc client");
+        let private_key = regtest.get_private_key().to_wif();
+        let signer =
+            KeyManager::new(&private_key, Network::Regtest).expect("Failed to create signer");
+        let inscriber = Inscriber::new(
+            &regtest.get_url(),
+            Network::Regtest,
+            auth,
+            &private_key,
+            None,
+        )
+        .await
+        .expect("Failed to create inscriber");
+
+        let mut inscriber = inscriber.with_change_output_type(ChangeOutputType::P2TR);
+        let first = inscriber
+            .inscribe(test_inscription_message(), InscriptionConfig::default())
+            .await
+            .expect("Failed to inscribe with P2TR change");
+        let mut inscriber = inscriber.with_change_output_type(ChangeOutputType::P2WPKH);
+        let second = inscriber
+            .inscribe(test_inscription_message(), InscriptionConfig::default())
+            .await
+            .expect("Failed to inscribe after switching to P2WPKH change");
+
+        // The P2TR change is spent whether it's still in the context or already fetched from the node.
+        let change_outpoint = OutPoint {
+            txid: first.final_reveal_tx.txid,
+            vout: 0,
+        };
+        let commit_tx = &second.final_commit_tx.tx;
+        let change_input = commit_tx
+            .input
+            .iter()
+            .find(|input| input.previous_output == change_outpoint)
+            .expect("P2TR change is not spent");
+        assert_eq!(change_input.witness.len(), 1);
+        assert_eq!(
+            commit_tx.output[0].script_pubkey,
+            *signer.get_p2wpkh_script_pubkey()
+        );
+        assert_eq!(
+            second.final_reveal_tx.tx.output[0].script_pubkey,
+            *signer.get_p2wpkh_script_pubkey()
+        );
+
+        assert_mined(
+            &regtest,
+            &rpc,
+            &[
+                first.final_commit_tx.txid,
+                first.final_reveal_tx.txid,
+                second.final_commit_tx.txid,
+                second.final_reveal_tx.txid,
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_raw_transaction() {
+        let regtest = BitcoinRegtest::new().expect("Failed to create BitcoinRegtest");
+        let client = BitcoinClient::new(
+            &regtest.get_url(),
+            Network::Regtest,
+            Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string()),
+        )
+        .expect("Failed create rpc client");
+
+        // Spend a UTXO back to the same address without leaving any fee.
+        let (outpoint, txout) = client
+            .fetch_utxos(regtest.get_address())
+            .await
+            .expect("Failed to fetch utxos")
+            .into_iter()
+            .next()
+            .expect("No utxos for the test address");
+        let tx = build_spend_to_self(&regtest, outpoint, &txout, Amount::ZERO);
+
+        let err = client
+            .send_raw_transaction(&tx)
+            .await
+            .expect_err("Underpriced transaction was accepted");
+        assert!(
+            matches!(&err, BroadcastError::Rejected { reason, .. } if reason.contains("fee")),
+            "unexpected error: {err}"
+        );
+
+        let mut inscriber = Inscriber::new(
+            &regtest.get_url(),
+            Network::Regtest,
+            Auth::UserPass("rpcuser".to_string(), "rpcpassword".to_string()),
+            &regtest.get_private_key().to_wif(),
+            None,
+        )
+        .await
+        .expect("Failed to create inscriber");
+        let message = InscriptionMessage::L1BatchDAReference(L1BatchDAReferenceInput {
+            l1_batch_hash: H256::zero(),
+            l1_batch_index: L1BatchNumber(1),
+            da_identifier: "da_identifier_celestia".to_string(),
+            blob_id: "blob_id".to_string(),
+        });
+        let info = inscriber
+            .prepare_inscribe(&message, InscriptionConfig::default(), None)
+            .await
+            .expect("Failed to prepare inscription");
+
+        let commit_tx = &info.final_commit_tx;
+        let txid = client
+            .send_raw_transaction(&commit_tx.tx)
+            .await
+            .expect("Failed to broadcast commit tx");
+        assert_eq!(txid, commit_tx.txid);
+
+        // Broadcasting the same transaction again is not an error.
+        let txid = client
+            .send_raw_transaction(&commit_tx.tx)
+            .await
+            .expect("Rebroadcast of a known tx failed");
+        assert_eq!(txid, commit_tx.txid);
+    }
 }
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use bitcoin::{
-    key::UntweakedPublicKey,
+    key::{TapTweak, UntweakedPublicKey},
     secp256k1::{
         ecdsa::Signature as ECDSASignature, schnorr::Signature as SchnorrSignature, All, Keypair,
         Message, PublicKey, Secp256k1, SecretKey,
@@ -21,8 +21,11 @@ pub struct KeyManager {
     sk: SecretKey,
     address: Address,
     keypair: Keypair,
+    tweaked_keypair: Keypair,
     internal_key: UntweakedPublicKey,
     script_pubkey: ScriptBuf,
+    p2tr_address: Address,
+    p2tr_script_pubkey: ScriptBuf,
 }
 
 impl KeyManager {
@@ -60,13 +63,22 @@ impl KeyManager {
 
         let script_pubkey = ScriptBuf::new_p2wpkh(&wpkh);
 
+        let tweaked_keypair = keypair.tap_tweak(&secp, None).to_inner();
+
+        let p2tr_address = Address::p2tr(&secp, internal_key, None, network);
+
+        let p2tr_script_pubkey = p2tr_address.script_pubkey();
+
         Ok(Self {
             secp,
             sk,
             address,
             keypair,
+            tweaked_keypair,
             internal_key,
             script_pubkey,
+            p2tr_address,
+            p2tr_script_pubkey,
         })
     }
 }
@@ -81,14 +93,20 @@ impl Default for KeyManager {
         let address = Address::p2wpkh(&compressed_pk, Network::Testnet);
         let internal_key = keypair.x_only_public_key().0;
         let script_pubkey = address.script_pubkey();
+        let tweaked_keypair = keypair.tap_tweak(&secp, None).to_inner();
+        let p2tr_address = Address::p2tr(&secp, internal_key, None, Network::Testnet);
+        let p2tr_script_pubkey = p2tr_address.script_pubkey();
 
         Self {
             secp,
             sk: sk.inner,
             address,
             keypair,
+            tweaked_keypair,
             internal_key,
             script_pubkey,
+            p2tr_address,
+            p2tr_script_pubkey,
         }
     }
 }
@@ -103,6 +121,14 @@ impl BitcoinSigner for KeyManager {
         &self.script_pubkey
     }
 
+    fn get_p2tr_address(&self) -> BitcoinSignerResult<Address> {
+        Ok(self.p2tr_address.clone())
+    }
+
+    fn get_p2tr_script_pubkey(&self) -> &ScriptBuf {
+        &self.p2tr_script_pubkey
+    }
+
     fn get_secp_ref(&self) -> &Secp256k1<All> {
         &self.secp
     }
@@ -121,6 +147,13 @@ impl BitcoinSigner for KeyManager {
         Ok(signature)
     }
 
+    fn sign_schnorr_tweaked(&self, msg: Message) -> BitcoinSignerResult<SchnorrSignature> {
+        let signature = self
+            .secp
+            .sign_schnorr_no_aux_rand(&msg, &self.tweaked_keypair);
+        Ok(signature)
+    }
+
     fn get_public_key(&self) -> PublicKey {
         self.sk.public_key(&self.secp)
     }
@@ -176,4 +209,24 @@ mod tests {
             )
             .is_ok());
     }
+
+    #[test]
+    fn test_sign_schnorr_tweaked() {
+        let key_manager = KeyManager::default();
+        assert_eq!(
+            key_manager.p2tr_address.address_type().unwrap(),
+            AddressType::P2tr
+        );
+
+        let message = Message::from_digest_slice(&[1; 32]).unwrap();
+        let signature = key_manager.sign_schnorr_tweaked(message).unwrap();
+        // The P2TR witness program is the tweaked output key.
+        let output_key =
+            secp256k1::XOnlyPublicKey::from_slice(&key_manager.p2tr_script_pubkey.as_bytes()[2..])
+                .unwrap();
+        assert!(key_manager
+            .secp
+            .verify_schnorr(&signature, &message, &output_key)
+            .is_ok());
+    }
 }
@@ -21,22 +21,43 @@ pub trait BitcoinOps: Send + Sync {
         &self,
         signed_transaction: &str,
     ) -> types::BitcoinClientResult<Txid>;
+    /// Broadcasts `tx`, reporting the node's reject reason on failure. A transaction the node
+    /// already knows about is treated as successfully broadcast.
+    async fn send_raw_transaction(&self, tx: &Transaction) -> Result<Txid, types::BroadcastError>;
     async fn fetch_utxos(
         &self,
         address: &Address,
     ) -> types::BitcoinClientResult<Vec<(OutPoint, TxOut)>>;
+    /// Same as `fetch_utxos`, but pairs every UTXO with the number of confirmations of its
+    /// funding transaction (0 for mempool transactions).
+    async fn fetch_utxos_with_confirmations(
+        &self,
+        address: &Address,
+    ) -> types::BitcoinClientResult<Vec<((OutPoint, TxOut), u32)>>;
     async fn check_tx_confirmation(
         &self,
         txid: &Txid,
         conf_num: u32,
     ) -> types::BitcoinClientResult<bool>;
+    /// Returns the number of confirmations of the transaction (0 if it is in the mempool),
+    /// or `None` if the transaction is unknown to the node.
+    async fn fetch_tx_confirmations(&self, txid: &Txid) -> types::BitcoinClientResult<Option<u32>>;
     async fn fetch_block_height(&self) -> types::BitcoinClientResult<u128>;
     async fn get_fee_rate(&self, conf_target: u16) -> types::BitcoinClientResult<u64>;
     fn get_network(&self) -> Network;
+    /// Returns the network the connected node runs on, which may differ from `get_network()`
+    /// if the client is misconfigured.
+    async fn fetch_node_network(&self) -> BitcoinClientResult<Network>;
+    /// Returns the timestamp of the node's chain tip block, as a Unix timestamp in seconds.
+    async fn fetch_tip_time(&self) -> BitcoinClientResult<u64>;
     async fn fetch_block(&self, block_height: u128) -> BitcoinClientResult<Block>;
 
     async fn get_transaction(&self, txid: &Txid) -> BitcoinClientResult<Transaction>;
-    async fn fetch_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinClientResult<Block>;
+    /// Fetches a block by its hash. Returns `None` if the node doesn't know the block.
+    async fn fetch_block_by_hash(
+        &self,
+        block_hash: &BlockHash,
+    ) -> BitcoinClientResult<Option<Block>>;
 }
 
 impl std::fmt::Debug for dyn BitcoinOps + 'static {
@@ -59,12 +80,17 @@ pub trait BitcoinRpc: Send + Sync {
     async fn get_block_count(&self) -> BitcoinRpcResult<u64>;
     async fn get_block_by_height(&self, block_height: u128) -> BitcoinRpcResult<Block>;
 
-    async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinRpcResult<Block>;
+    async fn get_block_by_hash(&self, block_hash: &BlockHash) -> BitcoinRpcResult<Option<Block>>;
     async fn get_best_block_hash(&self) -> BitcoinRpcResult<bitcoin::BlockHash>;
     async fn get_raw_transaction_info(
         &self,
         txid: &Txid,
     ) -> BitcoinRpcResult<bitcoincore_rpc::json::GetRawTransactionResult>;
+    /// Same as `get_raw_transaction_info`, but returns `None` if the transaction is unknown to the node.
+    async fn find_raw_transaction_info(
+        &self,
+        txid: &Txid,
+    ) -> BitcoinRpcResult<Option<bitcoincore_rpc::json::GetRawTransactionResult>>;
     async fn estimate_smart_fee(
         &self,
         conf_target: u16,
@@ -78,10 +104,17 @@ pub(crate) trait BitcoinSigner: Send + Sync {
 
     fn sign_schnorr(&self, msg: Message) -> types::BitcoinSignerResult<SchnorrSignature>;
 
+    /// Signs with the taproot-tweaked key, as required for key path spends of P2TR outputs.
+    fn sign_schnorr_tweaked(&self, msg: Message) -> types::BitcoinSignerResult<SchnorrSignature>;
+
     fn get_p2wpkh_address(&self) -> types::BitcoinSignerResult<Address>;
 
     fn get_p2wpkh_script_pubkey(&self) -> &ScriptBuf;
 
+    fn get_p2tr_address(&self) -> types::BitcoinSignerResult<Address>;
+
+    fn get_p2tr_script_pubkey(&self) -> &ScriptBuf;
+
     fn get_secp_ref(&self) -> &Secp256k1<All>;
 
     fn get_internal_key(&self) -> types::BitcoinSignerResult<UntweakedPublicKey>;
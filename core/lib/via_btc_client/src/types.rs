@@ -5,7 +5,10 @@ use bitcoin::{
     address::NetworkUnchecked, script::PushBytesBuf, taproot::Signature as TaprootSignature,
     Amount, TxIn, TxOut, Txid,
 };
-pub use bitcoin::{Address as BitcoinAddress, Network as BitcoinNetwork, Txid as BitcoinTxid};
+pub use bitcoin::{
+    Address as BitcoinAddress, BlockHash as BitcoinBlockHash, Network as BitcoinNetwork,
+    Txid as BitcoinTxid,
+};
 pub use bitcoincore_rpc::Auth as NodeAuth;
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
@@ -251,6 +254,13 @@ pub enum BitcoinError {
     #[error("RPC error: {0}")]
     Rpc(String),
 
+    #[error("RPC call timed out: {0}")]
+    RpcTimeout(String),
+
+    /// The node couldn't be reached or is still warming up.
+    #[error("Bitcoin node is unavailable: {0}")]
+    RpcUnavailable(String),
+
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
@@ -287,10 +297,28 @@ pub enum BitcoinError {
     #[error("Uncompressed public key error: {0}")]
     UncompressedPublicKeyError(String),
 
+    #[error("Transaction rejected by the node (code {0}): {1}")]
+    TransactionRejected(i32, String),
+
+    #[error("ZMQ notification error: {0}")]
+    Zmq(String),
+
     #[error("Other error: {0}")]
     Other(String),
 }
 
+impl BitcoinError {
+    /// Whether the error was caused by the node being unreachable, warming up or failing to respond
+    /// in time, i.e. the same call may succeed later. Errors returned by the node for the call itself
+    /// (e.g., an unknown transaction or an invalid parameter) are not transient.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            BitcoinError::RpcTimeout(_) | BitcoinError::RpcUnavailable(_)
+        )
+    }
+}
+
 pub type Result<T> = std::result::Result<T, BitcoinError>;
 
 pub type BitcoinClientResult<T> = Result<T>;
@@ -298,10 +326,42 @@ pub type BitcoinRpcResult<T> = Result<T>;
 
 impl From<bitcoincore_rpc::Error> for BitcoinError {
     fn from(error: bitcoincore_rpc::Error) -> Self {
-        BitcoinError::Rpc(error.to_string())
+        use bitcoincore_rpc::jsonrpc;
+
+        if is_transport_timeout(&error) {
+            return BitcoinError::RpcTimeout(error.to_string());
+        }
+        match &error {
+            bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Transport(_)) => {
+                BitcoinError::RpcUnavailable(error.to_string())
+            }
+            bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Rpc(err))
+                if err.code == RPC_IN_WARMUP =>
+            {
+                BitcoinError::RpcUnavailable(error.to_string())
+            }
+            _ => BitcoinError::Rpc(error.to_string()),
+        }
     }
 }
 
+/// `RPC_IN_WARMUP` from Bitcoin Core; returned by all calls while the node is starting up.
+const RPC_IN_WARMUP: i32 = -28;
+
+/// Checks whether the error was caused by the node not responding within the transport timeout.
+fn is_transport_timeout(error: &bitcoincore_rpc::Error) -> bool {
+    use bitcoincore_rpc::jsonrpc::{self, simple_http};
+
+    let bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Transport(error)) = error else {
+        return false;
+    };
+    matches!(
+        error.downcast_ref::<simple_http::Error>(),
+        Some(simple_http::Error::SocketError(error))
+            if matches!(error.kind(), std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock)
+    )
+}
+
 impl From<bitcoin::address::ParseError> for BitcoinError {
     fn from(error: bitcoin::address::ParseError) -> Self {
         BitcoinError::InvalidAddress(error.to_string())
@@ -323,10 +383,131 @@ pub enum IndexerError {
     InvalidBlockHeight(u32),
     #[error("Bitcoin client error: {0}")]
     BitcoinClientError(#[from] BitcoinError),
+    #[error("Bitcoin network mismatch: {0}")]
+    NetworkMismatch(String),
 }
 
 pub type BitcoinIndexerResult<T> = std::result::Result<T, IndexerError>;
+
+/// Error returned by the inscriber, classified so that callers can decide whether to retry,
+/// wait for the wallet to be funded or alert.
+#[derive(Error, Debug)]
+pub enum InscribeError {
+    #[error("Insufficient funds: required {required}, available {available}")]
+    InsufficientFunds { required: Amount, available: Amount },
+    /// The node couldn't be reached or failed to serve a request.
+    #[error("Bitcoin RPC error: {0}")]
+    Rpc(BitcoinError),
+    #[error("Transaction rejected by the node (code {code}): {reason}")]
+    Rejected { code: i32, reason: String },
+    /// The inscription couldn't be built or signed, e.g. because of an invalid address or key.
+    #[error("Bitcoin client error: {0}")]
+    Client(BitcoinError),
+    #[error("Serialization error: {0}")]
+    Serialization(String),
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
+
+impl InscribeError {
+    /// Whether the same inscription can succeed if it is retried without any external action.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, InscribeError::Rpc(error) if error.is_transient())
+    }
+}
+
+/// Error returned when broadcasting a raw transaction.
+#[derive(Error, Debug)]
+pub enum BroadcastError {
+    /// The node refused the transaction; `reason` is the reject reason reported by the node,
+    /// e.g. `min relay fee not met` or `bad-txns-inputs-missingorspent`.
+    #[error("Transaction rejected by the node (code {code}): {reason}")]
+    Rejected { code: i32, reason: String },
+    #[error(transparent)]
+    Client(BitcoinError),
+}
+
+impl From<BitcoinError> for InscribeError {
+    fn from(error: BitcoinError) -> Self {
+        match error {
+            BitcoinError::Rpc(_)
+            | BitcoinError::RpcTimeout(_)
+            | BitcoinError::RpcUnavailable(_) => InscribeError::Rpc(error),
+            BitcoinError::TransactionRejected(code, reason) => {
+                InscribeError::Rejected { code, reason }
+            }
+            _ => InscribeError::Client(error),
+        }
+    }
+}
+
+impl From<anyhow::Error> for InscribeError {
+    fn from(error: anyhow::Error) -> Self {
+        // The inscriber internals use `anyhow`, so recover the typed cause if there is one.
+        let error = match error.downcast::<InscribeError>() {
+            Ok(inscribe_error) => return inscribe_error,
+            Err(error) => error,
+        };
+        match error.downcast::<BitcoinError>() {
+            Ok(bitcoin_error) => bitcoin_error.into(),
+            Err(error) => InscribeError::Other(error),
+        }
+    }
+}
 pub type BitcoinSignerResult<T> = Result<T>;
 pub type BitcoinInscriberResult<T> = Result<T>;
 
 pub type BitcoinTransactionBuilderResult<T> = Result<T>;
+
+#[cfg(test)]
+mod tests {
+    use bitcoincore_rpc::jsonrpc;
+
+    use super::*;
+
+    fn rpc_error(code: i32, message: &str) -> bitcoincore_rpc::Error {
+        bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Rpc(jsonrpc::error::RpcError {
+            code,
+            message: message.to_string(),
+            data: None,
+        }))
+    }
+
+    #[test]
+    fn test_node_errors_are_not_transient() {
+        let errors = [
+            rpc_error(-5, "No such mempool or blockchain transaction"),
+            rpc_error(-8, "Block height out of range"),
+        ];
+        for error in errors {
+            let error = BitcoinError::from(error);
+            assert!(matches!(error, BitcoinError::Rpc(_)), "{error:?}");
+            assert!(!error.is_transient(), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_warmup_error_is_transient() {
+        let error = BitcoinError::from(rpc_error(-28, "Loading block index..."));
+        assert!(
+            matches!(error, BitcoinError::RpcUnavailable(_)),
+            "{error:?}"
+        );
+        assert!(error.is_transient());
+    }
+
+    #[test]
+    fn test_connection_error_is_transient() {
+        let io_error = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        let error = bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Transport(Box::new(
+            jsonrpc::simple_http::Error::SocketError(io_error),
+        )));
+
+        let error = BitcoinError::from(error);
+        assert!(
+            matches!(error, BitcoinError::RpcUnavailable(_)),
+            "{error:?}"
+        );
+        assert!(error.is_transient());
+    }
+}
@@ -1,3 +1,5 @@
+use std::future::Future;
+
 use tokio::time::Duration;
 
 pub(crate) async fn with_retry<F, T, E>(
@@ -28,3 +30,84 @@ where
         }
     }
 }
+
+/// Async counterpart of [`with_retry`] which doubles the delay after every failed attempt.
+/// Only errors accepted by `is_retriable` are retried; other errors are returned immediately.
+pub(crate) async fn with_async_retry<F, Fut, T, E>(
+    f: F,
+    is_retriable: impl Fn(&E) -> bool,
+    max_retries: u8,
+    initial_retry_delay_ms: u64,
+    operation_name: &str,
+) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: std::fmt::Debug,
+{
+    let mut retries = 0;
+    let mut retry_delay_ms = initial_retry_delay_ms;
+    loop {
+        match f().await {
+            Ok(result) => return Ok(result),
+            Err(e) if retries < max_retries && is_retriable(&e) => {
+                tracing::warn!(
+                    error = ?e,
+                    retries,
+                    "{} failed, retrying in {}ms",
+                    operation_name,
+                    retry_delay_ms
+                );
+                retries += 1;
+                tokio::time::sleep(Duration::from_millis(retry_delay_ms)).await;
+                retry_delay_ms = retry_delay_ms.saturating_mul(2);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_retry_returns_non_retriable_error_immediately() {
+        let attempts = &AtomicU8::new(0);
+        let result: Result<(), &str> = with_async_retry(
+            || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("rejected")
+            },
+            |err| *err != "rejected",
+            3,
+            1,
+            "test",
+        )
+        .await;
+
+        assert_eq!(result, Err("rejected"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_async_retry_retries_retriable_errors() {
+        let attempts = &AtomicU8::new(0);
+        let result: Result<(), &str> = with_async_retry(
+            || async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err("unavailable")
+            },
+            |err| *err == "unavailable",
+            3,
+            1,
+            "test",
+        )
+        .await;
+
+        assert_eq!(result, Err("unavailable"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,287 @@
+//! New block notifications via Bitcoin Core's ZMQ `hashblock` publisher (`-zmqpubhashblock`).
+//!
+//! Only the small subset of ZMTP 3.0 needed to act as a `SUB` socket with the `NULL` security
+//! mechanism is implemented, which is what Bitcoin Core's publisher speaks.
+
+use bitcoin::{hashes::Hash, BlockHash};
+use futures::{stream, Stream};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tracing::{debug, warn};
+
+use crate::types::{BitcoinClientResult, BitcoinError};
+
+const HASHBLOCK_TOPIC: &[u8] = b"hashblock";
+const GREETING_LEN: usize = 64;
+/// Upper bound on the frame size accepted from the publisher. The largest expected frames are 32-byte
+/// block hashes and handshake commands, so the bound only leaves a small slack for command metadata.
+const MAX_FRAME_LEN: u64 = 256;
+
+const FLAG_MORE: u8 = 0x01;
+const FLAG_LONG: u8 = 0x02;
+const FLAG_COMMAND: u8 = 0x04;
+
+/// Connection to a ZMQ `hashblock` publisher.
+#[derive(Debug)]
+struct HashBlockSubscriber {
+    stream: TcpStream,
+}
+
+impl HashBlockSubscriber {
+    /// Connects to an endpoint like `tcp://127.0.0.1:28332` and subscribes to `hashblock`.
+    async fn connect(endpoint: &str) -> BitcoinClientResult<Self> {
+        let address = endpoint.strip_prefix("tcp://").ok_or_else(|| {
+            BitcoinError::Zmq(format!(
+                "unsupported ZMQ endpoint `{endpoint}`, expected tcp://"
+            ))
+        })?;
+        let stream = TcpStream::connect(address).await.map_err(zmq_error)?;
+        let mut subscriber = Self { stream };
+        subscriber.handshake().await?;
+        subscriber.subscribe(HASHBLOCK_TOPIC).await?;
+        debug!("Subscribed to ZMQ block notifications at {endpoint}");
+        Ok(subscriber)
+    }
+
+    async fn handshake(&mut self) -> BitcoinClientResult<()> {
+        self.stream
+            .write_all(&greeting())
+            .await
+            .map_err(zmq_error)?;
+        let mut peer_greeting = [0_u8; GREETING_LEN];
+        self.stream
+            .read_exact(&mut peer_greeting)
+            .await
+            .map_err(zmq_error)?;
+        if peer_greeting[0] != 0xff || peer_greeting[9] != 0x7f || peer_greeting[10] < 3 {
+            return Err(BitcoinError::Zmq(
+                "peer is not a ZMTP 3.x endpoint".to_string(),
+            ));
+        }
+
+        let mut ready = command_body(b"READY");
+        ready.extend_from_slice(&property(b"Socket-Type", b"SUB"));
+        self.write_frame(FLAG_COMMAND, &ready).await?;
+
+        let (flags, body) = self.read_frame().await?;
+        if flags & FLAG_COMMAND == 0 || !body.starts_with(&command_body(b"READY")) {
+            return Err(BitcoinError::Zmq(
+                "expected READY command from the publisher".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn subscribe(&mut self, topic: &[u8]) -> BitcoinClientResult<()> {
+        let mut body = vec![0x01];
+        body.extend_from_slice(topic);
+        self.write_frame(0, &body).await
+    }
+
+    /// Waits for the next `hashblock` notification, skipping any other messages.
+    async fn next_block_hash(&mut self) -> BitcoinClientResult<BlockHash> {
+        loop {
+            let message = self.read_message().await?;
+            let [topic, hash, ..] = message.as_slice() else {
+                continue;
+            };
+            if topic.as_slice() != HASHBLOCK_TOPIC {
+                continue;
+            }
+            let mut hash: [u8; 32] = hash.as_slice().try_into().map_err(|_| {
+                BitcoinError::Zmq(format!("invalid block hash length: {}", hash.len()))
+            })?;
+            // The publisher sends the hash in display (reversed) byte order.
+            hash.reverse();
+            return Ok(BlockHash::from_byte_array(hash));
+        }
+    }
+
+    async fn read_message(&mut self) -> BitcoinClientResult<Vec<Vec<u8>>> {
+        let mut parts = vec![];
+        loop {
+            let (flags, body) = self.read_frame().await?;
+            if flags & FLAG_COMMAND != 0 {
+                // Commands (e.g. heartbeats) are not part of messages.
+                continue;
+            }
+            parts.push(body);
+            if flags & FLAG_MORE == 0 {
+                return Ok(parts);
+            }
+        }
+    }
+
+    async fn read_frame(&mut self) -> BitcoinClientResult<(u8, Vec<u8>)> {
+        let flags = self.stream.read_u8().await.map_err(zmq_error)?;
+        let len = if flags & FLAG_LONG != 0 {
+            self.stream.read_u64().await.map_err(zmq_error)?
+        } else {
+            self.stream.read_u8().await.map_err(zmq_error)?.into()
+        };
+        if len > MAX_FRAME_LEN {
+            return Err(BitcoinError::Zmq(format!(
+                "frame of {len} bytes exceeds the limit of {MAX_FRAME_LEN} bytes"
+            )));
+        }
+        let mut body = vec![0; len as usize];
+        self.stream.read_exact(&mut body).await.map_err(zmq_error)?;
+        Ok((flags, body))
+    }
+
+    async fn write_frame(&mut self, flags: u8, body: &[u8]) -> BitcoinClientResult<()> {
+        let mut frame = Vec::with_capacity(body.len() + 9);
+        if let Ok(len) = u8::try_from(body.len()) {
+            frame.extend_from_slice(&[flags, len]);
+        } else {
+            frame.push(flags | FLAG_LONG);
+            frame.extend_from_slice(&(body.len() as u64).to_be_bytes());
+        }
+        frame.extend_from_slice(body);
+        self.stream.write_all(&frame).await.map_err(zmq_error)
+    }
+}
+
+fn zmq_error(err: std::io::Error) -> BitcoinError {
+    BitcoinError::Zmq(err.to_string())
+}
+
+/// ZMTP 3.0 greeting announcing the `NULL` mechanism as a client.
+fn greeting() -> [u8; GREETING_LEN] {
+    let mut greeting = [0_u8; GREETING_LEN];
+    greeting[0] = 0xff;
+    greeting[9] = 0x7f;
+    greeting[10] = 3;
+    greeting[12..16].copy_from_slice(b"NULL");
+    greeting
+}
+
+fn command_body(name: &[u8]) -> Vec<u8> {
+    let mut body = vec![name.len() as u8];
+    body.extend_from_slice(name);
+    body
+}
+
+fn property(name: &[u8], value: &[u8]) -> Vec<u8> {
+    let mut property = command_body(name);
+    property.extend_from_slice(&(value.len() as u32).to_be_bytes());
+    property.extend_from_slice(value);
+    property
+}
+
+/// Subscribes to new block hashes published by the Bitcoin node at `endpoint`, e.g. `tcp://127.0.0.1:28332`.
+///
+/// The stream ends on the first connection or protocol error, so callers should keep
+/// polling the node as a fallback rather than rely on notifications exclusively.
+pub async fn subscribe_new_blocks(
+    endpoint: &str,
+) -> BitcoinClientResult<impl Stream<Item = BlockHash>> {
+    let subscriber = HashBlockSubscriber::connect(endpoint).await?;
+    Ok(stream::unfold(subscriber, |mut subscriber| async move {
+        match subscriber.next_block_hash().await {
+            Ok(hash) => Some((hash, subscriber)),
+            Err(err) => {
+                warn!("ZMQ block notifications stopped: {err}");
+                None
+            }
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Accepts a single subscriber and publishes `hashes` once it has subscribed.
+    async fn mock_publisher(listener: TcpListener, hashes: Vec<BlockHash>) {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut publisher = HashBlockSubscriber { stream };
+
+        let mut greeting = [0_u8; GREETING_LEN];
+        publisher.stream.read_exact(&mut greeting).await.unwrap();
+        assert_eq!(&greeting[12..16], b"NULL");
+        publisher
+            .stream
+            .write_all(&super::greeting())
+            .await
+            .unwrap();
+
+        let (flags, body) = publisher.read_frame().await.unwrap();
+        assert_ne!(flags & FLAG_COMMAND, 0);
+        assert!(body.ends_with(b"SUB"));
+        let mut ready = command_body(b"READY");
+        ready.extend_from_slice(&property(b"Socket-Type", b"PUB"));
+        publisher.write_frame(FLAG_COMMAND, &ready).await.unwrap();
+
+        let (_, subscription) = publisher.read_frame().await.unwrap();
+        assert_eq!(subscription, b"\x01hashblock");
+
+        for (sequence, hash) in hashes.into_iter().enumerate() {
+            let mut hash = hash.to_byte_array();
+            hash.reverse();
+            publisher.write_frame(FLAG_MORE, b"rawtx").await.unwrap();
+            publisher.write_frame(0, &[0; 4]).await.unwrap();
+            publisher
+                .write_frame(FLAG_MORE, HASHBLOCK_TOPIC)
+                .await
+                .unwrap();
+            publisher.write_frame(FLAG_MORE, &hash).await.unwrap();
+            publisher
+                .write_frame(0, &(sequence as u32).to_le_bytes())
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn receives_published_block_hashes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("tcp://{}", listener.local_addr().unwrap());
+        let hashes = vec![
+            BlockHash::from_byte_array([1; 32]),
+            BlockHash::from_byte_array([2; 32]),
+        ];
+        let publisher = tokio::spawn(mock_publisher(listener, hashes.clone()));
+
+        let notifications = subscribe_new_blocks(&endpoint).await.unwrap();
+        publisher.await.unwrap();
+        // The publisher has disconnected after sending, which ends the stream.
+        let received: Vec<_> = notifications.collect().await;
+        assert_eq!(received, hashes);
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_frames() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let publisher = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.write_u8(FLAG_LONG).await.unwrap();
+            stream.write_u64(u64::MAX).await.unwrap();
+            stream
+        });
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let mut subscriber = HashBlockSubscriber { stream };
+        let err = subscriber.read_frame().await.unwrap_err();
+        assert!(
+            matches!(&err, BitcoinError::Zmq(message) if message.contains("exceeds the limit")),
+            "{err:?}"
+        );
+        publisher.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_non_tcp_endpoints() {
+        let err = subscribe_new_blocks("ipc:///tmp/bitcoind.sock")
+            .await
+            .map(drop)
+            .unwrap_err();
+        assert!(matches!(err, BitcoinError::Zmq(_)));
+    }
+}
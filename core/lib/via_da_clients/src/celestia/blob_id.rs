@@ -0,0 +1,129 @@
+//! Encoding of the blob ids returned by [`CelestiaClient`](super::client::CelestiaClient).
+//!
+//! A blob id references a blob by the Celestia block it was included in and its share commitment:
+//!
+//! - legacy (unversioned): `[8]byte block height ++ [32]byte commitment`
+//! - versioned: `[1]byte version ++ [8]byte block height ++ [32]byte commitment`
+//!
+//! Legacy ids are always accepted when decoding, so that blobs dispatched before the versioned
+//! format was introduced can still be fetched.
+
+use anyhow::anyhow;
+use celestia_types::Commitment;
+use zksync_da_client::types::DAError;
+
+const BLOCK_HEIGHT_LEN: usize = 8;
+const COMMITMENT_LEN: usize = 32;
+const LEGACY_BLOB_ID_LEN: usize = BLOCK_HEIGHT_LEN + COMMITMENT_LEN;
+const VERSIONED_BLOB_ID_LEN: usize = 1 + LEGACY_BLOB_ID_LEN;
+
+/// Format of the blob ids produced when dispatching blobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlobIdVersion {
+    /// Unversioned `block height ++ commitment` id.
+    Legacy,
+    /// Version byte `1` followed by `block height ++ commitment`.
+    V1,
+}
+
+impl TryFrom<u8> for BlobIdVersion {
+    type Error = anyhow::Error;
+
+    /// Maps the `blob_id_version` config value to the format; `0` stands for the legacy format.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Legacy),
+            1 => Ok(Self::V1),
+            _ => Err(anyhow!("Unsupported blob id version: {value}")),
+        }
+    }
+}
+
+/// Encodes the location of a blob as a hex string.
+pub fn encode_blob_id(
+    version: BlobIdVersion,
+    block_height: u64,
+    commitment: &Commitment,
+) -> String {
+    let mut blob_id = Vec::with_capacity(VERSIONED_BLOB_ID_LEN);
+    if version == BlobIdVersion::V1 {
+        blob_id.push(1);
+    }
+    blob_id.extend_from_slice(&block_height.to_be_bytes());
+    blob_id.extend_from_slice(&commitment.0);
+    hex::encode(blob_id)
+}
+
+/// Decodes a blob id in either the legacy or the versioned format into the block height and commitment.
+pub fn decode_blob_id(blob_id: &str) -> Result<(u64, Commitment), DAError> {
+    let blob_id_bytes = hex::decode(blob_id).map_err(|error| DAError {
+        error: error.into(),
+        is_retriable: false,
+    })?;
+
+    let payload = match blob_id_bytes.len() {
+        LEGACY_BLOB_ID_LEN => &blob_id_bytes[..],
+        VERSIONED_BLOB_ID_LEN => match blob_id_bytes[0] {
+            1 => &blob_id_bytes[1..],
+            version => {
+                return Err(DAError {
+                    error: anyhow!("Unsupported blob id version: {version}"),
+                    is_retriable: false,
+                })
+            }
+        },
+        len => {
+            return Err(DAError {
+                error: anyhow!("Invalid blob id length: {len}"),
+                is_retriable: false,
+            })
+        }
+    };
+
+    let (block_height, commitment) = payload.split_at(BLOCK_HEIGHT_LEN);
+    let block_height = u64::from_be_bytes(block_height.try_into().expect("length checked above"));
+    let commitment: [u8; COMMITMENT_LEN] = commitment.try_into().expect("length checked above");
+    Ok((block_height, Commitment(commitment)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_blob_id_round_trip() {
+        let commitment = Commitment([3; 32]);
+        let blob_id = encode_blob_id(BlobIdVersion::Legacy, 42, &commitment);
+        assert_eq!(blob_id.len(), 2 * LEGACY_BLOB_ID_LEN);
+        assert_eq!(&blob_id[..16], "000000000000002a");
+
+        assert_eq!(decode_blob_id(&blob_id).unwrap(), (42, commitment));
+    }
+
+    #[test]
+    fn versioned_blob_id_round_trip() {
+        let commitment = Commitment([5; 32]);
+        let blob_id = encode_blob_id(BlobIdVersion::V1, u64::MAX, &commitment);
+        assert_eq!(blob_id.len(), 2 * VERSIONED_BLOB_ID_LEN);
+        assert!(blob_id.starts_with("01"));
+
+        assert_eq!(decode_blob_id(&blob_id).unwrap(), (u64::MAX, commitment));
+    }
+
+    #[test]
+    fn decoding_rejects_unknown_versions_and_lengths() {
+        let mut blob_id = vec![2];
+        blob_id.extend_from_slice(&[0; LEGACY_BLOB_ID_LEN]);
+        assert!(decode_blob_id(&hex::encode(blob_id)).is_err());
+
+        assert!(decode_blob_id(&hex::encode([0; 39])).is_err());
+        assert!(decode_blob_id("not hex").is_err());
+    }
+
+    #[test]
+    fn blob_id_version_from_config() {
+        assert_eq!(BlobIdVersion::try_from(0).unwrap(), BlobIdVersion::Legacy);
+        assert_eq!(BlobIdVersion::try_from(1).unwrap(), BlobIdVersion::V1);
+        assert!(BlobIdVersion::try_from(2).is_err());
+    }
+}
@@ -5,16 +5,71 @@ use std::{
 
 use anyhow::anyhow;
 use async_trait::async_trait;
-use celestia_rpc::{BlobClient, Client, P2PClient};
-use celestia_types::{nmt::Namespace, Blob, Commitment, TxConfig};
-use hex;
+use celestia_rpc::{BlobClient, Client, HeaderClient, P2PClient};
+use celestia_types::{
+    hash::Hash,
+    nmt::{Namespace, NamespaceProof, NamespacedHash},
+    Blob, Commitment, DataAvailabilityHeader, Share, TxConfig,
+};
 pub use zksync_config::ViaCelestiaConfig;
 pub use zksync_da_client::{types, DataAvailabilityClient};
+use zksync_types::{web3::keccak256, H256};
+
+use super::blob_id::{decode_blob_id, encode_blob_id, BlobIdVersion};
 
 /// If no value is provided for GasPrice, then this will be serialized to `-1.0` which means the node that
 /// receives the request will calculate the GasPrice for given blob.
 const GAS_PRICE: f64 = -1.0;
 
+/// Blob data returned together with the NMT proofs of its inclusion in a Celestia block.
+#[derive(Debug, Clone)]
+pub struct InclusionDataWithProof {
+    pub data: Vec<u8>,
+    pub block_height: u64,
+    pub commitment: Commitment,
+    /// Namespace proofs for the shares of the blob, one per row of the extended data square.
+    pub proofs: Vec<NamespaceProof>,
+}
+
+/// Properties a blob retrieved from Celestia is expected to have, e.g. as recorded when it was dispatched.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BlobExpectation {
+    /// Expected blob length in bytes.
+    pub len: Option<usize>,
+    /// Expected Keccak-256 hash of the blob.
+    pub hash: Option<H256>,
+}
+
+impl BlobExpectation {
+    /// Checks that `data` has the expected properties. A mismatch is not retriable: the light node
+    /// returns the same blob for the same ID.
+    pub fn check(&self, data: &[u8]) -> Result<(), types::DAError> {
+        if let Some(len) = self.len {
+            if data.len() != len {
+                return Err(types::DAError {
+                    error: anyhow!(
+                        "retrieved blob has {} bytes, while {len} bytes were expected",
+                        data.len()
+                    ),
+                    is_retriable: false,
+                });
+            }
+        }
+        if let Some(hash) = self.hash {
+            let actual_hash = H256(keccak256(data));
+            if actual_hash != hash {
+                return Err(types::DAError {
+                    error: anyhow!(
+                        "retrieved blob has hash {actual_hash:?}, while {hash:?} was expected"
+                    ),
+                    is_retriable: false,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 /// An implementation of the `DataAvailabilityClient` trait that stores the pubdata in the Celestia DA.
 #[derive(Clone)]
 pub struct CelestiaClient {
@@ -22,6 +77,7 @@ pub struct CelestiaClient {
     inner: Arc<Client>,
     blob_size_limit: usize,
     namespace: Namespace,
+    blob_id_version: BlobIdVersion,
 }
 
 impl CelestiaClient {
@@ -40,13 +96,179 @@ impl CelestiaClient {
             is_retriable: false,
         })?;
 
+        let blob_id_version = BlobIdVersion::try_from(celestia_conf.blob_id_version)?;
+
         Ok(Self {
             light_node_url: celestia_conf.api_node_url,
             inner: Arc::new(client),
             blob_size_limit: celestia_conf.blob_size_limit,
             namespace,
+            blob_id_version,
+        })
+    }
+
+    /// Same as [`DataAvailabilityClient::get_inclusion_data`], but also fetches the proof that
+    /// the blob is included in the Celestia block referenced by `blob_id`.
+    pub async fn get_inclusion_data_with_proof(
+        &self,
+        blob_id: &str,
+    ) -> Result<InclusionDataWithProof, types::DAError> {
+        let (block_height, commitment) = decode_blob_id(blob_id)?;
+
+        let blob = self
+            .inner
+            .blob_get(block_height, self.namespace, commitment)
+            .await
+            .map_err(|error| types::DAError {
+                error: error.into(),
+                is_retriable: true,
+            })?;
+
+        let proofs = self
+            .inner
+            .blob_get_proof(block_height, self.namespace, commitment)
+            .await
+            .map_err(|error| types::DAError {
+                error: error.into(),
+                is_retriable: true,
+            })?;
+
+        Ok(InclusionDataWithProof {
+            data: blob.data,
+            block_height,
+            commitment,
+            proofs,
         })
     }
+
+    /// Same as [`DataAvailabilityClient::get_inclusion_data`], but fails with a non-retriable error
+    /// if the retrieved blob doesn't match the `expectation`.
+    pub async fn get_inclusion_data_checked(
+        &self,
+        blob_id: &str,
+        expectation: BlobExpectation,
+    ) -> Result<Option<types::InclusionData>, types::DAError> {
+        let inclusion_data = self.get_inclusion_data(blob_id).await?;
+        if let Some(inclusion_data) = &inclusion_data {
+            expectation
+                .check(&inclusion_data.data)
+                .map_err(|err| types::DAError {
+                    error: err.error.context(format!("blob {blob_id}")),
+                    is_retriable: err.is_retriable,
+                })?;
+        }
+        Ok(inclusion_data)
+    }
+
+    /// Checks that the data matches the commitment and that the proofs include the blob shares in the
+    /// block at `inclusion.block_height`. Only the block header is fetched from the light node; the proofs
+    /// are verified locally against the row roots committed to by the header's data root.
+    pub async fn verify_inclusion(
+        &self,
+        inclusion: &InclusionDataWithProof,
+    ) -> Result<bool, types::DAError> {
+        let header = self
+            .inner
+            .header_get_by_height(inclusion.block_height)
+            .await
+            .map_err(|error| types::DAError {
+                error: error.into(),
+                is_retriable: true,
+            })?;
+
+        verify_inclusion_proofs(
+            self.namespace,
+            inclusion,
+            &header.dah,
+            &header.header.data_hash,
+        )
+    }
+}
+
+/// Verifies `inclusion` against the data availability header of its block, which must hash to `data_root`.
+pub fn verify_inclusion_proofs(
+    namespace: Namespace,
+    inclusion: &InclusionDataWithProof,
+    dah: &DataAvailabilityHeader,
+    data_root: &Hash,
+) -> Result<bool, types::DAError> {
+    if dah.hash() != *data_root {
+        return Ok(false);
+    }
+    if !verify_blob_commitment(namespace, &inclusion.data, &inclusion.commitment)? {
+        return Ok(false);
+    }
+    verify_blob_shares(
+        namespace,
+        &inclusion.data,
+        &inclusion.proofs,
+        dah.row_roots(),
+    )
+}
+
+/// Checks that the shares of the blob with `data` are proven by `proofs` against consecutive `row_roots`.
+/// Each proof covers the blob shares in a single row, in the order the blob is laid out in the data square.
+pub fn verify_blob_shares(
+    namespace: Namespace,
+    data: &[u8],
+    proofs: &[NamespaceProof],
+    row_roots: &[NamespacedHash],
+) -> Result<bool, types::DAError> {
+    let shares = Blob::new(namespace, data.to_vec())
+        .and_then(|blob| blob.to_shares())
+        .map_err(|error| types::DAError {
+            error: error.into(),
+            is_retriable: false,
+        })?;
+    if proofs.is_empty() {
+        return Ok(false);
+    }
+
+    let verifies = |proof: &NamespaceProof, row_root: &NamespacedHash, shares: &[Share]| {
+        proof.verify_range(row_root, shares, *namespace).is_ok()
+    };
+
+    let mut remaining_shares = shares.as_slice();
+    let mut next_row = None;
+    for proof in proofs {
+        let share_count = (proof.end_idx() - proof.start_idx()) as usize;
+        if share_count == 0 || share_count > remaining_shares.len() {
+            return Ok(false);
+        }
+        let (row_shares, rest) = remaining_shares.split_at(share_count);
+
+        // The row of the first proof is not known upfront, so it's looked up among all rows.
+        let row = match next_row {
+            Some(row) => row_roots
+                .get(row)
+                .filter(|row_root| verifies(proof, row_root, row_shares))
+                .map(|_| row),
+            None => row_roots
+                .iter()
+                .position(|row_root| verifies(proof, row_root, row_shares)),
+        };
+        let Some(row) = row else {
+            return Ok(false);
+        };
+        next_row = Some(row + 1);
+        remaining_shares = rest;
+    }
+    Ok(remaining_shares.is_empty())
+}
+
+/// Recomputes the share commitment of `data` and compares it with the expected one.
+pub fn verify_blob_commitment(
+    namespace: Namespace,
+    data: &[u8],
+    commitment: &Commitment,
+) -> Result<bool, types::DAError> {
+    let share_version = celestia_types::consts::appconsts::SHARE_VERSION_ZERO;
+    let computed =
+        Commitment::from_blob(namespace, share_version, data).map_err(|error| types::DAError {
+            error: error.into(),
+            is_retriable: false,
+        })?;
+    Ok(computed == *commitment)
 }
 
 #[async_trait]
@@ -89,13 +311,7 @@ impl DataAvailabilityClient for CelestiaClient {
                 is_retriable: true,
             })?;
 
-        // [8]byte block height ++ [32]byte commitment
-        let mut blob_id = Vec::with_capacity(8 + 32);
-        blob_id.extend_from_slice(&block_hight.to_be_bytes());
-        blob_id.extend_from_slice(&commitment_result.0);
-
-        // Convert blob_id to a hex string
-        let blob_id_str = hex::encode(blob_id);
+        let blob_id_str = encode_blob_id(self.blob_id_version, block_hight, &commitment_result);
 
         return Ok(types::DispatchResponse {
             blob_id: blob_id_str,
@@ -106,26 +322,7 @@ impl DataAvailabilityClient for CelestiaClient {
         &self,
         blob_id: &str,
     ) -> Result<Option<types::InclusionData>, types::DAError> {
-        // [8]byte block height ++ [32]byte commitment
-        let blob_id_bytes = hex::decode(blob_id).map_err(|error| types::DAError {
-            error: error.into(),
-            is_retriable: false,
-        })?;
-
-        let block_height =
-            u64::from_be_bytes(blob_id_bytes[..8].try_into().map_err(|_| types::DAError {
-                error: anyhow!("Failed to convert block height"),
-                is_retriable: false,
-            })?);
-
-        let commitment_data: [u8; 32] =
-            blob_id_bytes[8..40]
-                .try_into()
-                .map_err(|_| types::DAError {
-                    error: anyhow!("Failed to convert commitment"),
-                    is_retriable: false,
-                })?;
-        let commitment = Commitment(commitment_data);
+        let (block_height, commitment) = decode_blob_id(blob_id)?;
 
         let blob = self
             .inner
@@ -157,3 +354,132 @@ impl Debug for CelestiaClient {
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use celestia_types::nmt::Nmt;
+
+    use super::*;
+
+    fn namespace() -> Namespace {
+        Namespace::new_v0(&[b'V', b'I', b'A', 0, 0, 0, 0, 0]).unwrap()
+    }
+
+    #[test]
+    fn test_blob_id_commitment_verifies_against_data() {
+        let namespace = namespace();
+        let data = vec![7u8; 1024];
+        let commitment = Commitment::from_blob(
+            namespace,
+            celestia_types::consts::appconsts::SHARE_VERSION_ZERO,
+            &data,
+        )
+        .unwrap();
+
+        let blob_id = encode_blob_id(BlobIdVersion::Legacy, 42, &commitment);
+        let (block_height, parsed) = decode_blob_id(&blob_id).unwrap();
+
+        assert_eq!(block_height, 42);
+        assert_eq!(parsed, commitment);
+        assert!(verify_blob_commitment(namespace, &data, &parsed).unwrap());
+
+        let mut tampered = data.clone();
+        tampered[0] ^= 1;
+        assert!(!verify_blob_commitment(namespace, &tampered, &parsed).unwrap());
+    }
+
+    #[test]
+    fn test_blob_expectation_detects_size_mismatch() {
+        let data = vec![7u8; 1024];
+        let expectation = BlobExpectation {
+            len: Some(data.len()),
+            hash: Some(H256(keccak256(&data))),
+        };
+        expectation.check(&data).unwrap();
+        BlobExpectation::default().check(&data[..10]).unwrap();
+
+        let err = expectation.check(&data[..1000]).unwrap_err();
+        assert!(!err.is_retriable());
+        assert!(err.error.to_string().contains("1000 bytes"), "{err}");
+
+        let mut tampered = data.clone();
+        tampered[0] ^= 1;
+        let err = expectation.check(&tampered).unwrap_err();
+        assert!(err.error.to_string().contains("hash"), "{err}");
+    }
+
+    /// Builds a row of the data square out of `shares` and returns its root together with the proof
+    /// for the shares in `range`.
+    fn row_with_proof(shares: &[Share], range: Range<usize>) -> (NamespacedHash, NamespaceProof) {
+        let mut row = Nmt::new();
+        for share in shares {
+            row.push_leaf(share.as_ref(), *share.namespace()).unwrap();
+        }
+        let (_, proof) = row.get_range_with_proof(range);
+        (row.root(), proof.into())
+    }
+
+    fn shares(namespace: Namespace, data: &[u8]) -> Vec<Share> {
+        Blob::new(namespace, data.to_vec())
+            .unwrap()
+            .to_shares()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_blob_shares_verify_against_row_roots() {
+        let namespace = namespace();
+        let lower_namespace = Namespace::new_v0(&[b'A', 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        let higher_namespace = Namespace::new_v0(&[b'W', 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        let data = vec![7u8; 1500];
+        let blob_shares = shares(namespace, &data);
+        assert_eq!(blob_shares.len(), 4);
+
+        // The blob spans two rows, each shared with another namespace.
+        let first_row = [
+            shares(lower_namespace, &[1; 600]),
+            blob_shares[..2].to_vec(),
+        ]
+        .concat();
+        let second_row = [
+            blob_shares[2..].to_vec(),
+            shares(higher_namespace, &[2; 600]),
+        ]
+        .concat();
+        let (first_root, first_proof) = row_with_proof(&first_row, 2..4);
+        let (second_root, second_proof) = row_with_proof(&second_row, 0..2);
+        let row_roots = [first_root, second_root];
+        let proofs = [first_proof.clone(), second_proof.clone()];
+
+        assert!(verify_blob_shares(namespace, &data, &proofs, &row_roots).unwrap());
+
+        let mut tampered_data = data.clone();
+        tampered_data[0] ^= 1;
+        assert!(!verify_blob_shares(namespace, &tampered_data, &proofs, &row_roots).unwrap());
+
+        let swapped_proofs = [second_proof.clone(), first_proof.clone()];
+        assert!(!verify_blob_shares(namespace, &data, &swapped_proofs, &row_roots).unwrap());
+        assert!(!verify_blob_shares(namespace, &data, &proofs[..1], &row_roots).unwrap());
+        assert!(!verify_blob_shares(namespace, &data, &[], &row_roots).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_proof_is_rejected() {
+        let namespace = namespace();
+        let higher_namespace = Namespace::new_v0(&[b'W', 0, 0, 0, 0, 0, 0, 0]).unwrap();
+        let data = vec![7u8; 1500];
+        let blob_shares = shares(namespace, &data);
+
+        let row = [blob_shares.clone(), shares(higher_namespace, &[2; 600])].concat();
+        let (root, proof) = row_with_proof(&row, 0..blob_shares.len());
+        assert!(verify_blob_shares(namespace, &data, &[proof], &[root]).unwrap());
+
+        // A proof for the same shares whose siblings commit to different data in the rest of the row.
+        let tampered_row = [blob_shares.clone(), shares(higher_namespace, &[3; 600])].concat();
+        let (tampered_root, tampered_proof) = row_with_proof(&tampered_row, 0..blob_shares.len());
+        assert_ne!(tampered_root, root);
+        assert!(!verify_blob_shares(namespace, &data, &[tampered_proof], &[root]).unwrap());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod blob_id;
 pub mod client;
 pub mod config;
 pub mod wiring_layer;
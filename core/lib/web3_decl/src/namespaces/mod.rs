@@ -1,13 +1,15 @@
 pub use self::{
     debug::DebugNamespaceClient, en::EnNamespaceClient, eth::EthNamespaceClient,
     net::NetNamespaceClient, snapshots::SnapshotsNamespaceClient,
-    unstable::UnstableNamespaceClient, web3::Web3NamespaceClient, zks::ZksNamespaceClient,
+    unstable::UnstableNamespaceClient, via::ViaNamespaceClient, web3::Web3NamespaceClient,
+    zks::ZksNamespaceClient,
 };
 #[cfg(feature = "server")]
 pub use self::{
     debug::DebugNamespaceServer, en::EnNamespaceServer, eth::EthNamespaceServer,
     eth::EthPubSubServer, net::NetNamespaceServer, snapshots::SnapshotsNamespaceServer,
-    unstable::UnstableNamespaceServer, web3::Web3NamespaceServer, zks::ZksNamespaceServer,
+    unstable::UnstableNamespaceServer, via::ViaNamespaceServer, web3::Web3NamespaceServer,
+    zks::ZksNamespaceServer,
 };
 
 mod debug;
@@ -16,5 +18,6 @@ mod eth;
 mod net;
 mod snapshots;
 mod unstable;
+mod via;
 mod web3;
 mod zks;
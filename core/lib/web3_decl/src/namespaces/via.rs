@@ -0,0 +1,23 @@
+#[cfg_attr(not(feature = "server"), allow(unused_imports))]
+use jsonrpsee::core::RpcResult;
+use jsonrpsee::proc_macros::rpc;
+use zksync_types::{api::ViaBatchInscriptionStatus, L1BatchNumber};
+
+use crate::client::{ForWeb3Network, L2};
+
+/// Via-specific RPCs exposing the state of L1 batches on Bitcoin.
+#[cfg_attr(
+    feature = "server",
+    rpc(server, client, namespace = "via", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+#[cfg_attr(
+    not(feature = "server"),
+    rpc(client, namespace = "via", client_bounds(Self: ForWeb3Network<Net = L2>))
+)]
+pub trait ViaNamespace {
+    #[method(name = "getBatchInscriptionStatus")]
+    async fn get_batch_inscription_status(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> RpcResult<Vec<ViaBatchInscriptionStatus>>;
+}
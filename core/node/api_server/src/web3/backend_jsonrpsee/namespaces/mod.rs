@@ -4,5 +4,6 @@ pub mod eth;
 pub mod net;
 pub mod snapshots;
 pub mod unstable;
+pub mod via;
 pub mod web3;
 pub mod zks;
@@ -0,0 +1,19 @@
+use zksync_types::{api::ViaBatchInscriptionStatus, L1BatchNumber};
+use zksync_web3_decl::{
+    jsonrpsee::core::{async_trait, RpcResult},
+    namespaces::ViaNamespaceServer,
+};
+
+use crate::web3::namespaces::ViaNamespace;
+
+#[async_trait]
+impl ViaNamespaceServer for ViaNamespace {
+    async fn get_batch_inscription_status(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> RpcResult<Vec<ViaBatchInscriptionStatus>> {
+        self.get_batch_inscription_status_impl(batch_number)
+            .await
+            .map_err(|err| self.current_method().map_err(err))
+    }
+}
@@ -9,6 +9,7 @@ use tokio::{
     task::JoinHandle,
 };
 use tower_http::{cors::CorsLayer, metrics::InFlightRequestsLayer};
+use via_btc_client::traits::BitcoinOps;
 use zksync_config::configs::api::{MaxResponseSize, MaxResponseSizeOverrides};
 use zksync_dal::{helpers::wait_for_l1_batch, ConnectionPool, Core};
 use zksync_health_check::{HealthStatus, HealthUpdater, ReactiveHealthCheck};
@@ -24,8 +25,8 @@ use zksync_web3_decl::{
     },
     namespaces::{
         DebugNamespaceServer, EnNamespaceServer, EthNamespaceServer, EthPubSubServer,
-        NetNamespaceServer, SnapshotsNamespaceServer, UnstableNamespaceServer, Web3NamespaceServer,
-        ZksNamespaceServer,
+        NetNamespaceServer, SnapshotsNamespaceServer, UnstableNamespaceServer, ViaNamespaceServer,
+        Web3NamespaceServer, ZksNamespaceServer,
     },
     types::Filter,
 };
@@ -39,7 +40,7 @@ use self::{
     metrics::API_METRICS,
     namespaces::{
         DebugNamespace, EnNamespace, EthNamespace, NetNamespace, SnapshotsNamespace,
-        UnstableNamespace, Web3Namespace, ZksNamespace,
+        UnstableNamespace, ViaNamespace, Web3Namespace, ZksNamespace,
     },
     pubsub::{EthSubscribe, EthSubscriptionIdProvider, PubSubEvent},
     state::{Filters, InternalApiConfig, RpcState, SealedL2BlockNumber},
@@ -100,6 +101,7 @@ pub enum Namespace {
     Pubsub,
     Snapshots,
     Unstable,
+    Via,
 }
 
 impl Namespace {
@@ -133,6 +135,7 @@ struct OptionalApiParams {
     response_body_size_limit: Option<MaxResponseSize>,
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
     tree_api: Option<Arc<dyn TreeApiClient>>,
+    btc_client: Option<Arc<dyn BitcoinOps>>,
     mempool_cache: Option<MempoolCache>,
     extended_tracing: bool,
     pub_sub_events_sender: Option<mpsc::UnboundedSender<PubSubEvent>>,
@@ -275,6 +278,12 @@ impl ApiBuilder {
         self
     }
 
+    /// Sets the Bitcoin client used by the `via` namespace to report the live status of inscriptions.
+    pub fn with_btc_client(mut self, btc_client: Arc<dyn BitcoinOps>) -> Self {
+        self.optional.btc_client = Some(btc_client);
+        self
+    }
+
     pub fn with_mempool_cache(mut self, cache: MempoolCache) -> Self {
         self.optional.mempool_cache = Some(cache);
         self
@@ -365,6 +374,7 @@ impl ApiServer {
             mempool_cache: self.optional.mempool_cache,
             last_sealed_l2_block,
             tree_api: self.optional.tree_api,
+            btc_client: self.optional.btc_client,
         })
     }
 
@@ -413,9 +423,13 @@ impl ApiServer {
                 .context("cannot merge snapshots namespace")?;
         }
         if namespaces.contains(&Namespace::Unstable) {
-            rpc.merge(UnstableNamespace::new(rpc_state).into_rpc())
+            rpc.merge(UnstableNamespace::new(rpc_state.clone()).into_rpc())
                 .context("cannot merge unstable namespace")?;
         }
+        if namespaces.contains(&Namespace::Via) {
+            rpc.merge(ViaNamespace::new(rpc_state).into_rpc())
+                .context("cannot merge via namespace")?;
+        }
         Ok(rpc)
     }
 
@@ -7,11 +7,12 @@ pub(crate) mod eth;
 mod net;
 mod snapshots;
 mod unstable;
+mod via;
 mod web3;
 mod zks;
 
 pub(super) use self::{
     debug::DebugNamespace, en::EnNamespace, eth::EthNamespace, net::NetNamespace,
-    snapshots::SnapshotsNamespace, unstable::UnstableNamespace, web3::Web3Namespace,
-    zks::ZksNamespace,
+    snapshots::SnapshotsNamespace, unstable::UnstableNamespace, via::ViaNamespace,
+    web3::Web3Namespace, zks::ZksNamespace,
 };
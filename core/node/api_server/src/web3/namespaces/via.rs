@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use via_btc_client::types::BitcoinTxid;
+use zksync_dal::{CoreDal, DalError};
+use zksync_types::{
+    api::{ViaBatchInscriptionStatus, ViaInscriptionStatus},
+    L1BatchNumber,
+};
+use zksync_web3_decl::error::Web3Error;
+
+use crate::web3::{backend_jsonrpsee::MethodTracer, RpcState};
+
+#[derive(Debug)]
+pub(crate) struct ViaNamespace {
+    state: RpcState,
+}
+
+impl ViaNamespace {
+    pub fn new(state: RpcState) -> Self {
+        Self { state }
+    }
+
+    pub(crate) fn current_method(&self) -> &MethodTracer {
+        &self.state.current_method
+    }
+
+    /// Reports the status of the batch inscriptions. The status of broadcast inscriptions is checked
+    /// against the Bitcoin node if the API server has a Bitcoin client; otherwise, it's `Unknown`.
+    pub async fn get_batch_inscription_status_impl(
+        &self,
+        batch_number: L1BatchNumber,
+    ) -> Result<Vec<ViaBatchInscriptionStatus>, Web3Error> {
+        let mut storage = self.state.acquire_connection().await?;
+        let inscriptions = storage
+            .via_blocks_dal()
+            .get_batch_inscriptions(batch_number)
+            .await
+            .map_err(DalError::generalize)?;
+        drop(storage);
+
+        let mut statuses = Vec::with_capacity(inscriptions.len());
+        for inscription in inscriptions {
+            let status = match inscription.reveal_tx_id {
+                Some(reveal_tx_id) => self.fetch_inscription_status(&reveal_tx_id).await,
+                None => ViaInscriptionStatus::NotBroadcast,
+            };
+            statuses.push(ViaBatchInscriptionStatus {
+                l1_batch_number: batch_number,
+                inscription_type: inscription.request_type.to_string(),
+                status,
+                commit_tx_id: inscription.commit_tx_id,
+                reveal_tx_id: inscription.reveal_tx_id,
+                sent_at_block: inscription.sent_at_block,
+                confirmed_at: inscription
+                    .confirmed_at
+                    .map(|at| DateTime::<Utc>::from_naive_utc_and_offset(at, Utc)),
+            });
+        }
+        Ok(statuses)
+    }
+
+    async fn fetch_inscription_status(&self, reveal_tx_id: &BitcoinTxid) -> ViaInscriptionStatus {
+        let Some(btc_client) = &self.state.btc_client else {
+            return ViaInscriptionStatus::Unknown;
+        };
+        // The DB state is still useful to the caller, so node errors don't fail the request.
+        match btc_client.fetch_tx_confirmations(reveal_tx_id).await {
+            Ok(Some(0)) => ViaInscriptionStatus::InMempool,
+            Ok(Some(confirmations)) => ViaInscriptionStatus::Confirmed(confirmations),
+            Ok(None) => ViaInscriptionStatus::Unknown,
+            Err(err) => {
+                tracing::warn!(
+                    "Failed fetching confirmations for transaction {reveal_tx_id}: {err}"
+                );
+                ViaInscriptionStatus::Unknown
+            }
+        }
+    }
+}
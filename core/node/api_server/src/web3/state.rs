@@ -11,6 +11,7 @@ use anyhow::Context as _;
 use futures::TryFutureExt;
 use lru::LruCache;
 use tokio::sync::{watch, Mutex};
+use via_btc_client::traits::BitcoinOps;
 use vise::GaugeGuard;
 use zksync_config::{
     configs::{api::Web3JsonRpcConfig, ContractsConfig},
@@ -248,6 +249,7 @@ pub(crate) struct RpcState {
     pub(super) installed_filters: Option<Arc<Mutex<Filters>>>,
     pub(super) connection_pool: ConnectionPool<Core>,
     pub(super) tree_api: Option<Arc<dyn TreeApiClient>>,
+    pub(super) btc_client: Option<Arc<dyn BitcoinOps>>,
     pub(super) tx_sender: TxSender,
     pub(super) sync_state: Option<SyncState>,
     pub(super) api_config: InternalApiConfig,
@@ -105,6 +105,25 @@ pub async fn spawn_http_server(
     tx_executor: MockOneshotExecutor,
     method_tracer: Arc<MethodTracer>,
     stop_receiver: watch::Receiver<bool>,
+) -> ApiServerHandles {
+    spawn_http_server_with_btc_client(
+        api_config,
+        pool,
+        tx_executor,
+        method_tracer,
+        None,
+        stop_receiver,
+    )
+    .await
+}
+
+pub(crate) async fn spawn_http_server_with_btc_client(
+    api_config: InternalApiConfig,
+    pool: ConnectionPool<Core>,
+    tx_executor: MockOneshotExecutor,
+    method_tracer: Arc<MethodTracer>,
+    btc_client: Option<Arc<dyn BitcoinOps>>,
+    stop_receiver: watch::Receiver<bool>,
 ) -> ApiServerHandles {
     spawn_server(
         ApiTransportLabel::Http,
@@ -113,6 +132,7 @@ pub async fn spawn_http_server(
         None,
         tx_executor,
         method_tracer,
+        btc_client,
         stop_receiver,
     )
     .await
@@ -132,6 +152,7 @@ pub async fn spawn_ws_server(
         websocket_requests_per_minute_limit,
         MockOneshotExecutor::default(),
         Arc::default(),
+        None,
         stop_receiver,
     )
     .await
@@ -144,6 +165,7 @@ async fn spawn_server(
     websocket_requests_per_minute_limit: Option<NonZeroU32>,
     tx_executor: MockOneshotExecutor,
     method_tracer: Arc<MethodTracer>,
+    btc_client: Option<Arc<dyn BitcoinOps>>,
     stop_receiver: watch::Receiver<bool>,
 ) -> (ApiServerHandles, mpsc::UnboundedReceiver<PubSubEvent>) {
     let (tx_sender, vm_barrier) =
@@ -151,7 +173,7 @@ async fn spawn_server(
     let (pub_sub_events_sender, pub_sub_events_receiver) = mpsc::unbounded_channel();
 
     let mut namespaces = Namespace::DEFAULT.to_vec();
-    namespaces.extend([Namespace::Debug, Namespace::Snapshots]);
+    namespaces.extend([Namespace::Debug, Namespace::Snapshots, Namespace::Via]);
 
     let server_builder = match transport {
         ApiTransportLabel::Http => ApiBuilder::jsonrpsee_backend(api_config, pool).http(0),
@@ -166,6 +188,10 @@ async fn spawn_server(
             builder
         }
     };
+    let server_builder = match btc_client {
+        Some(btc_client) => server_builder.with_btc_client(btc_client),
+        None => server_builder,
+    };
     let server_handles = server_builder
         .with_polling_interval(POLL_INTERVAL)
         .with_tx_sender(tx_sender)
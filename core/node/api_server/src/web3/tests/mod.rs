@@ -59,12 +59,13 @@ use zksync_web3_decl::{
 use super::*;
 use crate::{
     execution_sandbox::testonly::MockOneshotExecutor,
-    web3::testonly::{spawn_http_server, spawn_ws_server},
+    web3::testonly::{spawn_http_server_with_btc_client, spawn_ws_server},
 };
 
 mod debug;
 mod filters;
 mod snapshots;
+mod via;
 mod vm;
 mod ws;
 
@@ -146,6 +147,10 @@ trait HttpTest: Send + Sync {
         Arc::default()
     }
 
+    fn btc_client(&self) -> Option<Arc<dyn BitcoinOps>> {
+        None
+    }
+
     async fn test(&self, client: &DynClient<L2>, pool: &ConnectionPool<Core>)
         -> anyhow::Result<()>;
 
@@ -246,11 +251,12 @@ async fn test_http_server(test: impl HttpTest) {
     let genesis = GenesisConfig::for_tests();
     let mut api_config = InternalApiConfig::new(&web3_config, &contracts_config, &genesis);
     api_config.filters_disabled = test.filters_disabled();
-    let mut server_handles = spawn_http_server(
+    let mut server_handles = spawn_http_server_with_btc_client(
         api_config,
         pool.clone(),
         test.transaction_executor(),
         test.method_tracer(),
+        test.btc_client(),
         stop_receiver,
     )
     .await;
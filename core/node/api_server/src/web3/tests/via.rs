@@ -0,0 +1,172 @@
+//! Tests for the `via` Web3 namespace.
+
+use anyhow::Context as _;
+use bitcoin::{hashes::Hash, Txid};
+use via_btc_client::inscriber::test_utils::{MockBitcoinOps, MockBitcoinOpsConfig};
+use zksync_types::{
+    api::{ViaBatchInscriptionStatus, ViaInscriptionStatus},
+    btc_inscription_operations::ViaBtcInscriptionRequestType,
+};
+use zksync_web3_decl::namespaces::ViaNamespaceClient;
+
+use super::*;
+
+async fn insert_commit_inscription(
+    storage: &mut Connection<'_, Core>,
+    number: L1BatchNumber,
+    sent_at_block: i64,
+    confirm: bool,
+) -> anyhow::Result<()> {
+    let request_type = ViaBtcInscriptionRequestType::CommitL1BatchOnchain;
+    let request = storage
+        .btc_sender_dal()
+        .via_save_btc_inscriptions_request(request_type, vec![], 0)
+        .await?;
+    storage
+        .via_blocks_dal()
+        .insert_l1_batch_inscription_request_id(number, request.id, request_type)
+        .await?;
+    let seed = number.0 as u8;
+    let history_id = storage
+        .btc_sender_dal()
+        .insert_inscription_request_history(
+            Txid::from_byte_array([seed; 32]),
+            Txid::from_byte_array([seed + 100; 32]),
+            request.id,
+            vec![],
+            vec![],
+            0,
+            sent_at_block,
+        )
+        .await?
+        .context("history was not inserted")?;
+    if confirm {
+        storage
+            .btc_sender_dal()
+            .confirm_inscription(request.id, history_id.into())
+            .await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+struct BatchInscriptionStatusTest;
+
+#[async_trait]
+impl HttpTest for BatchInscriptionStatusTest {
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        for number in 1..=3 {
+            seal_l1_batch(&mut storage, L1BatchNumber(number)).await?;
+        }
+        insert_commit_inscription(&mut storage, L1BatchNumber(1), 100, true).await?;
+        insert_commit_inscription(&mut storage, L1BatchNumber(2), 101, false).await?;
+
+        let confirmed = client
+            .get_batch_inscription_status(L1BatchNumber(1))
+            .await?;
+        assert_matches!(
+            confirmed.as_slice(),
+            [ViaBatchInscriptionStatus {
+                status: ViaInscriptionStatus::Unknown,
+                sent_at_block: Some(100),
+                confirmed_at: Some(_),
+                ..
+            }]
+        );
+        assert_eq!(confirmed[0].inscription_type, "CommitL1BatchOnchain");
+        assert_eq!(
+            confirmed[0].commit_tx_id,
+            Some(Txid::from_byte_array([1; 32]))
+        );
+
+        let pending = client
+            .get_batch_inscription_status(L1BatchNumber(2))
+            .await?;
+        assert_matches!(
+            pending.as_slice(),
+            [ViaBatchInscriptionStatus {
+                status: ViaInscriptionStatus::Unknown,
+                sent_at_block: Some(101),
+                confirmed_at: None,
+                ..
+            }]
+        );
+
+        let not_inscribed = client
+            .get_batch_inscription_status(L1BatchNumber(3))
+            .await?;
+        assert!(not_inscribed.is_empty());
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_batch_inscription_status() {
+    test_http_server(BatchInscriptionStatusTest).await;
+}
+
+#[derive(Debug)]
+struct LiveInscriptionStatusTest {
+    btc_config: MockBitcoinOpsConfig,
+    expected_status: ViaInscriptionStatus,
+}
+
+#[async_trait]
+impl HttpTest for LiveInscriptionStatusTest {
+    fn btc_client(&self) -> Option<Arc<dyn BitcoinOps>> {
+        Some(Arc::new(MockBitcoinOps::new(self.btc_config.clone())))
+    }
+
+    async fn test(
+        &self,
+        client: &DynClient<L2>,
+        pool: &ConnectionPool<Core>,
+    ) -> anyhow::Result<()> {
+        let mut storage = pool.connection().await?;
+        seal_l1_batch(&mut storage, L1BatchNumber(1)).await?;
+        insert_commit_inscription(&mut storage, L1BatchNumber(1), 100, false).await?;
+
+        let statuses = client
+            .get_batch_inscription_status(L1BatchNumber(1))
+            .await?;
+        assert_eq!(statuses.len(), 1);
+        assert_eq!(statuses[0].status, self.expected_status);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn getting_inscription_status_in_mempool() {
+    let mut btc_config = MockBitcoinOpsConfig::default();
+    btc_config.set_tx_in_mempool(true);
+    test_http_server(LiveInscriptionStatusTest {
+        btc_config,
+        expected_status: ViaInscriptionStatus::InMempool,
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn getting_confirmed_inscription_status() {
+    let mut btc_config = MockBitcoinOpsConfig::default();
+    btc_config.set_tx_confirmation(true);
+    test_http_server(LiveInscriptionStatusTest {
+        btc_config,
+        expected_status: ViaInscriptionStatus::Confirmed(1),
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn getting_inscription_status_unknown_to_node() {
+    test_http_server(LiveInscriptionStatusTest {
+        btc_config: MockBitcoinOpsConfig::default(),
+        expected_status: ViaInscriptionStatus::Unknown,
+    })
+    .await;
+}
@@ -1,4 +1,8 @@
-use std::{path::Path, sync::Arc, time::Duration};
+use std::{
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context as _;
 use serde::Serialize;
@@ -61,6 +65,19 @@ pub enum NodeRole {
     External,
 }
 
+/// Position of a RocksDB storage cache relative to the L1 batch it is expected to reflect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageCacheConsistency {
+    /// The cache contains the state after the expected L1 batch and nothing newer.
+    InSync,
+    /// The cache contains L1 batches newer than the expected one; it needs to be rolled back.
+    Ahead { next_l1_batch: L1BatchNumber },
+    /// The cache hasn't processed the expected L1 batch yet.
+    Behind {
+        next_l1_batch: Option<L1BatchNumber>,
+    },
+}
+
 /// This struct is used to roll back node state and revert batches committed (but generally not finalized) on L1.
 ///
 /// Reversion is a rare event of manual intervention, when the node operator
@@ -90,6 +107,7 @@ pub struct BlockReverter {
     should_roll_back_postgres: bool,
     storage_cache_paths: Vec<String>,
     merkle_tree_path: Option<String>,
+    compact_merkle_tree: bool,
     snapshots_object_store: Option<Arc<dyn ObjectStore>>,
 }
 
@@ -102,6 +120,7 @@ impl BlockReverter {
             should_roll_back_postgres: false,
             storage_cache_paths: Vec::new(),
             merkle_tree_path: None,
+            compact_merkle_tree: false,
             snapshots_object_store: None,
         }
     }
@@ -126,6 +145,14 @@ impl BlockReverter {
         self
     }
 
+    /// Enables compacting the Merkle tree RocksDB after it's rolled back. Compaction drops tombstones
+    /// left by removing tree nodes, which reduces the DB size and speeds up subsequent reads,
+    /// at the cost of a longer rollback. Disabled by default.
+    pub fn enable_merkle_tree_compaction(&mut self) -> &mut Self {
+        self.compact_merkle_tree = true;
+        self
+    }
+
     pub fn add_rocksdb_storage_path_to_rollback(&mut self, path: String) -> &mut Self {
         self.storage_cache_paths.push(path);
         self
@@ -201,11 +228,13 @@ impl BlockReverter {
                     merkle_tree_path.display()
                 );
                 let merkle_tree_path = merkle_tree_path.to_path_buf();
+                let compact = self.compact_merkle_tree;
                 tokio::task::spawn_blocking(move || {
                     Self::roll_back_tree_blocking(
                         last_l1_batch_to_keep,
                         &merkle_tree_path,
                         storage_root_hash,
+                        compact,
                     )
                 })
                 .await
@@ -236,10 +265,11 @@ impl BlockReverter {
         last_l1_batch_to_keep: L1BatchNumber,
         path: &Path,
         storage_root_hash: H256,
+        compact: bool,
     ) -> anyhow::Result<()> {
         let db = RocksDB::new(path).context("failed initializing RocksDB for Merkle tree")?;
-        let mut tree =
-            ZkSyncTree::new_lightweight(db.into()).context("failed initializing Merkle tree")?;
+        let mut tree = ZkSyncTree::new_lightweight(db.clone().into())
+            .context("failed initializing Merkle tree")?;
 
         if tree.next_l1_batch_number() <= last_l1_batch_to_keep {
             tracing::info!("Tree is behind the L1 batch to roll back to; skipping");
@@ -256,6 +286,16 @@ impl BlockReverter {
         );
         tracing::info!("Saving tree changes to disk");
         tree.save().context("failed saving tree changes")?;
+
+        if compact {
+            tracing::info!("Compacting Merkle tree RocksDB at `{}`", path.display());
+            let started_at = Instant::now();
+            db.compact();
+            tracing::info!(
+                "Compacted Merkle tree RocksDB in {:?}",
+                started_at.elapsed()
+            );
+        }
         Ok(())
     }
 
@@ -283,6 +323,49 @@ impl BlockReverter {
         Ok(())
     }
 
+    /// Checks the storage cache at `storage_cache_path` against `expected_l1_batch`, which must be
+    /// present in Postgres. Can be used as a diagnostic before rolling back the cache.
+    pub async fn check_storage_cache_consistency(
+        &self,
+        storage_cache_path: &str,
+        expected_l1_batch: L1BatchNumber,
+    ) -> anyhow::Result<StorageCacheConsistency> {
+        let sealed_l1_batch = self
+            .connection_pool
+            .connection()
+            .await?
+            .blocks_dal()
+            .get_sealed_l1_batch_number()
+            .await?;
+        anyhow::ensure!(
+            sealed_l1_batch >= Some(expected_l1_batch),
+            "L1 batch #{expected_l1_batch} is not present in Postgres (last sealed: {sealed_l1_batch:?})"
+        );
+
+        // Opening a RocksDB instance creates it if it doesn't exist, which would mask a wrong path.
+        let sk_cache_exists = fs::try_exists(storage_cache_path).await.with_context(|| {
+            format!("cannot check whether storage cache path `{storage_cache_path}` exists")
+        })?;
+        anyhow::ensure!(
+            sk_cache_exists,
+            "Storage cache path `{storage_cache_path}` does not exist"
+        );
+
+        tracing::info!("Opening DB with storage cache at `{storage_cache_path}`");
+        let sk_cache = RocksdbStorage::builder(storage_cache_path.as_ref())
+            .await
+            .context("failed initializing storage cache")?;
+        let next_l1_batch = sk_cache.l1_batch_number().await;
+
+        Ok(match next_l1_batch {
+            Some(next) if next == expected_l1_batch + 1 => StorageCacheConsistency::InSync,
+            Some(next) if next > expected_l1_batch + 1 => StorageCacheConsistency::Ahead {
+                next_l1_batch: next,
+            },
+            _ => StorageCacheConsistency::Behind { next_l1_batch },
+        })
+    }
+
     /// Rolls back data in the Postgres database.
     /// If `node_role` is `Main` a consensus hard-fork is performed.
     async fn roll_back_postgres(
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use test_casing::test_casing;
 use tokio::sync::watch;
 use zksync_dal::Connection;
-use zksync_merkle_tree::TreeInstruction;
+use zksync_merkle_tree::{MerkleTreeColumnFamily, TreeInstruction};
 use zksync_object_store::{Bucket, MockObjectStore};
 use zksync_state::interface::ReadStorage;
 use zksync_types::{
@@ -202,6 +202,112 @@ async fn block_reverter_basics(sync_merkle_tree: bool) {
     }
 }
 
+#[test_casing(2, [false, true])]
+#[tokio::test]
+async fn block_reverter_with_merkle_tree_compaction(compact: bool) {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let merkle_tree_path = temp_dir.path().join("tree");
+    let l1_batch_hashes = initialize_merkle_tree(&merkle_tree_path, &storage_logs);
+    for (number, hash) in (0..).zip(&l1_batch_hashes) {
+        storage
+            .blocks_dal()
+            .set_l1_batch_hash(L1BatchNumber(number), *hash)
+            .await
+            .unwrap();
+    }
+
+    let mut block_reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    block_reverter.enable_rolling_back_merkle_tree(merkle_tree_path.to_str().unwrap().to_owned());
+    if compact {
+        block_reverter.enable_merkle_tree_compaction();
+    }
+    block_reverter.roll_back(L1BatchNumber(5)).await.unwrap();
+
+    // Tree data is flushed to level 0 when the DB is reopened; compaction moves it to the bottom level.
+    let db = RocksDB::<MerkleTreeColumnFamily>::new(&merkle_tree_path).unwrap();
+    let level0_files = db
+        .num_files_at_level(MerkleTreeColumnFamily::Tree, 0)
+        .unwrap();
+    if compact {
+        assert_eq!(level0_files, 0);
+    } else {
+        assert!(level0_files > 0);
+    }
+
+    let tree = ZkSyncTree::new(db.into()).unwrap();
+    assert_eq!(tree.next_l1_batch_number(), L1BatchNumber(6));
+    assert_eq!(tree.root_hash(), l1_batch_hashes[5]);
+}
+
+#[tokio::test]
+async fn checking_storage_cache_consistency() {
+    let storage_logs = gen_storage_logs();
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    setup_storage(&mut storage, &storage_logs).await;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let sk_cache_path = temp_dir.path().join("sk_cache");
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    RocksdbStorage::builder(&sk_cache_path)
+        .await
+        .unwrap()
+        .synchronize(&mut storage, &stop_receiver, Some(L1BatchNumber(5)))
+        .await
+        .unwrap()
+        .expect("sk_cache syncing unexpectedly stopped");
+
+    let reverter = BlockReverter::new(NodeRole::External, pool.clone());
+    let sk_cache_path = sk_cache_path.to_str().unwrap();
+    let consistency = reverter
+        .check_storage_cache_consistency(sk_cache_path, L1BatchNumber(5))
+        .await
+        .unwrap();
+    assert_eq!(consistency, StorageCacheConsistency::InSync);
+
+    let consistency = reverter
+        .check_storage_cache_consistency(sk_cache_path, L1BatchNumber(3))
+        .await
+        .unwrap();
+    assert_eq!(
+        consistency,
+        StorageCacheConsistency::Ahead {
+            next_l1_batch: L1BatchNumber(6)
+        }
+    );
+
+    let consistency = reverter
+        .check_storage_cache_consistency(sk_cache_path, L1BatchNumber(8))
+        .await
+        .unwrap();
+    assert_eq!(
+        consistency,
+        StorageCacheConsistency::Behind {
+            next_l1_batch: Some(L1BatchNumber(6))
+        }
+    );
+
+    // L1 batches absent from Postgres cannot be checked against.
+    reverter
+        .check_storage_cache_consistency(sk_cache_path, L1BatchNumber(20))
+        .await
+        .unwrap_err();
+
+    // A missing cache must not be created and reported as empty.
+    let missing_path = temp_dir.path().join("missing_sk_cache");
+    let err = reverter
+        .check_storage_cache_consistency(missing_path.to_str().unwrap(), L1BatchNumber(5))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("does not exist"), "{err:#}");
+    assert!(!missing_path.exists());
+}
+
 async fn create_mock_snapshot(
     storage: &mut Connection<'_, Core>,
     object_store: &dyn ObjectStore,
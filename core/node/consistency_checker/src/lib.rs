@@ -1,4 +1,4 @@
-use std::{borrow::Cow, collections::HashSet, fmt, time::Duration};
+use std::{borrow::Cow, collections::HashSet, fmt, num::NonZeroU32, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use serde::Serialize;
@@ -9,7 +9,10 @@ use zksync_eth_client::{
     clients::{DynClient, L1},
     CallFunctionArgs, ContractCallError, EnrichedClientError, EthInterface,
 };
-use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_health_check::{
+    alerts::{Alert, AlertSeverity, AlertSink, NoopAlertSink},
+    Health, HealthStatus, HealthUpdater, ReactiveHealthCheck,
+};
 use zksync_l1_contract_interface::{
     i_executor::{commit::kzg::ZK_SYNC_BYTES_PER_BLOB, structures::CommitBatchInfo},
     Tokenizable,
@@ -38,6 +41,10 @@ enum CheckError {
     /// Error that is caused by violating invariants internal to *this* node (e.g., not having expected data in Postgres).
     #[error("internal error")]
     Internal(anyhow::Error),
+    /// The commit transaction receipt is known to L1, but the transaction itself isn't served yet
+    /// (e.g., the L1 node behind a load balancer lags behind the one that returned the receipt).
+    #[error("commit transaction is not available on L1 yet")]
+    TxNotAvailable(anyhow::Error),
 }
 
 impl CheckError {
@@ -46,6 +53,7 @@ impl CheckError {
             Self::Web3(err) | Self::ContractCall(ContractCallError::EthereumGateway(err)) => {
                 err.is_retriable()
             }
+            Self::TxNotAvailable(_) => true,
             _ => false,
         }
     }
@@ -310,13 +318,18 @@ pub struct ConsistencyChecker {
     diamond_proxy_addr: Option<Address>,
     /// How many past batches to check when starting
     max_batches_to_recheck: u32,
+    /// How many consecutive batches to check concurrently
+    max_concurrent_checks: NonZeroU32,
     sleep_interval: Duration,
     l1_client: Box<DynClient<L1>>,
     event_handler: Box<dyn HandleConsistencyCheckerEvent>,
     l1_data_mismatch_behavior: L1DataMismatchBehavior,
     pool: ConnectionPool<Core>,
+    /// If set, the checker doesn't persist its progress (e.g., when running against a read-only replica).
+    read_only: bool,
     health_check: ReactiveHealthCheck,
     commitment_mode: L1BatchCommitmentMode,
+    alert_sink: Arc<dyn AlertSink>,
 }
 
 impl ConsistencyChecker {
@@ -333,13 +346,16 @@ impl ConsistencyChecker {
             contract: zksync_contracts::hyperchain_contract(),
             diamond_proxy_addr: None,
             max_batches_to_recheck,
+            max_concurrent_checks: NonZeroU32::MIN,
             sleep_interval: Self::DEFAULT_SLEEP_INTERVAL,
             l1_client: l1_client.for_component("consistency_checker"),
             event_handler: Box::new(health_updater),
             l1_data_mismatch_behavior: L1DataMismatchBehavior::Log,
             pool,
+            read_only: false,
             health_check,
             commitment_mode,
+            alert_sink: Arc::new(NoopAlertSink),
         })
     }
 
@@ -348,6 +364,27 @@ impl ConsistencyChecker {
         self
     }
 
+    /// Sets the number of consecutive L1 batches whose commitments are checked concurrently.
+    /// Results are still persisted in batch order, so this only speeds up catching up with a backlog.
+    pub fn with_max_concurrent_checks(mut self, max_concurrent_checks: NonZeroU32) -> Self {
+        self.max_concurrent_checks = max_concurrent_checks;
+        self
+    }
+
+    /// Switches the checker to the read-only mode, in which it doesn't persist the last processed L1 batch.
+    /// Progress is tracked in memory, so each run re-checks batches starting from the configured
+    /// `max_batches_to_recheck` window. This allows running the checker against a read-only DB replica.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Sets the sink receiving alerts on L1 batches inconsistent with L1.
+    pub fn with_alert_sink(mut self, alert_sink: Arc<dyn AlertSink>) -> Self {
+        self.alert_sink = alert_sink;
+        self
+    }
+
     /// Returns health check associated with this checker.
     pub fn health_check(&self) -> &ReactiveHealthCheck {
         &self.health_check
@@ -378,7 +415,7 @@ impl ConsistencyChecker {
             .get_tx(commit_tx_hash)
             .await?
             .with_context(|| format!("commit transaction {commit_tx_hash:?} not found on L1"))
-            .map_err(CheckError::Internal)?; // we've got a transaction receipt previously, thus an internal error
+            .map_err(CheckError::TxNotAvailable)?; // we've got a transaction receipt previously, so the tx should show up shortly
 
         if let Some(diamond_proxy_addr) = self.diamond_proxy_addr {
             let event = self
@@ -527,10 +564,11 @@ impl ConsistencyChecker {
     pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         tracing::info!(
             "Starting consistency checker with diamond proxy contract: {:?}, sleep interval: {:?}, \
-             max historic L1 batches to check: {}",
+             max historic L1 batches to check: {}, max concurrent checks: {}",
             self.diamond_proxy_addr,
             self.sleep_interval,
-            self.max_batches_to_recheck
+            self.max_batches_to_recheck,
+            self.max_concurrent_checks
         );
         self.event_handler.initialize();
 
@@ -571,19 +609,27 @@ impl ConsistencyChecker {
             .saturating_sub(self.max_batches_to_recheck)
             .into();
 
-        let last_processed_batch = self
-            .pool
-            .connection()
-            .await?
-            .blocks_dal()
-            .get_consistency_checker_last_processed_l1_batch()
-            .await?;
+        let last_processed_batch = if self.read_only {
+            // Persisted progress may be written by another checker instance; in the read-only mode,
+            // we always start from the configured window.
+            None
+        } else {
+            let batch = self
+                .pool
+                .connection()
+                .await?
+                .blocks_dal()
+                .get_consistency_checker_last_processed_l1_batch()
+                .await?;
+            Some(batch)
+        };
 
         // We shouldn't check batches not present in the storage, and skip the genesis batch since
         // it's not committed on L1.
-        let first_batch_to_check = first_batch_to_check
-            .max(earliest_l1_batch_number)
-            .max(L1BatchNumber(last_processed_batch.0 + 1));
+        let mut first_batch_to_check = first_batch_to_check.max(earliest_l1_batch_number);
+        if let Some(last_processed_batch) = last_processed_batch {
+            first_batch_to_check = first_batch_to_check.max(last_processed_batch + 1);
+        }
         tracing::info!(
             "Last committed L1 batch is #{last_committed_batch}; starting checks from L1 batch #{first_batch_to_check}"
         );
@@ -591,15 +637,24 @@ impl ConsistencyChecker {
             .set_first_batch_to_check(first_batch_to_check);
 
         let mut batch_number = first_batch_to_check;
-        while !*stop_receiver.borrow_and_update() {
+        'main: while !*stop_receiver.borrow_and_update() {
             let mut storage = self.pool.connection().await?;
             // The batch might be already committed but not yet processed by the external node's tree
             // OR the batch might be processed by the external node's tree but not yet committed.
-            // We need both.
-            let local =
-                LocalL1BatchCommitData::new(&mut storage, batch_number, self.commitment_mode)
-                    .await?;
-            let Some(local) = local else {
+            // We need both. Only a contiguous range of ready batches is checked at once.
+            let mut ready_batches = vec![];
+            for offset in 0..self.max_concurrent_checks.get() {
+                let number = batch_number + offset;
+                let local =
+                    LocalL1BatchCommitData::new(&mut storage, number, self.commitment_mode).await?;
+                let Some(local) = local else {
+                    break;
+                };
+                ready_batches.push((number, local));
+            }
+            drop(storage);
+
+            if ready_batches.is_empty() {
                 if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
                     .await
                     .is_ok()
@@ -607,51 +662,76 @@ impl ConsistencyChecker {
                     break;
                 }
                 continue;
-            };
-            drop(storage);
+            }
 
-            match self.check_commitments(batch_number, &local).await {
-                Ok(()) => {
-                    let mut storage = self.pool.connection().await?;
-                    storage
-                        .blocks_dal()
-                        .set_consistency_checker_last_processed_l1_batch(batch_number)
-                        .await?;
-                    self.event_handler.update_checked_batch(batch_number);
-                    batch_number += 1;
-                }
-                Err(CheckError::Validation(err)) => {
-                    self.event_handler
-                        .report_inconsistent_batch(batch_number, &err);
-                    match &self.l1_data_mismatch_behavior {
-                        #[cfg(test)]
-                        L1DataMismatchBehavior::Bail => {
-                            let context =
-                                format!("L1 batch #{batch_number} is inconsistent with L1");
-                            return Err(err.context(context));
+            let check_results = futures::future::join_all(
+                ready_batches
+                    .iter()
+                    .map(|(number, local)| self.check_commitments(*number, local)),
+            )
+            .await;
+
+            // Results are handled in batch order, so the persisted cursor only ever moves forward
+            // over a contiguous range of checked batches.
+            for ((number, _), check_result) in ready_batches.iter().zip(check_results) {
+                debug_assert_eq!(*number, batch_number);
+                match check_result {
+                    Ok(()) => {
+                        if !self.read_only {
+                            let mut storage = self.pool.connection().await?;
+                            storage
+                                .blocks_dal()
+                                .set_consistency_checker_last_processed_l1_batch(batch_number)
+                                .await?;
+                        }
+                        self.event_handler.update_checked_batch(batch_number);
+                        batch_number += 1;
+                    }
+                    Err(CheckError::Validation(err)) => {
+                        self.event_handler
+                            .report_inconsistent_batch(batch_number, &err);
+                        let alert = Alert::new(
+                            "consistency_checker",
+                            AlertSeverity::Critical,
+                            format!("L1 batch #{batch_number} is inconsistent with L1"),
+                        )
+                        .with_details(serde_json::json!({
+                            "l1_batch_number": batch_number,
+                            "error": format!("{err:#}"),
+                        }));
+                        self.alert_sink.send(alert).await;
+                        match &self.l1_data_mismatch_behavior {
+                            #[cfg(test)]
+                            L1DataMismatchBehavior::Bail => {
+                                let context =
+                                    format!("L1 batch #{batch_number} is inconsistent with L1");
+                                return Err(err.context(context));
+                            }
+                            L1DataMismatchBehavior::Log => {
+                                batch_number += 1; // We don't want to infinitely loop failing the check on the same batch
+                            }
                         }
-                        L1DataMismatchBehavior::Log => {
-                            batch_number += 1; // We don't want to infinitely loop failing the check on the same batch
+                    }
+                    Err(err) if err.is_retriable() => {
+                        tracing::warn!(
+                            "Transient error while verifying L1 batch #{batch_number}; will retry after a delay: {:#}",
+                            anyhow::Error::from(err)
+                        );
+                        // Later batches in the range are re-checked on the next iteration.
+                        if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
+                            .await
+                            .is_ok()
+                        {
+                            break 'main;
                         }
+                        continue 'main;
                     }
-                }
-                Err(err) if err.is_retriable() => {
-                    tracing::warn!(
-                        "Transient error while verifying L1 batch #{batch_number}; will retry after a delay: {:#}",
-                        anyhow::Error::from(err)
-                    );
-                    if tokio::time::timeout(self.sleep_interval, stop_receiver.changed())
-                        .await
-                        .is_ok()
-                    {
-                        break;
+                    Err(other_err) => {
+                        let context =
+                            format!("failed verifying consistency of L1 batch #{batch_number}");
+                        return Err(anyhow::Error::from(other_err).context(context));
                     }
                 }
-                Err(other_err) => {
-                    let context =
-                        format!("failed verifying consistency of L1 batch #{batch_number}");
-                    return Err(anyhow::Error::from(other_err).context(context));
-                }
             }
         }
 
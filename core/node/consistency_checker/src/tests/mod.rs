@@ -1,22 +1,30 @@
 //! Tests for the consistency checker component.
-use std::{collections::HashMap, slice};
+use std::{
+    collections::HashMap,
+    slice,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 use assert_matches::assert_matches;
 use once_cell::sync::Lazy;
 use test_casing::{test_casing, Product};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Barrier};
 use zksync_config::GenesisConfig;
 use zksync_dal::Connection;
-use zksync_eth_client::{clients::MockSettlementLayer, Options};
+use zksync_eth_client::{clients::MockSettlementLayer, ClientError, Options};
 use zksync_l1_contract_interface::{i_executor::methods::CommitBatches, Tokenizable, Tokenize};
 use zksync_node_genesis::{insert_genesis_batch, mock_genesis_config, GenesisParams};
 use zksync_node_test_utils::{
     create_l1_batch, create_l1_batch_metadata, l1_batch_metadata_to_commitment_artifacts,
 };
 use zksync_types::{
-    aggregated_operations::AggregatedActionType, commitment::L1BatchWithMetadata,
-    protocol_version::ProtocolSemanticVersion, web3::Log, ProtocolVersion, ProtocolVersionId, H256,
+    aggregated_operations::AggregatedActionType,
+    commitment::L1BatchWithMetadata,
+    protocol_version::ProtocolSemanticVersion,
+    web3::{self, BlockId, Log},
+    ProtocolVersion, ProtocolVersionId, H256,
 };
+use zksync_web3_decl::client::MockClient;
 
 use super::*;
 
@@ -101,13 +109,16 @@ pub(crate) fn create_mock_checker(
         contract: zksync_contracts::hyperchain_contract(),
         diamond_proxy_addr: Some(DIAMOND_PROXY_ADDR),
         max_batches_to_recheck: 100,
+        max_concurrent_checks: NonZeroU32::MIN,
         sleep_interval: Duration::from_millis(10),
         l1_client: Box::new(client.into_client()),
         event_handler: Box::new(health_updater),
         l1_data_mismatch_behavior: L1DataMismatchBehavior::Bail,
         pool,
+        read_only: false,
         commitment_mode,
         health_check,
+        alert_sink: Arc::new(NoopAlertSink),
     }
 }
 
@@ -128,6 +139,125 @@ fn create_mock_ethereum() -> MockSettlementLayer {
     mock.build()
 }
 
+/// Wraps the mock L1 client so that the first `blocked_receipts` receipt requests are held until all of them
+/// are in flight at once. Returns the client together with the maximum observed number of pending requests.
+fn create_barrier_client(
+    client: MockSettlementLayer,
+    blocked_receipts: usize,
+) -> (MockClient<L1>, Arc<AtomicUsize>) {
+    let inner = Arc::new(client.into_client());
+    let barrier = Arc::new(Barrier::new(blocked_receipts));
+    let receipt_requests = Arc::new(AtomicUsize::new(0));
+    let pending_requests = Arc::new(AtomicUsize::new(0));
+    let max_pending_requests = Arc::new(AtomicUsize::new(0));
+
+    let client = MockClient::builder(L1::default())
+        .method("eth_call", {
+            let inner = inner.clone();
+            move |req: web3::CallRequest, block: BlockId| {
+                let inner = inner.clone();
+                async move {
+                    inner
+                        .call_contract_function(req, Some(block))
+                        .await
+                        .map_err(|err| ClientError::Custom(err.to_string()))
+                }
+            }
+        })
+        .method("eth_getTransactionByHash", {
+            let inner = inner.clone();
+            move |hash: H256| {
+                let inner = inner.clone();
+                async move {
+                    inner
+                        .get_tx(hash)
+                        .await
+                        .map_err(|err| ClientError::Custom(err.to_string()))
+                }
+            }
+        })
+        .method("eth_getTransactionReceipt", {
+            let max_pending_requests = max_pending_requests.clone();
+            move |hash: H256| {
+                let inner = inner.clone();
+                let barrier = barrier.clone();
+                let pending_requests = pending_requests.clone();
+                let max_pending_requests = max_pending_requests.clone();
+                let request_index = receipt_requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    let pending = pending_requests.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_pending_requests.fetch_max(pending, Ordering::SeqCst);
+                    if request_index < blocked_receipts {
+                        tokio::time::timeout(Duration::from_secs(10), barrier.wait())
+                            .await
+                            .expect("receipt requests are not issued concurrently");
+                    }
+                    let receipt = inner
+                        .tx_receipt(hash)
+                        .await
+                        .map_err(|err| ClientError::Custom(err.to_string()));
+                    pending_requests.fetch_sub(1, Ordering::SeqCst);
+                    receipt
+                }
+            }
+        })
+        .build();
+    (client, max_pending_requests)
+}
+
+/// Wraps the mock L1 client so that the first `missing_tx_requests` requests for a transaction return `None`,
+/// as if the L1 node hadn't received the transaction yet. Returns the client together with the number of
+/// transaction requests.
+fn create_lagging_tx_client(
+    client: MockSettlementLayer,
+    missing_tx_requests: usize,
+) -> (MockClient<L1>, Arc<AtomicUsize>) {
+    let inner = Arc::new(client.into_client());
+    let tx_requests = Arc::new(AtomicUsize::new(0));
+
+    let client = MockClient::builder(L1::default())
+        .method("eth_call", {
+            let inner = inner.clone();
+            move |req: web3::CallRequest, block: BlockId| {
+                let inner = inner.clone();
+                async move {
+                    inner
+                        .call_contract_function(req, Some(block))
+                        .await
+                        .map_err(|err| ClientError::Custom(err.to_string()))
+                }
+            }
+        })
+        .method("eth_getTransactionByHash", {
+            let inner = inner.clone();
+            let tx_requests = tx_requests.clone();
+            move |hash: H256| {
+                let inner = inner.clone();
+                let request_index = tx_requests.fetch_add(1, Ordering::SeqCst);
+                async move {
+                    if request_index < missing_tx_requests {
+                        return Ok(None);
+                    }
+                    inner
+                        .get_tx(hash)
+                        .await
+                        .map_err(|err| ClientError::Custom(err.to_string()))
+                }
+            }
+        })
+        .method("eth_getTransactionReceipt", move |hash: H256| {
+            let inner = inner.clone();
+            async move {
+                inner
+                    .tx_receipt(hash)
+                    .await
+                    .map_err(|err| ClientError::Custom(err.to_string()))
+            }
+        })
+        .build();
+    (client, tx_requests)
+}
+
 impl HandleConsistencyCheckerEvent for mpsc::UnboundedSender<L1BatchNumber> {
     fn initialize(&mut self) {
         // Do nothing
@@ -172,6 +302,14 @@ fn build_commit_tx_input_data_is_correct(commitment_mode: L1BatchCommitmentMode)
     }
 }
 
+#[test]
+fn missing_commit_tx_is_retriable() {
+    let err = CheckError::TxNotAvailable(anyhow::anyhow!("commit transaction not found on L1"));
+    assert!(err.is_retriable());
+    let err = CheckError::Internal(anyhow::anyhow!("missing L1 batch"));
+    assert!(!err.is_retriable());
+}
+
 #[test]
 fn extracting_commit_data_for_boojum_batch() {
     let contract = zksync_contracts::hyperchain_contract();
@@ -459,6 +597,270 @@ async fn normal_checker_function(
     checker_task.await.unwrap().unwrap();
 }
 
+#[test_casing(6, Product(([2, 4, 16], COMMITMENT_MODES)))]
+#[tokio::test]
+async fn checker_with_concurrent_checks(
+    max_concurrent_checks: u32,
+    commitment_mode: L1BatchCommitmentMode,
+) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batches: Vec<_> = (1..=10).map(create_l1_batch_with_metadata).collect();
+    let mut commit_tx_hash_by_l1_batch = HashMap::with_capacity(l1_batches.len());
+    let client = create_mock_ethereum();
+
+    for (i, l1_batches) in l1_batches.chunks(3).enumerate() {
+        let input_data = build_commit_tx_input_data(l1_batches, commitment_mode);
+        let signed_tx = client.sign_prepared_tx(
+            input_data.clone(),
+            VALIDATOR_TIMELOCK_ADDR,
+            Options {
+                nonce: Some(i.into()),
+                ..Options::default()
+            },
+        );
+        let signed_tx = signed_tx.unwrap();
+        client.as_ref().send_raw_tx(signed_tx.raw_tx).await.unwrap();
+        client
+            .execute_tx(signed_tx.hash, true, 1)
+            .with_logs(l1_batches.iter().map(l1_batch_commit_log).collect());
+
+        commit_tx_hash_by_l1_batch.extend(
+            l1_batches
+                .iter()
+                .map(|batch| (batch.header.number, signed_tx.hash)),
+        );
+    }
+
+    // Persist all batches upfront so that every window of checks is full.
+    let save_actions_mapper = SAVE_ACTION_MAPPERS[0].1;
+    for save_action in save_actions_mapper(&l1_batches) {
+        save_action
+            .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+            .await;
+    }
+
+    // Checks in the first window must all wait for their commit tx receipts at the same time.
+    let blocked_receipts = (max_concurrent_checks as usize).min(l1_batches.len());
+    let (l1_client, max_pending_requests) = create_barrier_client(client.clone(), blocked_receipts);
+
+    let (l1_batch_updates_sender, mut l1_batch_updates_receiver) = mpsc::unbounded_channel();
+    let checker = ConsistencyChecker {
+        event_handler: Box::new(l1_batch_updates_sender),
+        l1_client: Box::new(l1_client),
+        ..create_mock_checker(client, pool.clone(), commitment_mode)
+    }
+    .with_max_concurrent_checks(NonZeroU32::new(max_concurrent_checks).unwrap());
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let checker_task = tokio::spawn(checker.run(stop_receiver));
+
+    // Checked batches must be reported strictly in order, without gaps.
+    let mut expected_batch = L1BatchNumber(1);
+    loop {
+        let checked_batch = l1_batch_updates_receiver.recv().await.unwrap();
+        assert_eq!(checked_batch, expected_batch);
+
+        let last_processed_batch = storage
+            .blocks_dal()
+            .get_consistency_checker_last_processed_l1_batch()
+            .await
+            .unwrap();
+        assert!(last_processed_batch >= checked_batch);
+
+        if checked_batch == l1_batches.last().unwrap().header.number {
+            break;
+        }
+        expected_batch += 1;
+    }
+
+    let last_processed_batch = storage
+        .blocks_dal()
+        .get_consistency_checker_last_processed_l1_batch()
+        .await
+        .unwrap();
+    assert_eq!(last_processed_batch, L1BatchNumber(10));
+    assert_eq!(
+        max_pending_requests.load(Ordering::SeqCst),
+        blocked_receipts
+    );
+
+    stop_sender.send_replace(true);
+    checker_task.await.unwrap().unwrap();
+}
+
+#[test_casing(2, COMMITMENT_MODES)]
+#[tokio::test]
+async fn checker_retries_commit_tx_not_available_yet(commitment_mode: L1BatchCommitmentMode) {
+    const MISSING_TX_REQUESTS: usize = 2;
+
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batch = create_l1_batch_with_metadata(1);
+    let client = create_mock_ethereum();
+    let signed_tx = client
+        .sign_prepared_tx(
+            build_commit_tx_input_data(slice::from_ref(&l1_batch), commitment_mode),
+            VALIDATOR_TIMELOCK_ADDR,
+            Options {
+                nonce: Some(0.into()),
+                ..Options::default()
+            },
+        )
+        .unwrap();
+    client.as_ref().send_raw_tx(signed_tx.raw_tx).await.unwrap();
+    client
+        .execute_tx(signed_tx.hash, true, 1)
+        .with_logs(vec![l1_batch_commit_log(&l1_batch)]);
+
+    let commit_tx_hash_by_l1_batch = HashMap::from([(l1_batch.header.number, signed_tx.hash)]);
+    let save_actions = [
+        SaveAction::InsertBatch(&l1_batch),
+        SaveAction::SaveMetadata(&l1_batch),
+        SaveAction::InsertCommitTx(l1_batch.header.number),
+    ];
+    for save_action in save_actions {
+        save_action
+            .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+            .await;
+    }
+
+    let (l1_client, tx_requests) = create_lagging_tx_client(client.clone(), MISSING_TX_REQUESTS);
+    let (l1_batch_updates_sender, mut l1_batch_updates_receiver) = mpsc::unbounded_channel();
+    let checker = ConsistencyChecker {
+        event_handler: Box::new(l1_batch_updates_sender),
+        l1_client: Box::new(l1_client),
+        ..create_mock_checker(client, pool.clone(), commitment_mode)
+    };
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let checker_task = tokio::spawn(checker.run(stop_receiver));
+
+    // The checker must not fail while the commit tx is missing, and validate the batch once the tx shows up.
+    let checked_batch =
+        tokio::time::timeout(Duration::from_secs(10), l1_batch_updates_receiver.recv())
+            .await
+            .expect("L1 batch was not checked")
+            .unwrap();
+    assert_eq!(checked_batch, l1_batch.header.number);
+    assert_eq!(tx_requests.load(Ordering::SeqCst), MISSING_TX_REQUESTS + 1);
+
+    let last_processed_batch = storage
+        .blocks_dal()
+        .get_consistency_checker_last_processed_l1_batch()
+        .await
+        .unwrap();
+    assert_eq!(last_processed_batch, l1_batch.header.number);
+
+    stop_sender.send_replace(true);
+    checker_task.await.unwrap().unwrap();
+}
+
+#[test_casing(2, COMMITMENT_MODES)]
+#[tokio::test]
+async fn read_only_checker_does_not_persist_progress(commitment_mode: L1BatchCommitmentMode) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batches: Vec<_> = (1..=5).map(create_l1_batch_with_metadata).collect();
+    let client = create_mock_ethereum();
+    let input_data = build_commit_tx_input_data(&l1_batches, commitment_mode);
+    let signed_tx = client
+        .sign_prepared_tx(input_data, VALIDATOR_TIMELOCK_ADDR, Options::default())
+        .unwrap();
+    client.as_ref().send_raw_tx(signed_tx.raw_tx).await.unwrap();
+    client
+        .execute_tx(signed_tx.hash, true, 1)
+        .with_logs(l1_batches.iter().map(l1_batch_commit_log).collect());
+    let commit_tx_hash_by_l1_batch: HashMap<_, _> = l1_batches
+        .iter()
+        .map(|batch| (batch.header.number, signed_tx.hash))
+        .collect();
+
+    let save_actions_mapper = SAVE_ACTION_MAPPERS[0].1;
+    for save_action in save_actions_mapper(&l1_batches) {
+        save_action
+            .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+            .await;
+    }
+    // Progress persisted by another checker instance must be ignored.
+    storage
+        .blocks_dal()
+        .set_consistency_checker_last_processed_l1_batch(L1BatchNumber(3))
+        .await
+        .unwrap();
+
+    let (l1_batch_updates_sender, mut l1_batch_updates_receiver) = mpsc::unbounded_channel();
+    let checker = ConsistencyChecker {
+        event_handler: Box::new(l1_batch_updates_sender),
+        ..create_mock_checker(client, pool.clone(), commitment_mode)
+    }
+    .with_read_only(true);
+
+    let (stop_sender, stop_receiver) = watch::channel(false);
+    let checker_task = tokio::spawn(checker.run(stop_receiver));
+
+    for expected_batch in 1..=5 {
+        let checked_batch = l1_batch_updates_receiver.recv().await.unwrap();
+        assert_eq!(checked_batch, L1BatchNumber(expected_batch));
+    }
+    stop_sender.send_replace(true);
+    checker_task.await.unwrap().unwrap();
+
+    let last_processed_batch = storage
+        .blocks_dal()
+        .get_consistency_checker_last_processed_l1_batch()
+        .await
+        .unwrap();
+    assert_eq!(last_processed_batch, L1BatchNumber(3));
+}
+
+#[test_casing(2, COMMITMENT_MODES)]
+#[tokio::test]
+async fn read_only_checker_detects_incorrect_tx_data(commitment_mode: L1BatchCommitmentMode) {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+
+    let l1_batch = create_l1_batch_with_metadata(1);
+    let client = create_mock_ethereum();
+    let commit_tx_hash = IncorrectDataKind::MismatchedCommitDataTimestamp
+        .apply(&client, &l1_batch, commitment_mode)
+        .await;
+    let commit_tx_hash_by_l1_batch = HashMap::from([(l1_batch.header.number, commit_tx_hash)]);
+    let save_actions = [
+        SaveAction::InsertBatch(&l1_batch),
+        SaveAction::SaveMetadata(&l1_batch),
+        SaveAction::InsertCommitTx(l1_batch.header.number),
+    ];
+    for save_action in save_actions {
+        save_action
+            .apply(&mut storage, &commit_tx_hash_by_l1_batch)
+            .await;
+    }
+    drop(storage);
+
+    let checker = create_mock_checker(client, pool, commitment_mode).with_read_only(true);
+    let (_stop_sender, stop_receiver) = watch::channel(false);
+    tokio::time::timeout(Duration::from_secs(30), checker.run(stop_receiver))
+        .await
+        .expect("Timed out waiting for checker to stop")
+        .unwrap_err();
+}
+
 #[test_casing(8, Product((SAVE_ACTION_MAPPERS, COMMITMENT_MODES)))]
 #[tokio::test]
 async fn checker_processes_pre_boojum_batches(
@@ -0,0 +1,172 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use zksync_health_check::alerts::{Alert, AlertSink, LogAlertSink};
+
+use crate::{
+    implementations::resources::alerts::AlertSinkResource,
+    wiring_layer::{WiringError, WiringLayer},
+};
+
+/// Sink posting alerts as JSON to a webhook URL. Alerts that cannot be delivered are passed
+/// to the fallback sink (by default, logged) so that they aren't lost.
+#[derive(Debug, Clone)]
+pub struct WebhookAlertSink {
+    client: reqwest::Client,
+    url: String,
+    fallback: Arc<dyn AlertSink>,
+}
+
+impl WebhookAlertSink {
+    const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+            fallback: Arc::new(LogAlertSink),
+        }
+    }
+
+    #[cfg(test)]
+    fn with_fallback(mut self, fallback: Arc<dyn AlertSink>) -> Self {
+        self.fallback = fallback;
+        self
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookAlertSink {
+    async fn send(&self, alert: Alert) {
+        let response = self
+            .client
+            .post(&self.url)
+            .timeout(Self::REQUEST_TIMEOUT)
+            .json(&alert)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(err) = response {
+            tracing::error!("Failed sending alert to webhook: {err}");
+            self.fallback.send(alert).await;
+        }
+    }
+}
+
+/// Wiring layer providing the [`AlertSinkResource`]. Alerts are posted to the webhook if its URL
+/// is specified, and are logged otherwise.
+#[derive(Debug, Default)]
+pub struct AlertSinkLayer {
+    webhook_url: Option<String>,
+}
+
+impl AlertSinkLayer {
+    pub fn new(webhook_url: Option<String>) -> Self {
+        Self { webhook_url }
+    }
+}
+
+#[async_trait]
+impl WiringLayer for AlertSinkLayer {
+    type Input = ();
+    type Output = AlertSinkResource;
+
+    fn layer_name(&self) -> &'static str {
+        "alert_sink_layer"
+    }
+
+    async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
+        Ok(match self.webhook_url {
+            Some(url) => AlertSinkResource(Arc::new(WebhookAlertSink::new(url))),
+            None => AlertSinkResource(Arc::new(LogAlertSink)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use axum::{http::StatusCode, routing::post, Json, Router};
+    use tokio::{net::TcpListener, sync::mpsc};
+    use zksync_health_check::alerts::AlertSeverity;
+
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct RecordingAlertSink(Mutex<Vec<Alert>>);
+
+    #[async_trait]
+    impl AlertSink for RecordingAlertSink {
+        async fn send(&self, alert: Alert) {
+            self.0.lock().unwrap().push(alert);
+        }
+    }
+
+    /// Starts a local webhook responding with the specified status. Returns the webhook URL
+    /// and a receiver of the posted request bodies.
+    async fn start_webhook(
+        status: StatusCode,
+    ) -> (String, mpsc::UnboundedReceiver<serde_json::Value>) {
+        let (body_sender, body_receiver) = mpsc::unbounded_channel();
+        let app = Router::new().route(
+            "/alerts",
+            post(move |Json(body): Json<serde_json::Value>| async move {
+                body_sender.send(body).ok();
+                status
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        (format!("http://{local_addr}/alerts"), body_receiver)
+    }
+
+    fn test_alert() -> Alert {
+        Alert::new("test", AlertSeverity::Critical, "Test alert")
+            .with_details(serde_json::json!({ "l1_batch": 1 }))
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_posts_alerts() {
+        let (url, mut body_receiver) = start_webhook(StatusCode::OK).await;
+        let fallback = Arc::new(RecordingAlertSink::default());
+        let sink = WebhookAlertSink::new(url).with_fallback(fallback.clone());
+
+        sink.send(test_alert()).await;
+
+        let body = body_receiver.recv().await.unwrap();
+        assert_eq!(body["component"], "test");
+        assert_eq!(body["severity"], "critical");
+        assert_eq!(body["summary"], "Test alert");
+        assert_eq!(body["details"], serde_json::json!({ "l1_batch": 1 }));
+        assert!(fallback.0.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_falls_back_on_error_response() {
+        let (url, mut body_receiver) = start_webhook(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let fallback = Arc::new(RecordingAlertSink::default());
+        let sink = WebhookAlertSink::new(url).with_fallback(fallback.clone());
+
+        sink.send(test_alert()).await;
+
+        body_receiver.recv().await.unwrap();
+        assert_eq!(*fallback.0.lock().unwrap(), [test_alert()]);
+    }
+
+    #[tokio::test]
+    async fn webhook_sink_falls_back_if_webhook_is_unreachable() {
+        // Bind and immediately drop a listener to get a local address nobody listens on.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+        drop(listener);
+        let fallback = Arc::new(RecordingAlertSink::default());
+        let sink = WebhookAlertSink::new(format!("http://{local_addr}/alerts"))
+            .with_fallback(fallback.clone());
+
+        sink.send(test_alert()).await;
+
+        assert_eq!(*fallback.0.lock().unwrap(), [test_alert()]);
+    }
+}
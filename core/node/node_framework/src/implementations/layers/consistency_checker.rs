@@ -1,8 +1,11 @@
+use std::num::NonZeroU32;
+
 use zksync_consistency_checker::ConsistencyChecker;
 use zksync_types::{commitment::L1BatchCommitmentMode, Address};
 
 use crate::{
     implementations::resources::{
+        alerts::AlertSinkResource,
         eth_interface::EthInterfaceResource,
         healthcheck::AppHealthCheckResource,
         pools::{MasterPool, PoolResource},
@@ -18,6 +21,8 @@ use crate::{
 pub struct ConsistencyCheckerLayer {
     diamond_proxy_addr: Address,
     max_batches_to_recheck: u32,
+    max_concurrent_checks: NonZeroU32,
+    read_only: bool,
     commitment_mode: L1BatchCommitmentMode,
 }
 
@@ -28,6 +33,8 @@ pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
+    #[context(default)]
+    pub alert_sink: AlertSinkResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -46,9 +53,23 @@ impl ConsistencyCheckerLayer {
         Self {
             diamond_proxy_addr,
             max_batches_to_recheck,
+            max_concurrent_checks: NonZeroU32::MIN,
+            read_only: false,
             commitment_mode,
         }
     }
+
+    /// Sets the maximum number of L1 batches checked concurrently. By default, batches are checked one by one.
+    pub fn with_max_concurrent_checks(mut self, max_concurrent_checks: NonZeroU32) -> Self {
+        self.max_concurrent_checks = max_concurrent_checks;
+        self
+    }
+
+    /// Makes the checker track its progress in memory instead of persisting it to the DB.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -73,7 +94,10 @@ impl WiringLayer for ConsistencyCheckerLayer {
             self.commitment_mode,
         )
         .map_err(WiringError::Internal)?
-        .with_diamond_proxy_addr(self.diamond_proxy_addr);
+        .with_diamond_proxy_addr(self.diamond_proxy_addr)
+        .with_max_concurrent_checks(self.max_concurrent_checks)
+        .with_read_only(self.read_only)
+        .with_alert_sink(input.alert_sink.0);
 
         input
             .app_health
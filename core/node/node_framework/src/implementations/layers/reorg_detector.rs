@@ -2,6 +2,7 @@ use zksync_reorg_detector::{self, ReorgDetector};
 
 use crate::{
     implementations::resources::{
+        alerts::AlertSinkResource,
         healthcheck::AppHealthCheckResource,
         main_node_client::MainNodeClientResource,
         pools::{MasterPool, PoolResource},
@@ -26,6 +27,8 @@ pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
     #[context(default)]
     pub app_health: AppHealthCheckResource,
+    #[context(default)]
+    pub alert_sink: AlertSinkResource,
 }
 
 #[derive(Debug, IntoContext)]
@@ -48,7 +51,8 @@ impl WiringLayer for ReorgDetectorLayer {
         let MainNodeClientResource(main_node_client) = input.main_node_client;
         let pool = input.master_pool.get().await?;
 
-        let reorg_detector = ReorgDetector::new(main_node_client, pool);
+        let reorg_detector =
+            ReorgDetector::new(main_node_client, pool).with_alert_sink(input.alert_sink.0);
 
         let AppHealthCheckResource(app_health) = input.app_health;
         app_health
@@ -3,10 +3,13 @@ use via_btc_client::{
     types::{BitcoinNetwork, NodeAuth},
 };
 use via_btc_sender::btc_inscription_aggregator::ViaBtcInscriptionAggregator;
-use zksync_config::ViaBtcSenderConfig;
+use zksync_config::{ViaBtcClientConfig, ViaBtcSenderConfig};
 
 use crate::{
-    implementations::resources::pools::{MasterPool, PoolResource},
+    implementations::resources::{
+        da_client::DAClientResource,
+        pools::{MasterPool, PoolResource},
+    },
     service::StopReceiver,
     task::{Task, TaskId},
     wiring_layer::{WiringError, WiringLayer},
@@ -22,6 +25,7 @@ use crate::{
 /// ## Requests resources
 ///
 /// - `PoolResource<MasterPool>`
+/// - `DAClientResource` (optional; used to check blob availability before inscribing)
 ///
 /// ## Adds tasks
 ///
@@ -29,12 +33,14 @@ use crate::{
 #[derive(Debug)]
 pub struct ViaBtcInscriptionAggregatorLayer {
     config: ViaBtcSenderConfig,
+    btc_client_config: ViaBtcClientConfig,
 }
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
+    pub da_client: Option<DAClientResource>,
 }
 
 #[derive(Debug, IntoContext)]
@@ -45,8 +51,11 @@ pub struct Output {
 }
 
 impl ViaBtcInscriptionAggregatorLayer {
-    pub fn new(config: ViaBtcSenderConfig) -> Self {
-        Self { config }
+    pub fn new(config: ViaBtcSenderConfig, btc_client_config: ViaBtcClientConfig) -> Self {
+        Self {
+            config,
+            btc_client_config,
+        }
     }
 }
 
@@ -66,7 +75,7 @@ impl WiringLayer for ViaBtcInscriptionAggregatorLayer {
         let network = BitcoinNetwork::from_core_arg(self.config.network())
             .map_err(|_| WiringError::Configuration("Wrong network in config".to_string()))?;
 
-        let inscriber = Inscriber::new(
+        let inscriber = Inscriber::new_with_config(
             self.config.rpc_url(),
             network,
             NodeAuth::UserPass(
@@ -75,12 +84,19 @@ impl WiringLayer for ViaBtcInscriptionAggregatorLayer {
             ),
             self.config.private_key(),
             None,
+            &self.btc_client_config,
         )
         .await
-        .unwrap();
+        .unwrap()
+        .with_change_output_type(self.config.change_output_type())
+        .with_min_fee_rate(self.config.min_fee_rate());
 
-        let via_btc_inscription_aggregator =
+        let mut via_btc_inscription_aggregator =
             ViaBtcInscriptionAggregator::new(inscriber, master_pool, self.config).await?;
+        if let Some(da_client) = input.da_client {
+            via_btc_inscription_aggregator =
+                via_btc_inscription_aggregator.with_da_client(da_client.0);
+        }
 
         Ok(Output {
             via_btc_inscription_aggregator,
@@ -2,7 +2,7 @@ use anyhow::Context;
 use via_btc_client::{inscriber::Inscriber, types::NodeAuth};
 use via_btc_sender::btc_inscription_manager::ViaBtcInscriptionManager;
 use via_btc_watch::BitcoinNetwork;
-use zksync_config::ViaBtcSenderConfig;
+use zksync_config::{ViaBtcClientConfig, ViaBtcSenderConfig};
 
 use crate::{
     implementations::resources::pools::{MasterPool, PoolResource},
@@ -27,6 +27,7 @@ use crate::{
 #[derive(Debug)]
 pub struct ViaInscriptionManagerLayer {
     pub config: ViaBtcSenderConfig,
+    pub btc_client_config: ViaBtcClientConfig,
 }
 
 #[derive(Debug, FromContext)]
@@ -43,8 +44,11 @@ pub struct Output {
 }
 
 impl ViaInscriptionManagerLayer {
-    pub fn new(config: ViaBtcSenderConfig) -> Self {
-        Self { config }
+    pub fn new(config: ViaBtcSenderConfig, btc_client_config: ViaBtcClientConfig) -> Self {
+        Self {
+            config,
+            btc_client_config,
+        }
     }
 }
 
@@ -64,7 +68,7 @@ impl WiringLayer for ViaInscriptionManagerLayer {
         let network = BitcoinNetwork::from_core_arg(self.config.network())
             .map_err(|_| WiringError::Configuration("Wrong network in config".to_string()))?;
 
-        let inscriber = Inscriber::new(
+        let inscriber = Inscriber::new_with_config(
             self.config.rpc_url(),
             network,
             NodeAuth::UserPass(
@@ -73,9 +77,12 @@ impl WiringLayer for ViaInscriptionManagerLayer {
             ),
             self.config.private_key(),
             None,
+            &self.btc_client_config,
         )
         .await
-        .context("Init inscriber")?;
+        .context("Init inscriber")?
+        .with_change_output_type(self.config.change_output_type())
+        .with_min_fee_rate(self.config.min_fee_rate());
 
         let via_btc_inscription_manager =
             ViaBtcInscriptionManager::new(inscriber, master_pool, self.config)
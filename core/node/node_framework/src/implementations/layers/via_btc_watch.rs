@@ -1,10 +1,17 @@
-use via_btc_client::{indexer::BitcoinInscriptionIndexer, types::NodeAuth};
+use futures::StreamExt;
+use via_btc_client::{
+    indexer::{BitcoinInscriptionIndexer, BlockArchive},
+    types::NodeAuth,
+    zmq,
+};
 use via_btc_watch::{BitcoinNetwork, BtcWatch};
-use zksync_config::ViaBtcWatchConfig;
+use zksync_config::{ViaBtcClientConfig, ViaBtcWatchConfig};
 
 use crate::{
     implementations::resources::{
+        healthcheck::AppHealthCheckResource,
         pools::{MasterPool, PoolResource},
+        via_btc_client::BtcClientResource,
         via_btc_indexer::BtcIndexerResource,
     },
     service::StopReceiver,
@@ -20,25 +27,32 @@ use crate::{
 pub struct BtcWatchLayer {
     // TODO: divide into multiple configs
     btc_watch_config: ViaBtcWatchConfig,
+    btc_client_config: ViaBtcClientConfig,
 }
 
 #[derive(Debug, FromContext)]
 #[context(crate = crate)]
 pub struct Input {
     pub master_pool: PoolResource<MasterPool>,
+    #[context(default)]
+    pub app_health: AppHealthCheckResource,
 }
 
 #[derive(Debug, IntoContext)]
 #[context(crate = crate)]
 pub struct Output {
     pub btc_indexer_resource: BtcIndexerResource,
+    pub btc_client_resource: BtcClientResource,
     #[context(task)]
     pub btc_watch: BtcWatch,
 }
 
 impl BtcWatchLayer {
-    pub fn new(btc_watch_config: ViaBtcWatchConfig) -> Self {
-        Self { btc_watch_config }
+    pub fn new(btc_watch_config: ViaBtcWatchConfig, btc_client_config: ViaBtcClientConfig) -> Self {
+        Self {
+            btc_watch_config,
+            btc_client_config,
+        }
     }
 }
 
@@ -71,29 +85,62 @@ impl WiringLayer for BtcWatchLayer {
         let btc_blocks_lag = self.btc_watch_config.btc_blocks_lag();
 
         let indexer = BtcIndexerResource::from(
-            BitcoinInscriptionIndexer::new(
+            BitcoinInscriptionIndexer::new_with_config(
                 self.btc_watch_config.rpc_url(),
                 network,
                 node_auth.clone(),
                 bootstrap_txids.clone(),
+                &self.btc_client_config,
             )
             .await
             .map_err(|e| WiringError::Internal(e.into()))?,
         );
-        let btc_watch = BtcWatch::new(
+        let btc_client = BtcClientResource(indexer.0.client());
+        let mut btc_watch = BtcWatch::new(
             self.btc_watch_config.rpc_url(),
             network,
             node_auth,
+            &self.btc_client_config,
             self.btc_watch_config.confirmations_for_btc_msg,
             bootstrap_txids,
             main_pool,
             self.btc_watch_config.poll_interval(),
             btc_blocks_lag,
+            self.btc_watch_config.metrics_enabled(),
         )
-        .await?;
+        .await?
+        .with_poll_jitter(self.btc_watch_config.poll_jitter())
+        .with_max_message_bytes(self.btc_watch_config.max_message_bytes_per_iteration())
+        .with_strict_inscription_validation(self.btc_watch_config.strict_inscription_validation())
+        .with_max_clock_skew(self.btc_watch_config.max_clock_skew());
+
+        if let Some((path, capacity)) = self.btc_watch_config.block_archive() {
+            let archive = BlockArchive::new(path, capacity).map_err(WiringError::internal)?;
+            btc_watch = btc_watch.with_block_archive(archive);
+        }
+
+        if let Some(zmq_endpoint) = self.btc_watch_config.zmq_block_endpoint() {
+            match zmq::subscribe_new_blocks(zmq_endpoint).await {
+                Ok(notifications) => {
+                    btc_watch = btc_watch.with_block_notifications(notifications.boxed());
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed subscribing to block notifications at {zmq_endpoint}, relying on polling: {err}"
+                    );
+                }
+            }
+        }
+
+        input
+            .app_health
+            .0
+            .insert_component(btc_watch.health_check().clone())
+            .map_err(WiringError::internal)?;
 
         Ok(Output {
             btc_indexer_resource: indexer,
+            btc_client_resource: btc_client,
             btc_watch,
         })
     }
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use via_fee_model::{ViaApiFeeInputProvider, ViaMainNodeFeeInputProvider};
-use zksync_config::configs::chain::StateKeeperConfig;
-use zksync_types::fee_model::FeeModelConfig;
+use via_fee_model::{FixedFeeInputProvider, ViaApiFeeInputProvider, ViaMainNodeFeeInputProvider};
+use zksync_config::{configs::chain::StateKeeperConfig, ViaFeeModelConfig};
+use zksync_types::fee_model::{BatchFeeInput, FeeModelConfig};
 
 use crate::{
     implementations::resources::fee_input::{ApiFeeInputResource, SequencerFeeInputResource},
@@ -15,6 +15,7 @@ use crate::{
 #[derive(Debug)]
 pub struct ViaL1GasLayer {
     state_keeper_config: StateKeeperConfig,
+    fee_model_config: ViaFeeModelConfig,
 }
 
 #[derive(Debug, IntoContext)]
@@ -25,9 +26,13 @@ pub struct Output {
 }
 
 impl ViaL1GasLayer {
-    pub fn new(state_keeper_config: StateKeeperConfig) -> Self {
+    pub fn new(
+        state_keeper_config: StateKeeperConfig,
+        fee_model_config: ViaFeeModelConfig,
+    ) -> Self {
         Self {
             state_keeper_config,
+            fee_model_config,
         }
     }
 }
@@ -42,9 +47,27 @@ impl WiringLayer for ViaL1GasLayer {
     }
 
     async fn wire(self, _input: Self::Input) -> Result<Self::Output, WiringError> {
-        let main_fee_input_provider = Arc::new(ViaMainNodeFeeInputProvider::new(
-            FeeModelConfig::from_state_keeper_config(&self.state_keeper_config),
-        )?);
+        let fee_model_config = FeeModelConfig::from_state_keeper_config(&self.state_keeper_config);
+
+        if let Some((l1_gas_price, fair_l2_gas_price, fair_pubdata_price)) =
+            self.fee_model_config.fixed_fee_input()?
+        {
+            tracing::info!("Using a fixed batch fee input");
+            let fixed_fee_input_provider = Arc::new(FixedFeeInputProvider::new(
+                fee_model_config,
+                BatchFeeInput::pubdata_independent(
+                    l1_gas_price,
+                    fair_l2_gas_price,
+                    fair_pubdata_price,
+                ),
+            )?);
+            return Ok(Output {
+                sequencer_fee_input: fixed_fee_input_provider.clone().into(),
+                api_fee_input: fixed_fee_input_provider.into(),
+            });
+        }
+
+        let main_fee_input_provider = Arc::new(ViaMainNodeFeeInputProvider::new(fee_model_config)?);
 
         let api_fee_input_provider =
             Arc::new(ViaApiFeeInputProvider::new(main_fee_input_provider.clone()));
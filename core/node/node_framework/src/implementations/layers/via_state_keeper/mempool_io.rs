@@ -100,6 +100,9 @@ impl WiringLayer for MempoolIOLayer {
     }
 
     async fn wire(self, input: Self::Input) -> Result<Self::Output, WiringError> {
+        // Must be set before the mempool registers its metrics.
+        via_state_keeper::metrics::set_metrics_enabled(self.state_keeper_config.metrics_enabled());
+
         let batch_fee_input_provider = input.fee_input.0;
         let master_pool = input.master_pool;
 
@@ -11,6 +11,7 @@ use crate::{
         healthcheck::AppHealthCheckResource,
         pools::{PoolResource, ReplicaPool},
         sync_state::SyncStateResource,
+        via_btc_client::BtcClientResource,
         web3_api::{MempoolCacheResource, TreeApiClientResource, TxSenderResource},
     },
     service::StopReceiver,
@@ -81,6 +82,7 @@ enum Transport {
 /// - `TxSenderResource`
 /// - `SyncStateResource` (optional)
 /// - `TreeApiClientResource` (optional)
+/// - `BtcClientResource` (optional)
 /// - `MempoolCacheResource`
 /// - `CircuitBreakersResource` (adds a circuit breaker)
 /// - `AppHealthCheckResource` (adds a health check)
@@ -104,6 +106,7 @@ pub struct Input {
     pub tx_sender: TxSenderResource,
     pub sync_state: Option<SyncStateResource>,
     pub tree_api_client: Option<TreeApiClientResource>,
+    pub btc_client: Option<BtcClientResource>,
     pub mempool_cache: MempoolCacheResource,
     #[context(default)]
     pub circuit_breakers: CircuitBreakersResource,
@@ -169,6 +172,7 @@ impl WiringLayer for Web3ServerLayer {
         let MempoolCacheResource(mempool_cache) = input.mempool_cache;
         let sync_state = input.sync_state.map(|state| state.0);
         let tree_api_client = input.tree_api_client.map(|client| client.0);
+        let btc_client = input.btc_client.map(|client| client.0);
 
         // Build server.
         let mut api_builder =
@@ -180,6 +184,9 @@ impl WiringLayer for Web3ServerLayer {
         if let Some(client) = tree_api_client {
             api_builder = api_builder.with_tree_api(client);
         }
+        if let Some(client) = btc_client {
+            api_builder = api_builder.with_btc_client(client);
+        }
         match self.transport {
             Transport::Http => {
                 api_builder = api_builder.http(self.port);
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use zksync_health_check::alerts::NoopAlertSink;
+// Public re-exports from external crate to minimize the required dependencies.
+pub use zksync_health_check::alerts::{Alert, AlertSeverity, AlertSink};
+
+use crate::resource::Resource;
+
+/// A resource that provides the [`AlertSink`] components push alerts to.
+/// If no sink is provided, alerts are discarded.
+#[derive(Debug, Clone)]
+pub struct AlertSinkResource(pub Arc<dyn AlertSink>);
+
+impl Default for AlertSinkResource {
+    fn default() -> Self {
+        Self(Arc::new(NoopAlertSink))
+    }
+}
+
+impl Resource for AlertSinkResource {
+    fn name() -> String {
+        "common/alert_sink".into()
+    }
+}
@@ -1,4 +1,5 @@
 pub mod action_queue;
+pub mod alerts;
 pub mod base_token_ratio_provider;
 pub mod circuit_breakers;
 pub mod da_client;
@@ -14,6 +15,7 @@ pub mod price_api_client;
 pub mod reverter;
 pub mod state_keeper;
 pub mod sync_state;
+pub mod via_btc_client;
 pub mod via_btc_indexer;
 pub mod via_state_keeper;
 pub mod web3_api;
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use via_btc_client::traits::BitcoinOps;
+
+use crate::Resource;
+
+/// A resource that provides a Bitcoin client for components that only read the chain state.
+#[derive(Debug, Clone)]
+pub struct BtcClientResource(pub Arc<dyn BitcoinOps>);
+
+impl Resource for BtcClientResource {
+    fn name() -> String {
+        "btc_client_resource".into()
+    }
+}
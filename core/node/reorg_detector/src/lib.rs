@@ -1,10 +1,13 @@
-use std::{fmt, future::Future, time::Duration};
+use std::{fmt, future::Future, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use async_trait::async_trait;
 use tokio::sync::watch;
 use zksync_dal::{ConnectionPool, Core, CoreDal, DalError};
-use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
+use zksync_health_check::{
+    alerts::{Alert, AlertSeverity, AlertSink, NoopAlertSink},
+    Health, HealthStatus, HealthUpdater, ReactiveHealthCheck,
+};
 use zksync_shared_metrics::{CheckerComponent, EN_METRICS};
 use zksync_types::{L1BatchNumber, L2BlockNumber, H256};
 use zksync_web3_decl::{
@@ -246,6 +249,7 @@ pub struct ReorgDetector {
     pool: ConnectionPool<Core>,
     sleep_interval: Duration,
     health_check: ReactiveHealthCheck,
+    alert_sink: Arc<dyn AlertSink>,
 }
 
 impl ReorgDetector {
@@ -259,9 +263,16 @@ impl ReorgDetector {
             pool,
             sleep_interval: Self::DEFAULT_SLEEP_INTERVAL,
             health_check,
+            alert_sink: Arc::new(NoopAlertSink),
         }
     }
 
+    /// Sets the sink receiving alerts on detected reorgs.
+    pub fn with_alert_sink(mut self, alert_sink: Arc<dyn AlertSink>) -> Self {
+        self.alert_sink = alert_sink;
+        self
+    }
+
     pub fn health_check(&self) -> &ReactiveHealthCheck {
         &self.health_check
     }
@@ -312,21 +323,49 @@ impl ReorgDetector {
             .await?
             .context("all L1 batches disappeared")?;
         drop(storage);
-        match self.root_hashes_match(first_l1_batch).await {
-            Ok(true) => {}
-            Ok(false) => return Err(Error::EarliestL1BatchMismatch(first_l1_batch)),
+        let err = match self.root_hashes_match(first_l1_batch).await {
+            Ok(true) => None,
+            Ok(false) => Some(Error::EarliestL1BatchMismatch(first_l1_batch)),
             Err(HashMatchError::MissingData(_)) => {
-                return Err(Error::EarliestL1BatchTruncated(first_l1_batch));
+                Some(Error::EarliestL1BatchTruncated(first_l1_batch))
             }
             Err(err) => return Err(err.into()),
+        };
+        if let Some(err) = err {
+            let alert = Alert::new("reorg_detector", AlertSeverity::Critical, err.to_string())
+                .with_details(serde_json::json!({
+                    "earliest_l1_batch": first_l1_batch,
+                    "diverged_l1_batch": diverged_l1_batch,
+                }));
+            self.alert_sink.send(alert).await;
+            return Err(err);
         }
 
         tracing::info!("Searching for the first diverged L1 batch");
         let last_correct_l1_batch = self.detect_reorg(first_l1_batch, diverged_l1_batch).await?;
         tracing::info!("Reorg localized: last correct L1 batch is #{last_correct_l1_batch}");
+        self.alert_sink
+            .send(Self::reorg_alert(local_l1_batch, last_correct_l1_batch))
+            .await;
         Err(Error::ReorgDetected(last_correct_l1_batch))
     }
 
+    fn reorg_alert(local_l1_batch: L1BatchNumber, last_correct_l1_batch: L1BatchNumber) -> Alert {
+        let reverted_l1_batches = local_l1_batch.0.saturating_sub(last_correct_l1_batch.0);
+        Alert::new(
+            "reorg_detector",
+            AlertSeverity::Critical,
+            format!(
+                "Reorg detected: {reverted_l1_batches} L1 batch(es) after #{last_correct_l1_batch} diverge from the main node"
+            ),
+        )
+        .with_details(serde_json::json!({
+            "last_correct_l1_batch": last_correct_l1_batch,
+            "local_l1_batch": local_l1_batch,
+            "reverted_l1_batches": reverted_l1_batches,
+        }))
+    }
+
     /// Compares hashes of the given local L2 block and the same L2 block from main node.
     async fn l2_block_hashes_match(&self, l2_block: L2BlockNumber) -> Result<bool, HashMatchError> {
         let mut storage = self.pool.connection().await?;
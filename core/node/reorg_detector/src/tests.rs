@@ -170,6 +170,17 @@ fn create_mock_detector(
         pool,
         sleep_interval: Duration::from_millis(10),
         health_check,
+        alert_sink: Arc::new(NoopAlertSink),
+    }
+}
+
+#[derive(Debug, Default)]
+struct RecordingAlertSink(Mutex<Vec<Alert>>);
+
+#[async_trait]
+impl AlertSink for RecordingAlertSink {
+    async fn send(&self, alert: Alert) {
+        self.0.lock().unwrap().push(alert);
     }
 }
 
@@ -320,6 +331,64 @@ async fn reorg_is_detected_on_batch_hash_mismatch() {
     );
 }
 
+#[tokio::test]
+async fn deep_reorg_is_reported_to_alert_sink() {
+    let pool = ConnectionPool::<Core>::test_pool().await;
+    let mut storage = pool.connection().await.unwrap();
+    let genesis_batch = insert_genesis_batch(&mut storage, &GenesisParams::mock())
+        .await
+        .unwrap();
+    let mut client = MockMainNodeClient::default();
+    client.l2_block_hashes.insert(
+        L2BlockNumber(0),
+        L2BlockHasher::legacy_hash(L2BlockNumber(0)),
+    );
+    client
+        .l1_batch_root_hashes
+        .insert(L1BatchNumber(0), Ok(genesis_batch.root_hash));
+
+    for number in 1..=5 {
+        let l2_block_hash = H256::from_low_u64_be(number.into());
+        let l1_batch_hash = H256::repeat_byte(number as u8);
+        client
+            .l2_block_hashes
+            .insert(L2BlockNumber(number), l2_block_hash);
+        client
+            .l1_batch_root_hashes
+            .insert(L1BatchNumber(number), Ok(l1_batch_hash));
+
+        // Only L1 batch #1 matches the main node; the next 4 batches diverge.
+        if number == 1 {
+            store_l2_block(&mut storage, number, l2_block_hash).await;
+            seal_l1_batch(&mut storage, number, l1_batch_hash).await;
+        } else {
+            store_l2_block(&mut storage, number, H256::zero()).await;
+            seal_l1_batch(&mut storage, number, H256::repeat_byte(0xff)).await;
+        }
+    }
+
+    let alert_sink = Arc::new(RecordingAlertSink::default());
+    let mut detector =
+        create_mock_detector(client, pool.clone()).with_alert_sink(alert_sink.clone());
+    assert_matches!(
+        detector.check_consistency().await,
+        Err(Error::ReorgDetected(L1BatchNumber(1)))
+    );
+
+    let alerts = alert_sink.0.lock().unwrap();
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].component, "reorg_detector");
+    assert_eq!(alerts[0].severity, AlertSeverity::Critical);
+    assert_eq!(
+        alerts[0].details,
+        serde_json::json!({
+            "last_correct_l1_batch": 1,
+            "local_l1_batch": 5,
+            "reverted_l1_batches": 4,
+        })
+    );
+}
+
 #[tokio::test]
 async fn reorg_is_detected_on_l2_block_hash_mismatch() {
     let pool = ConnectionPool::<Core>::test_pool().await;
@@ -1,6 +1,7 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use tokio::sync::watch;
 use via_btc_client::{
     inscriber::Inscriber,
@@ -9,8 +10,9 @@ use via_btc_client::{
 };
 use zksync_config::ViaBtcSenderConfig;
 use zksync_contracts::BaseSystemContractsHashes;
+use zksync_da_client::DataAvailabilityClient;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
-use zksync_types::{ProtocolVersionId, H256};
+use zksync_types::{btc_block::ViaBtcL1BlockDetails, ProtocolVersionId, H256};
 
 use crate::aggregator::ViaAggregator;
 
@@ -20,6 +22,7 @@ pub struct ViaBtcInscriptionAggregator {
     aggregator: ViaAggregator,
     pool: ConnectionPool<Core>,
     config: ViaBtcSenderConfig,
+    da_client: Option<Box<dyn DataAvailabilityClient>>,
 }
 
 impl ViaBtcInscriptionAggregator {
@@ -35,9 +38,17 @@ impl ViaBtcInscriptionAggregator {
             aggregator,
             pool,
             config,
+            da_client: None,
         })
     }
 
+    /// Sets the DA client used to check that the blob referenced by an inscription is available
+    /// on the DA layer before the inscription request is created.
+    pub fn with_da_client(mut self, da_client: Box<dyn DataAvailabilityClient>) -> Self {
+        self.da_client = Some(da_client);
+        self
+    }
+
     pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
         let mut timer = tokio::time::interval(self.config.poll_interval());
         let pool = self.pool.clone();
@@ -70,6 +81,10 @@ impl ViaBtcInscriptionAggregator {
         &mut self,
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), anyhow::Error> {
+        if let Some(timeout) = self.config.da_inclusion_alert_timeout() {
+            self.check_da_inclusion_delay(storage, timeout).await?;
+        }
+
         let base_system_contracts_hashes = BaseSystemContractsHashes {
             bootloader: self.get_bootloader_code_hash().await?,
             default_aa: self.get_aa_code_hash().await?,
@@ -86,6 +101,8 @@ impl ViaBtcInscriptionAggregator {
             let mut transaction = storage.start_transaction().await?;
 
             for batch in operation.get_l1_batches_detail() {
+                self.ensure_blob_is_available(batch).await?;
+
                 let inscription_message = self.aggregator.construct_inscription_message(
                     &operation.get_inscription_request_type(),
                     batch,
@@ -126,6 +143,55 @@ impl ViaBtcInscriptionAggregator {
         Ok(())
     }
 
+    /// Reports the first L1 batch whose DA blob has been awaiting inclusion for longer than `timeout`.
+    /// Batches aren't inscribed until their blob is included, so such a batch holds back all later ones.
+    async fn check_da_inclusion_delay(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        let Some(blob) = storage
+            .via_data_availability_dal()
+            .get_first_da_blob_awaiting_inclusion()
+            .await?
+        else {
+            return Ok(());
+        };
+        if let Some(delay) = overdue_da_inclusion(blob.sent_at, Utc::now(), timeout) {
+            tracing::error!(
+                "DA blob `{}` for L1 batch #{} has been awaiting inclusion for {delay:?}, \
+                 holding back inscriptions of this and later batches",
+                blob.blob_id,
+                blob.l1_batch_number
+            );
+        }
+        Ok(())
+    }
+
+    /// Re-fetches the inclusion data of the blob referenced by the batch, so that we never inscribe
+    /// a reference to a blob the DA layer doesn't serve.
+    async fn ensure_blob_is_available(&self, batch: &ViaBtcL1BlockDetails) -> anyhow::Result<()> {
+        let Some(da_client) = &self.da_client else {
+            return Ok(());
+        };
+        let inclusion_data = da_client
+            .get_inclusion_data(&batch.blob_id)
+            .await
+            .with_context(|| {
+                format!(
+                    "failed fetching DA blob `{}` for L1 batch #{}",
+                    batch.blob_id, batch.number
+                )
+            })?;
+        anyhow::ensure!(
+            inclusion_data.is_some(),
+            "DA blob `{}` for L1 batch #{} is not available on the DA layer",
+            batch.blob_id,
+            batch.number
+        );
+        Ok(())
+    }
+
     // Todo: call indexer to fetch  the data
     async fn get_bootloader_code_hash(&self) -> anyhow::Result<H256> {
         let hex_str = "010008e742608b21bf7eb23c1a9d0602047e3618b464c9b59c0fba3b3d7ab66e";
@@ -143,3 +209,13 @@ impl ViaBtcInscriptionAggregator {
         Ok(ProtocolVersionId::latest())
     }
 }
+
+/// Returns how long a blob sent at `sent_at` has been awaiting inclusion, if that exceeds `timeout`.
+pub(crate) fn overdue_da_inclusion(
+    sent_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    timeout: Duration,
+) -> Option<Duration> {
+    let delay = (now - sent_at).to_std().ok()?;
+    (delay > timeout).then_some(delay)
+}
@@ -1,22 +1,44 @@
 use anyhow::{Context, Result};
-use bincode::serialize;
+use bincode::{deserialize, serialize};
+use bitcoin::{Amount, Transaction, Txid};
 use tokio::sync::watch;
 use via_btc_client::{
     inscriber::Inscriber,
-    traits::Serializable,
-    types::{InscriptionConfig, InscriptionMessage},
+    traits::{BitcoinOps, Serializable},
+    types::{InscribeError, InscriptionConfig, InscriptionMessage},
 };
 use zksync_config::ViaBtcSenderConfig;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
-use zksync_types::btc_sender::ViaBtcInscriptionRequest;
+use zksync_types::{
+    btc_inscription_operations::ViaBtcInscriptionRequestType,
+    btc_sender::{ViaBtcInscriptionRequest, ViaBtcInscriptionRequestHistory},
+};
 
-use crate::config::BLOCK_RESEND;
+use crate::{
+    config::BLOCK_RESEND,
+    metrics::{ViaBtcSenderMetrics, METRICS},
+};
+
+/// Fees of an inscription send attempt as recorded by the manager and as observed on chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InscriptionFeeAudit {
+    pub recorded_fee: Amount,
+    pub on_chain_fee: Amount,
+}
+
+impl InscriptionFeeAudit {
+    pub fn is_consistent(&self) -> bool {
+        self.recorded_fee == self.on_chain_fee
+    }
+}
 
 #[derive(Debug)]
 pub struct ViaBtcInscriptionManager {
     inscriber: Inscriber,
     config: ViaBtcSenderConfig,
     pool: ConnectionPool<Core>,
+    /// `None` if metrics are disabled in the config.
+    metrics: Option<&'static ViaBtcSenderMetrics>,
 }
 
 impl ViaBtcInscriptionManager {
@@ -25,10 +47,12 @@ impl ViaBtcInscriptionManager {
         pool: ConnectionPool<Core>,
         config: ViaBtcSenderConfig,
     ) -> anyhow::Result<Self> {
+        let metrics = config.metrics_enabled().then_some(&*METRICS);
         Ok(Self {
             inscriber,
             config,
             pool,
+            metrics,
         })
     }
 
@@ -48,9 +72,19 @@ impl ViaBtcInscriptionManager {
                 Ok(()) => {
                     tracing::info!("Inscription manager task finished");
                 }
-                Err(err) => {
-                    tracing::error!("Failed to process btc_sender_inscription_manager: {err}");
-                }
+                Err(err) => match err.downcast_ref::<InscribeError>() {
+                    Some(InscribeError::InsufficientFunds { .. }) => {
+                        tracing::warn!("Inscription manager is waiting for funds: {err:#}");
+                    }
+                    Some(inscribe_error) if inscribe_error.is_retriable() => {
+                        tracing::warn!(
+                            "Inscription manager hit a transient error, will retry: {err:#}"
+                        );
+                    }
+                    _ => {
+                        tracing::error!("Failed to process btc_sender_inscription_manager: {err}");
+                    }
+                },
             }
         }
 
@@ -62,6 +96,9 @@ impl ViaBtcInscriptionManager {
         &mut self,
         storage: &mut Connection<'_, Core>,
     ) -> Result<(), anyhow::Error> {
+        if self.config.rebroadcast_evicted_inscriptions() {
+            self.rebroadcast_evicted_inscriptions(storage).await?;
+        }
         self.update_inscription_status_or_resend(storage).await?;
         self.send_new_inscription_txs(storage).await?;
         Ok(())
@@ -98,6 +135,10 @@ impl ViaBtcInscriptionManager {
                         "Inscription confirmed {reveal_tx}",
                         reveal_tx = last_inscription_history.reveal_tx_id,
                     );
+                    if inscription.request_type == ViaBtcInscriptionRequestType::CommitProofOnchain
+                    {
+                        self.report_batch_lifecycle(storage, inscription.id).await?;
+                    }
                 } else {
                     let current_block = self
                         .inscriber
@@ -136,7 +177,147 @@ impl ViaBtcInscriptionManager {
         Ok(())
     }
 
-    async fn send_new_inscription_txs(
+    /// Reports how long the L1 batch proven by the confirmed proof inscription took to get through
+    /// its Bitcoin stages.
+    async fn report_batch_lifecycle(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        proof_inscription_id: i64,
+    ) -> anyhow::Result<()> {
+        let Some(metrics) = self.metrics else {
+            return Ok(());
+        };
+        let Some(batch_number) = storage
+            .via_blocks_dal()
+            .get_l1_batch_number_by_proof_inscription_id(proof_inscription_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        let Some(lifecycle) = storage
+            .via_blocks_dal()
+            .get_batch_lifecycle_timings(batch_number)
+            .await?
+        else {
+            return Ok(());
+        };
+        metrics.observe_batch_lifecycle(&lifecycle);
+        Ok(())
+    }
+
+    /// Re-broadcasts the last sent commit / reveal transactions of inflight inscriptions
+    /// that are neither in the mempool nor confirmed, e.g. because they were evicted due to a low fee.
+    pub(crate) async fn rebroadcast_evicted_inscriptions(
+        &mut self,
+        storage: &mut Connection<'_, Core>,
+    ) -> anyhow::Result<()> {
+        let inflight_inscriptions = storage.btc_sender_dal().get_inflight_inscriptions().await?;
+
+        for inscription in inflight_inscriptions {
+            let Some(history) = storage
+                .btc_sender_dal()
+                .get_last_inscription_request_history(inscription.id)
+                .await?
+            else {
+                continue;
+            };
+            if !self.is_evicted(&history.reveal_tx_id).await? {
+                continue;
+            }
+
+            tracing::info!(
+                "Inscription {reveal_tx} was evicted from the mempool, re-broadcasting it",
+                reveal_tx = history.reveal_tx_id
+            );
+            if let Err(err) = self.rebroadcast(&history).await {
+                tracing::warn!(
+                    "Failed to re-broadcast inscription {reveal_tx}: {err:#}",
+                    reveal_tx = history.reveal_tx_id
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn is_evicted(&self, txid: &Txid) -> anyhow::Result<bool> {
+        let client = self.inscriber.get_client().await;
+        // Both mempool and mined transactions are known to the node, while an evicted one is unknown.
+        // Other lookup errors are propagated, so that a failing node doesn't trigger re-broadcasts.
+        let confirmations = client
+            .fetch_tx_confirmations(txid)
+            .await
+            .with_context(|| format!("Fetch confirmations of tx {txid}"))?;
+        Ok(confirmations.is_none())
+    }
+
+    async fn rebroadcast(&self, history: &ViaBtcInscriptionRequestHistory) -> anyhow::Result<()> {
+        let (Some(signed_commit_tx), Some(signed_reveal_tx)) =
+            (&history.signed_commit_tx, &history.signed_reveal_tx)
+        else {
+            anyhow::bail!("signed transactions are missing from the inscription history");
+        };
+        let commit_tx: Transaction =
+            deserialize(signed_commit_tx).context("Deserialize the commit tx")?;
+        let reveal_tx: Transaction =
+            deserialize(signed_reveal_tx).context("Deserialize the reveal tx")?;
+
+        let client = self.inscriber.get_client().await;
+        // The commit tx may already be mined with all its outputs spent, in which case the node would reject it.
+        if self.is_evicted(&history.commit_tx_id).await? {
+            client
+                .send_raw_transaction(&commit_tx)
+                .await
+                .context("Re-broadcast the commit tx")?;
+        }
+        client
+            .send_raw_transaction(&reveal_tx)
+            .await
+            .context("Re-broadcast the reveal tx")?;
+        Ok(())
+    }
+
+    /// Compares the fee recorded for the last send attempt of the inscription request with the fees
+    /// of its commit and reveal transactions computed from the Bitcoin node. Returns `None` if the
+    /// request was never sent.
+    pub async fn audit_inscription_fees(
+        &self,
+        storage: &mut Connection<'_, Core>,
+        inscription_request_id: i64,
+    ) -> anyhow::Result<Option<InscriptionFeeAudit>> {
+        let Some(history) = storage
+            .btc_sender_dal()
+            .get_last_inscription_request_history(inscription_request_id)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let client = self.inscriber.get_client().await;
+        let mut on_chain_fee = Amount::ZERO;
+        for txid in [history.commit_tx_id, history.reveal_tx_id] {
+            let tx = client
+                .get_transaction(&txid)
+                .await
+                .with_context(|| format!("Fetch inscription tx {txid}"))?;
+            on_chain_fee += transaction_fee(client, &tx).await?;
+        }
+
+        let audit = InscriptionFeeAudit {
+            recorded_fee: Amount::from_sat(history.actual_fees as u64),
+            on_chain_fee,
+        };
+        if !audit.is_consistent() {
+            tracing::warn!(
+                "Inscription {reveal_tx} has recorded fee {recorded}, but paid {on_chain} on chain",
+                reveal_tx = history.reveal_tx_id,
+                recorded = audit.recorded_fee,
+                on_chain = audit.on_chain_fee,
+            );
+        }
+        Ok(Some(audit))
+    }
+
+    pub(crate) async fn send_new_inscription_txs(
         &mut self,
         storage: &mut Connection<'_, Core>,
     ) -> anyhow::Result<()> {
@@ -161,13 +342,33 @@ impl ViaBtcInscriptionManager {
             count = number_of_available_slots_for_inscription_txs
         );
 
-        if number_of_available_slots_for_inscription_txs > 0 {
-            let list_new_inscription_request = storage
-                .btc_sender_dal()
-                .list_new_inscription_request(number_of_available_slots_for_inscription_txs)
+        if number_of_available_slots_for_inscription_txs <= 0 {
+            return Ok(());
+        }
+
+        // Proof inscriptions are sent first, so that a backlog of batch commitments
+        // doesn't delay proofs for batches which are already committed.
+        let proof_requests = storage
+            .btc_sender_dal()
+            .list_new_inscription_request(
+                number_of_available_slots_for_inscription_txs,
+                Some(ViaBtcInscriptionRequestType::CommitProofOnchain),
+            )
+            .await?;
+        let remaining_slots =
+            number_of_available_slots_for_inscription_txs - proof_requests.len() as i64;
+        for inscription in proof_requests {
+            self.send_inscription_tx(storage, &inscription, InscriptionConfig::default())
                 .await?;
+        }
 
-            for inscription in list_new_inscription_request {
+        if remaining_slots > 0 {
+            // Sent proof requests have history now, so only other request types are listed.
+            let other_requests = storage
+                .btc_sender_dal()
+                .list_new_inscription_request(remaining_slots, None)
+                .await?;
+            for inscription in other_requests {
                 self.send_inscription_tx(storage, &inscription, InscriptionConfig::default())
                     .await?;
             }
@@ -198,10 +399,12 @@ impl ViaBtcInscriptionManager {
             .await
             .context("Sent inscription tx")?;
 
-        let signed_commit_tx =
-            serialize(&inscribe_info.final_commit_tx.tx).context("Serilize the commit tx")?;
-        let signed_reveal_tx =
-            serialize(&inscribe_info.final_reveal_tx.tx).context("Serilize the reveal tx")?;
+        let signed_commit_tx = serialize(&inscribe_info.final_commit_tx.tx)
+            .map_err(|err| InscribeError::Serialization(err.to_string()))
+            .context("Serilize the commit tx")?;
+        let signed_reveal_tx = serialize(&inscribe_info.final_reveal_tx.tx)
+            .map_err(|err| InscribeError::Serialization(err.to_string()))
+            .context("Serilize the reveal tx")?;
 
         let actual_fees = inscribe_info.reveal_tx_output_info._reveal_fee
             + inscribe_info.commit_tx_output_info._commit_tx_fee;
@@ -227,3 +430,24 @@ impl ViaBtcInscriptionManager {
         Ok(())
     }
 }
+
+/// Computes the fee paid by a transaction as the value of its inputs minus the value of its outputs.
+async fn transaction_fee(client: &dyn BitcoinOps, tx: &Transaction) -> anyhow::Result<Amount> {
+    let mut input_value = Amount::ZERO;
+    for input in &tx.input {
+        let prev_out = input.previous_output;
+        let prev_tx = client
+            .get_transaction(&prev_out.txid)
+            .await
+            .with_context(|| format!("Fetch the tx spent by {}", tx.compute_txid()))?;
+        let spent_output = prev_tx
+            .output
+            .get(prev_out.vout as usize)
+            .with_context(|| format!("Output {prev_out} does not exist"))?;
+        input_value += spent_output.value;
+    }
+    let output_value = tx.output.iter().map(|output| output.value).sum::<Amount>();
+    input_value
+        .checked_sub(output_value)
+        .with_context(|| format!("Tx {} spends more than its inputs", tx.compute_txid()))
+}
@@ -3,6 +3,7 @@ pub mod aggregator;
 pub mod btc_inscription_aggregator;
 pub mod btc_inscription_manager;
 mod config;
+mod metrics;
 mod publish_criterion;
 #[cfg(test)]
 mod tests;
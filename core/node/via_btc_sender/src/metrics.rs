@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use vise::{Buckets, Histogram, Metrics, Unit};
+use zksync_dal::via_blocks_dal::ViaBatchLifecycle;
+
+/// Buckets for batch stages that take from minutes to hours, since they wait for Bitcoin blocks.
+const BATCH_STAGE_BUCKETS: Buckets = Buckets::exponential(60.0..=61_440.0, 2.0);
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "via_server_btc_sender")]
+pub struct ViaBtcSenderMetrics {
+    /// Time between sealing an L1 batch and the confirmation of its commit inscription.
+    #[metrics(buckets = BATCH_STAGE_BUCKETS, unit = Unit::Seconds)]
+    pub time_to_commit: Histogram<Duration>,
+
+    /// Time between the confirmation of the commit and the proof inscriptions of an L1 batch.
+    #[metrics(buckets = BATCH_STAGE_BUCKETS, unit = Unit::Seconds)]
+    pub time_to_prove: Histogram<Duration>,
+}
+
+impl ViaBtcSenderMetrics {
+    pub fn observe_batch_lifecycle(&self, lifecycle: &ViaBatchLifecycle) {
+        // Negative durations can only be caused by clock adjustments, so they are skipped.
+        if let Some(latency) = lifecycle.time_to_commit().and_then(|d| d.to_std().ok()) {
+            self.time_to_commit.observe(latency);
+        }
+        if let Some(latency) = lifecycle.time_to_prove().and_then(|d| d.to_std().ok()) {
+            self.time_to_prove.observe(latency);
+        }
+    }
+}
+
+#[vise::register]
+pub static METRICS: vise::Global<ViaBtcSenderMetrics> = vise::Global::new();
@@ -62,6 +62,34 @@ mod tests {
         );
     }
 
+    // A batch whose DA blob isn't included yet must be held back until the inclusion is recorded.
+    #[tokio::test]
+    async fn test_get_next_ready_operation_waits_for_da_inclusion() {
+        let header = create_l1_batch(1);
+        let mut aggregator_test = ViaAggregatorTest::new(
+            header.protocol_version.unwrap(),
+            header.base_system_contracts_hashes,
+            ConnectionPool::<Core>::test_pool().await,
+            None,
+        )
+        .await;
+
+        aggregator_test
+            .insert_l1_batch_awaiting_da_inclusion(
+                header.clone(),
+                l1_batch_metadata_to_commitment_artifacts(&default_l1_batch_metadata()),
+            )
+            .await;
+        assert!(aggregator_test.get_next_ready_operation().await.is_none());
+
+        aggregator_test
+            .save_l1_batch_da_inclusion(header.number)
+            .await;
+        let op = aggregator_test.get_next_ready_operation().await.unwrap();
+        assert_eq!(op.get_l1_batches_detail().len(), 1);
+        assert_eq!(op.get_l1_batches_detail()[0].number, header.number);
+    }
+
     // Get the current operation (commitBatch or commitProof) to execute, when there are many batch ready to be 'commitBatch'.
     #[tokio::test]
     async fn test_get_next_ready_operation_when_many_batches() {
@@ -12,9 +12,12 @@ mod tests {
         btc_sender::ViaBtcInscriptionRequest, ProtocolVersionId, H256,
     };
 
-    use crate::tests::utils::{
-        default_l1_batch_metadata, get_btc_sender_config, get_inscription_aggregator_mock,
-        ViaAggregatorTest,
+    use crate::{
+        btc_inscription_aggregator::overdue_da_inclusion,
+        tests::utils::{
+            default_l1_batch_metadata, get_btc_sender_config, get_inscription_aggregator_mock,
+            MockDAClient, ViaAggregatorTest,
+        },
     };
 
     #[tokio::test]
@@ -222,6 +225,63 @@ mod tests {
         assert_eq!(op.get_l1_batches_detail()[0].number.0, 4);
     }
 
+    #[tokio::test]
+    async fn test_btc_inscription_aggregator_checks_blob_availability() {
+        for blobs_available in [false, true] {
+            let pool = ConnectionPool::<Core>::test_pool().await;
+            let config = get_btc_sender_config(1, 1);
+            let header = via_create_l1_batch(1);
+
+            let mut aggregator_test = ViaAggregatorTest::new(
+                header.protocol_version.unwrap(),
+                header.base_system_contracts_hashes,
+                pool.clone(),
+                Some(config.clone()),
+            )
+            .await;
+            aggregator_test
+                .insert_l1_batch(
+                    header,
+                    l1_batch_metadata_to_commitment_artifacts(&default_l1_batch_metadata()),
+                )
+                .await;
+
+            let (stop_sender, stop_receiver) = watch::channel(false);
+            let inscription_aggregator = get_inscription_aggregator_mock(pool.clone(), config)
+                .await
+                .with_da_client(Box::new(MockDAClient { blobs_available }));
+            let aggregator_task = tokio::spawn(inscription_aggregator.run(stop_receiver));
+            time::sleep(time::Duration::from_secs(1)).await;
+            stop_sender.send_replace(true);
+            aggregator_task.await.unwrap().unwrap();
+
+            // A batch whose blob is missing on the DA layer must not be inscribed.
+            let inscription_request_list =
+                list_new_inscription_request(&mut aggregator_test, 10).await;
+            let expected_len = if blobs_available { 1 } else { 0 };
+            assert_eq!(inscription_request_list.len(), expected_len);
+        }
+    }
+
+    #[test]
+    fn test_da_inclusion_is_overdue_after_timeout() {
+        let sent_at = chrono::Utc::now();
+        let timeout = time::Duration::from_secs(600);
+
+        let now = sent_at + chrono::Duration::seconds(60);
+        assert_eq!(overdue_da_inclusion(sent_at, now, timeout), None);
+
+        let now = sent_at + chrono::Duration::seconds(601);
+        assert_eq!(
+            overdue_da_inclusion(sent_at, now, timeout),
+            Some(time::Duration::from_secs(601))
+        );
+
+        // Clock adjustments may put `sent_at` into the future.
+        let now = sent_at - chrono::Duration::seconds(60);
+        assert_eq!(overdue_da_inclusion(sent_at, now, timeout), None);
+    }
+
     pub fn via_create_l1_batch(number: u32) -> L1BatchHeader {
         let hex_str = "0000000000000000000000000000000000000000000000000000000000000000";
         let mut header = create_l1_batch(number);
@@ -271,7 +331,7 @@ mod tests {
         aggregator_test
             .storage
             .btc_sender_dal()
-            .list_new_inscription_request(limit)
+            .list_new_inscription_request(limit, None)
             .await
             .unwrap()
     }
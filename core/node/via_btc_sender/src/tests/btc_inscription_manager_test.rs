@@ -2,8 +2,16 @@
 mod tests {
     use std::str::FromStr;
 
+    use bitcoin::{
+        absolute::LockTime, hashes::Hash, transaction::Version, Amount, OutPoint, ScriptBuf,
+        Transaction, TxIn, TxOut, Txid,
+    };
     use tokio::{sync::watch, time};
-    use via_btc_client::inscriber::test_utils::MockBitcoinOpsConfig;
+    use via_btc_client::{
+        inscriber::test_utils::MockBitcoinOpsConfig,
+        traits::Serializable,
+        types::{InscriptionMessage, ValidatorAttestationInput, Vote},
+    };
     use zksync_config::ViaBtcSenderConfig;
     use zksync_contracts::BaseSystemContractsHashes;
     use zksync_dal::{ConnectionPool, Core, CoreDal};
@@ -13,9 +21,12 @@ mod tests {
         ProtocolVersionId, H256,
     };
 
-    use crate::tests::utils::{
-        default_l1_batch_metadata, get_btc_sender_config, get_inscription_aggregator_mock,
-        get_inscription_manager_mock, ViaAggregatorTest,
+    use crate::{
+        btc_inscription_manager::InscriptionFeeAudit,
+        tests::utils::{
+            default_l1_batch_metadata, get_btc_sender_config, get_inscription_aggregator_mock,
+            get_inscription_manager_mock, ViaAggregatorTest,
+        },
     };
 
     #[tokio::test]
@@ -250,6 +261,229 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_btc_inscription_manager_rebroadcasts_evicted_inscription() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut config = get_btc_sender_config(1, 1);
+        config.rebroadcast_evicted_inscriptions = true;
+
+        let commit_tx = test_transaction(1);
+        let reveal_tx = test_transaction(2);
+        let mut storage = pool.connection().await.unwrap();
+        let request = storage
+            .btc_sender_dal()
+            .via_save_btc_inscriptions_request(
+                ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+                vec![],
+                0,
+            )
+            .await
+            .unwrap();
+        storage
+            .btc_sender_dal()
+            .insert_inscription_request_history(
+                commit_tx.compute_txid(),
+                reveal_tx.compute_txid(),
+                request.id,
+                bincode::serialize(&commit_tx).unwrap(),
+                bincode::serialize(&reveal_tx).unwrap(),
+                0,
+                1,
+            )
+            .await
+            .unwrap();
+
+        // Transactions are still in the mempool, so nothing is re-broadcast.
+        let mut mock_btc_ops_config = MockBitcoinOpsConfig::default();
+        mock_btc_ops_config.set_tx_in_mempool(true);
+        let mut manager =
+            get_inscription_manager_mock(pool.clone(), config.clone(), mock_btc_ops_config.clone())
+                .await;
+        manager
+            .rebroadcast_evicted_inscriptions(&mut storage)
+            .await
+            .unwrap();
+        assert!(mock_btc_ops_config.broadcast_txids().is_empty());
+
+        // Transactions were evicted from the mempool.
+        let mut mock_btc_ops_config = MockBitcoinOpsConfig::default();
+        mock_btc_ops_config.set_tx_in_mempool(false);
+        mock_btc_ops_config.set_tx_confirmation(false);
+        let mut manager =
+            get_inscription_manager_mock(pool.clone(), config, mock_btc_ops_config.clone()).await;
+        manager
+            .rebroadcast_evicted_inscriptions(&mut storage)
+            .await
+            .unwrap();
+        assert_eq!(
+            mock_btc_ops_config.broadcast_txids(),
+            [commit_tx.compute_txid(), reveal_tx.compute_txid()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_btc_inscription_manager_sends_proof_requests_first() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        // Only one inscription can be in flight.
+        let config = get_btc_sender_config(1, 1);
+        let mut mock_btc_ops_config = MockBitcoinOpsConfig::default();
+        mock_btc_ops_config.set_block_height(1);
+
+        let inscription_message =
+            InscriptionMessage::ValidatorAttestation(ValidatorAttestationInput {
+                reference_txid: Txid::all_zeros(),
+                attestation: Vote::Ok,
+            })
+            .to_bytes();
+        let mut storage = pool.connection().await.unwrap();
+        let commit_request = storage
+            .btc_sender_dal()
+            .via_save_btc_inscriptions_request(
+                ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+                inscription_message.clone(),
+                0,
+            )
+            .await
+            .unwrap();
+        let proof_request = storage
+            .btc_sender_dal()
+            .via_save_btc_inscriptions_request(
+                ViaBtcInscriptionRequestType::CommitProofOnchain,
+                inscription_message,
+                0,
+            )
+            .await
+            .unwrap();
+
+        let mut manager =
+            get_inscription_manager_mock(pool.clone(), config, mock_btc_ops_config).await;
+        manager
+            .send_new_inscription_txs(&mut storage)
+            .await
+            .unwrap();
+
+        // The proof request is sent although the commit request is older.
+        let proof_history = storage
+            .btc_sender_dal()
+            .get_last_inscription_request_history(proof_request.id)
+            .await
+            .unwrap();
+        assert!(proof_history.is_some());
+        let commit_history = storage
+            .btc_sender_dal()
+            .get_last_inscription_request_history(commit_request.id)
+            .await
+            .unwrap();
+        assert!(commit_history.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_btc_inscription_manager_audits_inscription_fees() {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let config = get_btc_sender_config(1, 1);
+
+        // Both inscriptions record a fee of 1_500 sat, but only the first one paid it: its commit tx
+        // pays 1_000 sat and its reveal tx pays 500 sat, while the second commit tx pays only 900 sat.
+        let funding_tx = test_transaction_spending(None, 100_000);
+        let mut mock_btc_ops_config = MockBitcoinOpsConfig::default();
+        mock_btc_ops_config.add_transaction(funding_tx.clone());
+
+        let mut storage = pool.connection().await.unwrap();
+        let mut request_ids = vec![];
+        for commit_output in [99_000, 99_100] {
+            let commit_tx = test_transaction_spending(Some(&funding_tx), commit_output);
+            let reveal_tx = test_transaction_spending(Some(&commit_tx), commit_output - 500);
+            let request = storage
+                .btc_sender_dal()
+                .via_save_btc_inscriptions_request(
+                    ViaBtcInscriptionRequestType::CommitL1BatchOnchain,
+                    vec![],
+                    0,
+                )
+                .await
+                .unwrap();
+            storage
+                .btc_sender_dal()
+                .insert_inscription_request_history(
+                    commit_tx.compute_txid(),
+                    reveal_tx.compute_txid(),
+                    request.id,
+                    vec![],
+                    vec![],
+                    1_500,
+                    1,
+                )
+                .await
+                .unwrap();
+            request_ids.push(request.id);
+            mock_btc_ops_config.add_transaction(commit_tx);
+            mock_btc_ops_config.add_transaction(reveal_tx);
+        }
+        let unsent_request = storage
+            .btc_sender_dal()
+            .via_save_btc_inscriptions_request(
+                ViaBtcInscriptionRequestType::CommitProofOnchain,
+                vec![],
+                0,
+            )
+            .await
+            .unwrap();
+
+        let manager = get_inscription_manager_mock(pool.clone(), config, mock_btc_ops_config).await;
+        let audit = manager
+            .audit_inscription_fees(&mut storage, request_ids[0])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            audit,
+            InscriptionFeeAudit {
+                recorded_fee: Amount::from_sat(1_500),
+                on_chain_fee: Amount::from_sat(1_500),
+            }
+        );
+        assert!(audit.is_consistent());
+
+        let audit = manager
+            .audit_inscription_fees(&mut storage, request_ids[1])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(audit.on_chain_fee, Amount::from_sat(1_400));
+        assert!(!audit.is_consistent());
+
+        let audit = manager
+            .audit_inscription_fees(&mut storage, unsent_request.id)
+            .await
+            .unwrap();
+        assert_eq!(audit, None);
+    }
+
+    fn test_transaction_spending(prev_tx: Option<&Transaction>, value: u64) -> Transaction {
+        let input = prev_tx.map(|prev_tx| TxIn {
+            previous_output: OutPoint::new(prev_tx.compute_txid(), 0),
+            ..TxIn::default()
+        });
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: input.into_iter().collect(),
+            output: vec![TxOut {
+                value: Amount::from_sat(value),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        }
+    }
+
+    fn test_transaction(lock_time: u32) -> Transaction {
+        Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::from_consensus(lock_time),
+            input: vec![],
+            output: vec![],
+        }
+    }
+
     async fn run_aggregator(pool: ConnectionPool<Core>, config: ViaBtcSenderConfig) {
         {
             // Create an async channel to break the while loop afer 3 seconds.
@@ -11,8 +11,15 @@ use via_btc_client::{
     traits::Serializable,
     types::InscriptionMessage,
 };
-use zksync_config::{configs::via_btc_sender::ProofSendingMode, ViaBtcSenderConfig};
+use zksync_config::{
+    configs::via_btc_sender::{ChangeOutputType, ProofSendingMode},
+    ViaBtcSenderConfig,
+};
 use zksync_contracts::BaseSystemContractsHashes;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
 use zksync_types::{
     block::{L1BatchHeader, L1BatchTreeData},
@@ -91,6 +98,11 @@ pub fn get_btc_sender_config(
         max_txs_in_flight: 1,
         proof_sending_mode: ProofSendingMode::SkipEveryProof,
         block_confirmations: 0,
+        change_output_type: ChangeOutputType::P2WPKH,
+        min_fee_rate: None,
+        rebroadcast_evicted_inscriptions: false,
+        da_inclusion_alert_timeout_secs: None,
+        metrics_enabled: true,
     }
 }
 
@@ -102,6 +114,39 @@ pub async fn get_inscription_aggregator_mock(
     Result::unwrap(ViaBtcInscriptionAggregator::new(inscriber, pool, config).await)
 }
 
+/// DA client that either serves inclusion data for every blob, or for none of them.
+#[derive(Debug, Clone)]
+pub struct MockDAClient {
+    pub blobs_available: bool,
+}
+
+#[async_trait::async_trait]
+impl DataAvailabilityClient for MockDAClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        _data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        Ok(DispatchResponse {
+            blob_id: format!("blob_{batch_number}"),
+        })
+    }
+
+    async fn get_inclusion_data(&self, _blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        Ok(self
+            .blobs_available
+            .then(|| InclusionData { data: vec![1; 32] }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        None
+    }
+}
+
 pub async fn get_inscription_manager_mock(
     pool: ConnectionPool<Core>,
     config: ViaBtcSenderConfig,
@@ -173,6 +218,18 @@ impl ViaAggregatorTest {
         &mut self,
         header: L1BatchHeader,
         l1_commitment_artifacts: L1BatchCommitmentArtifacts,
+    ) {
+        let number = header.number;
+        self.insert_l1_batch_awaiting_da_inclusion(header, l1_commitment_artifacts)
+            .await;
+        self.save_l1_batch_da_inclusion(number).await;
+    }
+
+    /// Inserts an L1 batch whose DA blob was dispatched, but isn't included yet.
+    pub async fn insert_l1_batch_awaiting_da_inclusion(
+        &mut self,
+        header: L1BatchHeader,
+        l1_commitment_artifacts: L1BatchCommitmentArtifacts,
     ) {
         self.storage
             .blocks_dal()
@@ -205,12 +262,14 @@ impl ViaAggregatorTest {
             .insert_l1_batch_da(header.number, "blob_id", time)
             .await
             .expect("insert_l1_batch_da");
+    }
 
+    pub async fn save_l1_batch_da_inclusion(&mut self, number: L1BatchNumber) {
         let random_slice: &[u8] = &generate_random_bytes(32);
 
         self.storage
             .via_data_availability_dal()
-            .save_l1_batch_inclusion_data(header.number, random_slice)
+            .save_l1_batch_inclusion_data(number, random_slice)
             .await
             .expect("save_l1_batch_inclusion_data");
     }
@@ -1,24 +1,43 @@
 mod message_processors;
 mod metrics;
 
-use std::time::Duration;
+use std::{
+    fmt, future,
+    time::{Duration, SystemTime},
+};
 
 use anyhow::Context as _;
-use tokio::sync::watch;
+use futures::{stream::BoxStream, StreamExt};
+use rand::Rng;
+use serde::Serialize;
+use tokio::{sync::watch, time::Instant};
 // re-export via_btc_client types
 pub use via_btc_client::types::BitcoinNetwork;
 use via_btc_client::{
-    indexer::BitcoinInscriptionIndexer,
-    types::{BitcoinAddress, BitcoinTxid, NodeAuth},
+    indexer::{BitcoinInscriptionIndexer, BlockArchive},
+    types::{BitcoinAddress, BitcoinBlockHash, BitcoinTxid, FullInscriptionMessage, NodeAuth},
 };
+use zksync_config::ViaBtcClientConfig;
 use zksync_dal::{Connection, ConnectionPool, Core, CoreDal};
+use zksync_health_check::{Health, HealthStatus, HealthUpdater, ReactiveHealthCheck};
 use zksync_types::PriorityOpId;
 
+pub use self::message_processors::MessageProcessorInfo;
 use self::{
     message_processors::{L1ToL2MessageProcessor, MessageProcessor, MessageProcessorError},
-    metrics::METRICS,
+    metrics::{ErrorType, MetricsReporter, ThroughputTracker},
 };
-use crate::metrics::ErrorType;
+
+/// Window over which the block processing throughput is averaged.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(300);
+/// Interval between checks of the local clock against the Bitcoin chain.
+const CLOCK_SKEW_CHECK_INTERVAL: Duration = Duration::from_secs(600);
+/// Allowance for the Bitcoin chain tip timestamp trailing the wall clock. The tip is as old as the time
+/// since the last block, which is 10 minutes on average and rarely exceeds an hour on mainnet.
+const TIP_TIME_LAG_ALLOWANCE: Duration = Duration::from_secs(3_600);
+/// Allowance for the Bitcoin chain tip timestamp running ahead of the wall clock. Consensus rules accept
+/// block timestamps up to 2 hours ahead of the network-adjusted time.
+const TIP_TIME_LEAD_ALLOWANCE: Duration = Duration::from_secs(2 * 3_600);
 
 #[derive(Debug)]
 struct BtcWatchState {
@@ -27,15 +46,49 @@ struct BtcWatchState {
     bridge_address: BitcoinAddress,
 }
 
+/// Health details reported by [`BtcWatch`].
+#[derive(Debug, Serialize)]
+struct BtcWatchDetails {
+    last_processed_bitcoin_block: u32,
+    message_processors: Vec<MessageProcessorInfo>,
+}
+
+/// Stream of new block hashes announced by the Bitcoin node.
+struct BlockNotifications(BoxStream<'static, BitcoinBlockHash>);
+
+impl fmt::Debug for BlockNotifications {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("BlockNotifications")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reason for [`BtcWatch`] to run the next poll.
+#[derive(Debug, PartialEq)]
+enum PollTrigger {
+    Interval,
+    NewBlock(BitcoinBlockHash),
+    Stop,
+}
+
 #[derive(Debug)]
 pub struct BtcWatch {
     indexer: BitcoinInscriptionIndexer,
     poll_interval: Duration,
+    poll_jitter: Duration,
+    max_message_bytes: Option<usize>,
+    block_notifications: Option<BlockNotifications>,
+    max_clock_skew: Option<Duration>,
     confirmations_for_btc_msg: u64,
     last_processed_bitcoin_block: u32,
     pool: ConnectionPool<Core>,
     message_processors: Vec<Box<dyn MessageProcessor>>,
     btc_blocks_lag: u32,
+    metrics: MetricsReporter,
+    throughput: ThroughputTracker,
+    health_check: ReactiveHealthCheck,
+    health_updater: HealthUpdater,
 }
 
 impl BtcWatch {
@@ -44,25 +97,29 @@ impl BtcWatch {
         rpc_url: &str,
         network: BitcoinNetwork,
         node_auth: NodeAuth,
+        btc_client_config: &ViaBtcClientConfig,
         confirmations_for_btc_msg: Option<u64>,
         bootstrap_txids: Vec<BitcoinTxid>,
         pool: ConnectionPool<Core>,
         poll_interval: Duration,
         btc_blocks_lag: u32,
+        metrics_enabled: bool,
     ) -> anyhow::Result<Self> {
-        let indexer =
-            BitcoinInscriptionIndexer::new(rpc_url, network, node_auth, bootstrap_txids).await?;
+        let indexer = BitcoinInscriptionIndexer::new_with_config(
+            rpc_url,
+            network,
+            node_auth,
+            bootstrap_txids,
+            btc_client_config,
+        )
+        .await?;
         let mut storage = pool.connection_tagged("via_btc_watch").await?;
         let state = Self::initialize_state(&indexer, &mut storage, btc_blocks_lag).await?;
         tracing::info!("initialized state: {state:?}");
         drop(storage);
 
-        // TODO: add other message processors if needed
-        let message_processors: Vec<Box<dyn MessageProcessor>> =
-            vec![Box::new(L1ToL2MessageProcessor::new(
-                state.bridge_address.clone(),
-                state.next_expected_priority_id,
-            ))];
+        let metrics = MetricsReporter::new(metrics_enabled);
+        let message_processors = Self::default_message_processors(&state, metrics);
 
         let confirmations_for_btc_msg = confirmations_for_btc_msg.unwrap_or(0);
 
@@ -74,17 +131,104 @@ impl BtcWatch {
             ));
         }
 
+        let (health_check, health_updater) = ReactiveHealthCheck::new("via_btc_watch");
         Ok(Self {
             indexer,
             poll_interval,
+            poll_jitter: Duration::ZERO,
+            max_message_bytes: None,
+            block_notifications: None,
+            max_clock_skew: None,
             confirmations_for_btc_msg,
             last_processed_bitcoin_block: state.last_processed_bitcoin_block,
             pool,
             message_processors,
             btc_blocks_lag,
+            metrics,
+            throughput: ThroughputTracker::new(THROUGHPUT_WINDOW),
+            health_check,
+            health_updater,
         })
     }
 
+    /// Sets the maximum random deviation of the poll interval. The jitter is capped by the poll interval.
+    pub fn with_poll_jitter(mut self, poll_jitter: Duration) -> Self {
+        self.poll_jitter = poll_jitter.min(self.poll_interval);
+        self
+    }
+
+    /// Caps the approximate size of messages collected in a single poll. If the cap is reached,
+    /// the remaining blocks are processed during the next polls. At least one block is always processed.
+    pub fn with_max_message_bytes(mut self, max_message_bytes: Option<usize>) -> Self {
+        self.max_message_bytes = max_message_bytes;
+        self
+    }
+
+    /// Polls as soon as a new block is announced, in addition to polling on the interval.
+    /// If the notifications end (e.g. the connection to the node is lost), only the interval is used.
+    pub fn with_block_notifications(
+        mut self,
+        notifications: BoxStream<'static, BitcoinBlockHash>,
+    ) -> Self {
+        self.block_notifications = Some(BlockNotifications(notifications));
+        self
+    }
+
+    /// Checks on startup and then periodically that the local clock doesn't deviate from the timestamp
+    /// of the Bitcoin chain tip by more than `max_clock_skew` (on top of [`TIP_TIME_LAG_ALLOWANCE`]
+    /// and [`TIP_TIME_LEAD_ALLOWANCE`]). Startup fails if it does;
+    /// later violations are reported as errors.
+    pub fn with_max_clock_skew(mut self, max_clock_skew: Option<Duration>) -> Self {
+        self.max_clock_skew = max_clock_skew;
+        self
+    }
+
+    /// Makes the indexer reject transactions carrying more than one inscription.
+    pub fn with_strict_inscription_validation(mut self, strict: bool) -> Self {
+        self.indexer = self.indexer.with_strict_inscription_validation(strict);
+        self
+    }
+
+    /// Makes the indexer persist the raw fetched blocks to the archive.
+    pub fn with_block_archive(mut self, archive: BlockArchive) -> Self {
+        self.indexer = self.indexer.with_block_archive(archive);
+        self
+    }
+
+    fn default_message_processors(
+        state: &BtcWatchState,
+        metrics: MetricsReporter,
+    ) -> Vec<Box<dyn MessageProcessor>> {
+        // TODO: add other message processors if needed
+        vec![Box::new(L1ToL2MessageProcessor::new(
+            state.bridge_address.clone(),
+            state.next_expected_priority_id,
+            metrics,
+        ))]
+    }
+
+    /// Returns health check associated with this watcher.
+    pub fn health_check(&self) -> &ReactiveHealthCheck {
+        &self.health_check
+    }
+
+    /// Returns the registered message processors together with their current state.
+    pub fn message_processors(&self) -> Vec<MessageProcessorInfo> {
+        self.message_processors
+            .iter()
+            .map(|processor| processor.info())
+            .collect()
+    }
+
+    fn update_health(&self, status: HealthStatus) {
+        let details = BtcWatchDetails {
+            last_processed_bitcoin_block: self.last_processed_bitcoin_block,
+            message_processors: self.message_processors(),
+        };
+        self.health_updater
+            .update(Health::from(status).with_details(details));
+    }
+
     async fn initialize_state(
         indexer: &BitcoinInscriptionIndexer,
         storage: &mut Connection<'_, Core>,
@@ -121,26 +265,54 @@ impl BtcWatch {
     }
 
     pub async fn run(mut self, mut stop_receiver: watch::Receiver<bool>) -> anyhow::Result<()> {
-        let mut timer = tokio::time::interval(self.poll_interval);
+        if let Some(max_clock_skew) = self.max_clock_skew {
+            self.check_clock_skew(max_clock_skew).await?;
+        }
+        let mut next_clock_skew_check = Instant::now() + CLOCK_SKEW_CHECK_INTERVAL;
+        let mut next_poll = Instant::now();
         let pool = self.pool.clone();
+        self.update_health(HealthStatus::Ready);
 
         while !*stop_receiver.borrow_and_update() {
-            tokio::select! {
-                _ = timer.tick() => { /* continue iterations */ }
-                _ = stop_receiver.changed() => break,
+            let trigger =
+                wait_for_poll(next_poll, &mut self.block_notifications, &mut stop_receiver).await;
+            match trigger {
+                PollTrigger::Interval => { /* continue iterations */ }
+                PollTrigger::NewBlock(block_hash) => {
+                    tracing::debug!("New block {block_hash} announced, polling ahead of schedule");
+                    next_poll = Instant::now();
+                }
+                PollTrigger::Stop => break,
+            }
+            next_poll += jittered_interval(
+                self.poll_interval,
+                self.poll_jitter,
+                &mut rand::thread_rng(),
+            );
+            // Don't try to catch up with missed polls if an iteration took too long.
+            next_poll = next_poll.max(Instant::now());
+
+            if let Some(max_clock_skew) = self.max_clock_skew {
+                if Instant::now() >= next_clock_skew_check {
+                    next_clock_skew_check = Instant::now() + CLOCK_SKEW_CHECK_INTERVAL;
+                    if let Err(err) = self.check_clock_skew(max_clock_skew).await {
+                        tracing::error!("Clock skew check failed: {err:#}");
+                    }
+                }
             }
-            METRICS.btc_poll.inc();
+            self.metrics.btc_poll();
 
             let mut storage = pool.connection_tagged("via_btc_watch").await?;
             match self.loop_iteration(&mut storage).await {
-                Ok(()) => { /* everything went fine */ }
+                Ok(()) => self.update_health(HealthStatus::Ready),
                 Err(MessageProcessorError::Internal(err)) => {
-                    METRICS.errors[&ErrorType::InternalError].inc();
+                    self.metrics.error(ErrorType::InternalError);
                     tracing::error!("Internal error processing new blocks: {err:?}");
                     return Err(err);
                 }
                 Err(err) => {
                     tracing::error!("Failed to process new blocks: {err}");
+                    self.update_health(HealthStatus::Affected);
                     self.last_processed_bitcoin_block =
                         Self::initialize_state(&self.indexer, &mut storage, self.btc_blocks_lag)
                             .await?
@@ -164,21 +336,389 @@ impl BtcWatch {
             .map_err(|e| MessageProcessorError::Internal(anyhow::anyhow!(e.to_string())))?
             .saturating_sub(self.confirmations_for_btc_msg as u128) as u32;
         if to_block <= self.last_processed_bitcoin_block {
+            self.report_throughput(0, 0);
             return Ok(());
         }
 
-        let messages = self
+        let mut batch = MessageBatch::new(self.max_message_bytes);
+        let mut last_collected_block = self.last_processed_bitcoin_block;
+        for block in self.last_processed_bitcoin_block + 1..=to_block {
+            let messages = self
+                .indexer
+                .process_block(block)
+                .await
+                .map_err(|e| MessageProcessorError::Internal(e.into()))?;
+            last_collected_block = block;
+            if batch.push_block(messages) && block < to_block {
+                tracing::info!(
+                    "Message batch reached {} bytes at block {block}, deferring blocks up to {to_block} to the next poll",
+                    batch.bytes
+                );
+                break;
+            }
+        }
+
+        for processor in &mut self.message_processors {
+            processor.process_messages(storage, &batch.messages).await?;
+        }
+
+        let processed_blocks = last_collected_block - self.last_processed_bitcoin_block;
+        self.last_processed_bitcoin_block = last_collected_block;
+        self.report_throughput(processed_blocks, to_block - last_collected_block);
+        Ok(())
+    }
+
+    /// Compares the local clock with the timestamp of the Bitcoin chain tip.
+    async fn check_clock_skew(&self, max_clock_skew: Duration) -> anyhow::Result<()> {
+        let tip_time = self
             .indexer
-            .process_blocks(self.last_processed_bitcoin_block + 1, to_block)
+            .fetch_tip_time()
             .await
-            .map_err(|e| MessageProcessorError::Internal(e.into()))?;
+            .context("failed fetching chain tip time from the Bitcoin node")?;
+        let skew = clock_skew_secs(SystemTime::now(), tip_time);
+        self.metrics.clock_skew(skew);
+        ensure_clock_skew_within(skew, max_clock_skew)
+    }
+
+    fn report_throughput(&mut self, processed_blocks: u32, lag: u32) {
+        let now = std::time::Instant::now();
+        self.throughput.record(now, processed_blocks.into());
+        self.metrics.throughput(
+            self.throughput.blocks_per_second(now),
+            self.throughput.estimated_time_to_tip(now, lag.into()),
+        );
+    }
+}
+
+/// Messages collected during a single poll, together with their approximate in-memory size.
+#[derive(Debug)]
+struct MessageBatch {
+    messages: Vec<FullInscriptionMessage>,
+    bytes: usize,
+    max_bytes: Option<usize>,
+}
 
-        // temporary use only one processor to avoid cloning
-        if let Some(processor) = self.message_processors.first_mut() {
-            processor.process_messages(storage, messages).await?;
+impl MessageBatch {
+    fn new(max_bytes: Option<usize>) -> Self {
+        Self {
+            messages: Vec::new(),
+            bytes: 0,
+            max_bytes,
         }
+    }
 
-        self.last_processed_bitcoin_block = to_block;
-        Ok(())
+    /// Adds messages from a single block. Returns `true` if the batch has reached its size cap.
+    fn push_block(&mut self, messages: Vec<FullInscriptionMessage>) -> bool {
+        self.bytes += messages.iter().map(estimated_message_size).sum::<usize>();
+        self.messages.extend(messages);
+        self.max_bytes
+            .is_some_and(|max_bytes| self.bytes >= max_bytes)
+    }
+}
+
+/// Estimates the memory held by `message`, counting the variable-sized parts of the inscription.
+fn estimated_message_size(message: &FullInscriptionMessage) -> usize {
+    let heap_size = match message {
+        FullInscriptionMessage::L1BatchDAReference(msg) => {
+            msg.input.da_identifier.len() + msg.input.blob_id.len()
+        }
+        FullInscriptionMessage::ProofDAReference(msg) => {
+            msg.input.da_identifier.len() + msg.input.blob_id.len()
+        }
+        FullInscriptionMessage::ValidatorAttestation(_)
+        | FullInscriptionMessage::ProposeSequencer(_) => 0,
+        FullInscriptionMessage::SystemBootstrapping(msg) => {
+            msg.input.verifier_p2wpkh_addresses.len() * std::mem::size_of::<BitcoinAddress>()
+        }
+        FullInscriptionMessage::L1ToL2Message(msg) => {
+            msg.input.call_data.len()
+                + msg
+                    .tx_outputs
+                    .iter()
+                    .map(|output| std::mem::size_of_val(output) + output.script_pubkey.len())
+                    .sum::<usize>()
+        }
+    };
+    std::mem::size_of::<FullInscriptionMessage>() + heap_size
+}
+
+/// Waits until the next poll is due, a new block is announced or the watcher is stopped.
+/// Ended notifications are dropped, so that the watcher falls back to polling on the interval.
+async fn wait_for_poll(
+    next_poll: Instant,
+    notifications: &mut Option<BlockNotifications>,
+    stop_receiver: &mut watch::Receiver<bool>,
+) -> PollTrigger {
+    loop {
+        let notification = async {
+            match notifications.as_mut() {
+                Some(notifications) => notifications.0.next().await,
+                None => future::pending().await,
+            }
+        };
+        let block_hash = tokio::select! {
+            _ = tokio::time::sleep_until(next_poll) => return PollTrigger::Interval,
+            block_hash = notification => block_hash,
+            _ = stop_receiver.changed() => return PollTrigger::Stop,
+        };
+        match block_hash {
+            Some(block_hash) => return PollTrigger::NewBlock(block_hash),
+            None => {
+                tracing::warn!("Block notifications ended, falling back to polling");
+                *notifications = None;
+            }
+        }
+    }
+}
+
+/// Returns by how many seconds the local clock is ahead of the Bitcoin chain tip timestamp (negative if behind).
+fn clock_skew_secs(local_time: SystemTime, tip_time: u64) -> i64 {
+    let local_secs = local_time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |since_epoch| since_epoch.as_secs());
+    local_secs as i64 - tip_time as i64
+}
+
+/// Checks the clock skew against `max_clock_skew` extended by the allowance for the tip timestamp
+/// trailing (if the skew is positive) or running ahead of (if negative) the wall clock.
+fn ensure_clock_skew_within(skew_secs: i64, max_clock_skew: Duration) -> anyhow::Result<()> {
+    let tip_time_allowance = if skew_secs >= 0 {
+        TIP_TIME_LAG_ALLOWANCE
+    } else {
+        TIP_TIME_LEAD_ALLOWANCE
+    };
+    let allowed_skew = max_clock_skew + tip_time_allowance;
+    anyhow::ensure!(
+        skew_secs.unsigned_abs() <= allowed_skew.as_secs(),
+        "local clock deviates from the Bitcoin chain tip time by {skew_secs}s, more than the allowed {allowed_skew:?}"
+    );
+    Ok(())
+}
+
+/// Returns `interval` shifted by a uniformly distributed random value in `[-jitter, jitter]`.
+fn jittered_interval(interval: Duration, jitter: Duration, rng: &mut impl Rng) -> Duration {
+    if jitter.is_zero() {
+        return interval;
+    }
+    let jitter_ms = jitter.as_millis() as i64;
+    let offset_ms = rng.gen_range(-jitter_ms..=jitter_ms);
+    if offset_ms >= 0 {
+        interval + Duration::from_millis(offset_ms as u64)
+    } else {
+        interval.saturating_sub(Duration::from_millis(offset_ms.unsigned_abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use bitcoin::{
+        hashes::Hash, script::PushBytesBuf, taproot::Signature, Amount, ScriptBuf, TxOut, Txid,
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+    use via_btc_client::types::{CommonFields, L1ToL2Message, L1ToL2MessageInput};
+    use zksync_types::Address;
+
+    use super::*;
+    use crate::metrics::{InscriptionStage, ViaBtcWatcherMetrics};
+
+    fn bridge_address() -> BitcoinAddress {
+        "bcrt1qx2lk0unukm80qmepjp49hwf9z6xnz0s73k9j56"
+            .parse::<BitcoinAddress<_>>()
+            .unwrap()
+            .assume_checked()
+    }
+
+    fn l1_to_l2_message(call_data_len: usize) -> FullInscriptionMessage {
+        FullInscriptionMessage::L1ToL2Message(L1ToL2Message {
+            common: CommonFields {
+                schnorr_signature: Signature::from_slice(&[1; 64]).unwrap(),
+                encoded_public_key: PushBytesBuf::new(),
+                block_height: 1,
+                tx_id: Txid::all_zeros(),
+                p2wpkh_address: bridge_address(),
+            },
+            amount: Amount::from_sat(1_000),
+            input: L1ToL2MessageInput {
+                receiver_l2_address: Address::zero(),
+                l2_contract_address: Address::zero(),
+                call_data: vec![0; call_data_len],
+            },
+            tx_outputs: vec![TxOut {
+                value: Amount::from_sat(1_000),
+                script_pubkey: ScriptBuf::new(),
+            }],
+        })
+    }
+
+    #[test]
+    fn message_batch_without_cap_is_unlimited() {
+        let mut batch = MessageBatch::new(None);
+        for _ in 0..10 {
+            assert!(!batch.push_block(vec![l1_to_l2_message(1_000_000)]));
+        }
+        assert_eq!(batch.messages.len(), 10);
+        assert!(batch.bytes > 10_000_000);
+    }
+
+    #[test]
+    fn message_batch_reaches_cap_on_oversized_content() {
+        let small_message_size = estimated_message_size(&l1_to_l2_message(10));
+        let large_message_size = estimated_message_size(&l1_to_l2_message(10_000));
+        assert_eq!(large_message_size - small_message_size, 9_990);
+
+        let mut batch = MessageBatch::new(Some(20_000));
+        assert!(!batch.push_block(vec![]));
+        assert!(!batch.push_block(vec![l1_to_l2_message(10), l1_to_l2_message(10)]));
+        assert!(!batch.push_block(vec![l1_to_l2_message(10_000)]));
+        // The block that crosses the cap is still included in the batch.
+        assert!(batch.push_block(vec![l1_to_l2_message(10_000)]));
+        assert_eq!(batch.messages.len(), 4);
+        assert_eq!(batch.bytes, 2 * small_message_size + 2 * large_message_size);
+    }
+
+    #[test]
+    fn default_message_processors() {
+        let state = BtcWatchState {
+            last_processed_bitcoin_block: 100,
+            next_expected_priority_id: PriorityOpId(5),
+            bridge_address: bridge_address(),
+        };
+
+        let infos: Vec<_> =
+            BtcWatch::default_message_processors(&state, MetricsReporter::new(true))
+                .iter()
+                .map(|processor| processor.info())
+                .collect();
+        assert_eq!(
+            infos,
+            [MessageProcessorInfo {
+                name: L1ToL2MessageProcessor::NAME,
+                processed_messages: 0,
+                next_expected_priority_id: Some(PriorityOpId(5)),
+            }]
+        );
+    }
+
+    /// Processes a single deposit and returns the metrics it was reported to.
+    async fn process_deposit(metrics_enabled: bool) -> &'static ViaBtcWatcherMetrics {
+        let pool = ConnectionPool::<Core>::test_pool().await;
+        let mut storage = pool.connection().await.unwrap();
+        let mut deposit = l1_to_l2_message(0);
+        if let FullInscriptionMessage::L1ToL2Message(message) = &mut deposit {
+            message.tx_outputs[0].script_pubkey = bridge_address().script_pubkey();
+        }
+
+        let metrics = &*Box::leak(Box::<ViaBtcWatcherMetrics>::default());
+        let reporter = MetricsReporter::new(metrics_enabled).with_metrics(metrics);
+        let mut processor =
+            L1ToL2MessageProcessor::new(bridge_address(), PriorityOpId(0), reporter);
+        processor
+            .process_messages(&mut storage, &[deposit])
+            .await
+            .unwrap();
+        assert_eq!(processor.info().processed_messages, 1);
+        metrics
+    }
+
+    #[tokio::test]
+    async fn disabled_metrics_are_not_updated_when_processing_messages() {
+        let metrics = process_deposit(false).await;
+        assert_eq!(
+            metrics.inscriptions_processed[&InscriptionStage::Deposit].get(),
+            0
+        );
+        assert_eq!(metrics.errors[&ErrorType::DatabaseError].get(), 0);
+
+        let metrics = process_deposit(true).await;
+        assert_eq!(
+            metrics.inscriptions_processed[&InscriptionStage::Deposit].get(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn block_notification_triggers_single_poll() {
+        let block_hash = BitcoinBlockHash::from_byte_array([1; 32]);
+        let mut notifications = Some(BlockNotifications(
+            futures::stream::iter([block_hash]).boxed(),
+        ));
+        let (_stop_sender, mut stop_receiver) = watch::channel(false);
+
+        let far_poll = Instant::now() + Duration::from_secs(3_600);
+        let trigger = wait_for_poll(far_poll, &mut notifications, &mut stop_receiver).await;
+        assert_eq!(trigger, PollTrigger::NewBlock(block_hash));
+
+        // The notifications have ended, so the next poll happens on the interval.
+        let next_poll = Instant::now() + Duration::from_millis(10);
+        let trigger = wait_for_poll(next_poll, &mut notifications, &mut stop_receiver).await;
+        assert_eq!(trigger, PollTrigger::Interval);
+        assert!(notifications.is_none());
+    }
+
+    #[tokio::test]
+    async fn stop_signal_interrupts_waiting_for_poll() {
+        let mut notifications = Some(BlockNotifications(futures::stream::pending().boxed()));
+        let (stop_sender, mut stop_receiver) = watch::channel(false);
+        stop_sender.send_replace(true);
+
+        let far_poll = Instant::now() + Duration::from_secs(3_600);
+        let trigger = wait_for_poll(far_poll, &mut notifications, &mut stop_receiver).await;
+        assert_eq!(trigger, PollTrigger::Stop);
+    }
+
+    #[test]
+    fn clock_skew_out_of_bounds_is_rejected() {
+        let tip_time = 1_700_000_000;
+        let max_clock_skew = Duration::from_secs(600);
+
+        // The tip trailing the wall clock is tolerated up to the lag allowance.
+        let local_time = SystemTime::UNIX_EPOCH + Duration::from_secs(tip_time + 3_600);
+        let skew = clock_skew_secs(local_time, tip_time);
+        assert_eq!(skew, 3_600);
+        ensure_clock_skew_within(skew, max_clock_skew).unwrap();
+
+        let local_time = SystemTime::UNIX_EPOCH + Duration::from_secs(tip_time + 3_600 + 601);
+        let skew = clock_skew_secs(local_time, tip_time);
+        let err = ensure_clock_skew_within(skew, max_clock_skew).unwrap_err();
+        assert!(err.to_string().contains("4201s"), "{err}");
+
+        // The tip may run ahead of the wall clock by up to 2 hours.
+        let local_time = SystemTime::UNIX_EPOCH + Duration::from_secs(tip_time - 2 * 3_600);
+        let skew = clock_skew_secs(local_time, tip_time);
+        assert_eq!(skew, -2 * 3_600);
+        ensure_clock_skew_within(skew, max_clock_skew).unwrap();
+
+        let local_time = SystemTime::UNIX_EPOCH + Duration::from_secs(tip_time - 2 * 3_600 - 601);
+        let skew = clock_skew_secs(local_time, tip_time);
+        ensure_clock_skew_within(skew, max_clock_skew).unwrap_err();
+    }
+
+    #[test]
+    fn jittered_interval_stays_within_bounds() {
+        let interval = Duration::from_millis(1_000);
+        let jitter = Duration::from_millis(100);
+        let mut rng = StdRng::seed_from_u64(123);
+
+        let intervals: Vec<_> = (0..1_000)
+            .map(|_| jittered_interval(interval, jitter, &mut rng))
+            .collect();
+        for &value in &intervals {
+            assert!(
+                value >= interval - jitter && value <= interval + jitter,
+                "{value:?}"
+            );
+        }
+        let distinct: HashSet<_> = intervals.iter().collect();
+        assert!(distinct.len() > 1);
+        let average_ms =
+            intervals.iter().map(Duration::as_millis).sum::<u128>() / intervals.len() as u128;
+        assert!(average_ms.abs_diff(1_000) <= 10, "{average_ms}");
+
+        assert_eq!(
+            jittered_interval(interval, Duration::ZERO, &mut rng),
+            interval
+        );
     }
 }
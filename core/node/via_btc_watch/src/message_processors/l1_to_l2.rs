@@ -8,31 +8,49 @@ use zksync_types::{
 };
 
 use crate::{
-    message_processors::{MessageProcessor, MessageProcessorError},
-    metrics::{ErrorType, InscriptionStage, METRICS},
+    message_processors::{MessageProcessor, MessageProcessorError, MessageProcessorInfo},
+    metrics::{ErrorType, InscriptionStage, MetricsReporter},
 };
 
 #[derive(Debug)]
 pub struct L1ToL2MessageProcessor {
     bridge_address: BitcoinAddress,
     next_expected_priority_id: PriorityOpId,
+    processed_messages: u64,
+    metrics: MetricsReporter,
 }
 
 impl L1ToL2MessageProcessor {
-    pub fn new(bridge_address: BitcoinAddress, next_expected_priority_id: PriorityOpId) -> Self {
+    pub const NAME: &'static str = "l1_to_l2";
+
+    pub fn new(
+        bridge_address: BitcoinAddress,
+        next_expected_priority_id: PriorityOpId,
+        metrics: MetricsReporter,
+    ) -> Self {
         Self {
             bridge_address,
             next_expected_priority_id,
+            processed_messages: 0,
+            metrics,
         }
     }
 }
 
 #[async_trait::async_trait]
 impl MessageProcessor for L1ToL2MessageProcessor {
+    fn info(&self) -> MessageProcessorInfo {
+        MessageProcessorInfo {
+            name: Self::NAME,
+            processed_messages: self.processed_messages,
+            next_expected_priority_id: Some(self.next_expected_priority_id),
+        }
+    }
+
     async fn process_messages(
         &mut self,
         storage: &mut Connection<'_, Core>,
-        msgs: Vec<FullInscriptionMessage>,
+        msgs: &[FullInscriptionMessage],
     ) -> Result<(), MessageProcessorError> {
         let mut priority_ops = Vec::new();
         for msg in msgs {
@@ -59,7 +77,7 @@ impl MessageProcessor for L1ToL2MessageProcessor {
                         continue;
                     }
                     let serial_id = self.next_expected_priority_id;
-                    let l1_tx = self.create_l1_tx_from_message(&l1_to_l2_msg, serial_id)?;
+                    let l1_tx = self.create_l1_tx_from_message(l1_to_l2_msg, serial_id)?;
                     priority_ops.push((l1_tx, tx_id));
                     self.next_expected_priority_id = self.next_expected_priority_id.next();
                 }
@@ -71,15 +89,17 @@ impl MessageProcessor for L1ToL2MessageProcessor {
         }
 
         for (new_op, txid) in priority_ops {
-            METRICS.inscriptions_processed[&InscriptionStage::Deposit].inc();
+            self.metrics
+                .inscription_processed(InscriptionStage::Deposit);
             storage
                 .via_transactions_dal()
                 .insert_transaction_l1(&new_op, new_op.eth_block(), txid)
                 .await
                 .map_err(|e| {
-                    METRICS.errors[&ErrorType::DatabaseError].inc();
+                    self.metrics.error(ErrorType::DatabaseError);
                     MessageProcessorError::DatabaseError(e.to_string())
                 })?;
+            self.processed_messages += 1;
         }
 
         Ok(())
@@ -1,6 +1,8 @@
 pub(crate) use l1_to_l2::L1ToL2MessageProcessor;
+use serde::Serialize;
 use via_btc_client::types::FullInscriptionMessage;
 use zksync_dal::{Connection, Core};
+use zksync_types::PriorityOpId;
 
 mod l1_to_l2;
 
@@ -12,11 +14,26 @@ pub(super) enum MessageProcessorError {
     DatabaseError(String),
 }
 
+/// Introspection data reported by a [`MessageProcessor`], e.g. for debugging a stuck processor.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MessageProcessorInfo {
+    /// Name of the processor.
+    pub name: &'static str,
+    /// Number of messages persisted by the processor since the watcher started.
+    pub processed_messages: u64,
+    /// Priority ID that will be assigned to the next L1 -> L2 message, if applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_expected_priority_id: Option<PriorityOpId>,
+}
+
 #[async_trait::async_trait]
 pub(super) trait MessageProcessor: 'static + std::fmt::Debug + Send + Sync {
+    /// Returns the current state of the processor.
+    fn info(&self) -> MessageProcessorInfo;
+
     async fn process_messages(
         &mut self,
         storage: &mut Connection<'_, Core>,
-        msgs: Vec<FullInscriptionMessage>,
+        msgs: &[FullInscriptionMessage],
     ) -> Result<(), MessageProcessorError>;
 }
@@ -1,4 +1,9 @@
-use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Metrics};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use vise::{Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Metrics, Unit};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -24,7 +29,159 @@ pub struct ViaBtcWatcherMetrics {
 
     /// Number of errors encountered, labeled by error type.
     pub errors: Family<ErrorType, Counter>,
+
+    /// Bitcoin blocks processed per second, averaged over a sliding window.
+    pub blocks_per_second: Gauge<f64>,
+
+    /// Estimated time to catch up with the Bitcoin chain tip at the current throughput.
+    #[metrics(unit = Unit::Seconds)]
+    pub estimated_time_to_tip: Gauge<Duration>,
+
+    /// Seconds by which the local clock is ahead of the timestamp of the Bitcoin chain tip.
+    pub clock_skew_seconds: Gauge<i64>,
 }
 
 #[vise::register]
 pub static METRICS: vise::Global<ViaBtcWatcherMetrics> = vise::Global::new();
+
+/// Reporter for [`METRICS`] that can be disabled to reduce cardinality in deployments that don't need
+/// watcher metrics. If disabled, metrics are still registered, but never updated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MetricsReporter {
+    metrics: &'static ViaBtcWatcherMetrics,
+    enabled: bool,
+}
+
+impl MetricsReporter {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            metrics: &METRICS,
+            enabled,
+        }
+    }
+
+    /// Reports to the provided metrics instead of the global [`METRICS`].
+    #[cfg(test)]
+    pub fn with_metrics(mut self, metrics: &'static ViaBtcWatcherMetrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    fn metrics(self) -> Option<&'static ViaBtcWatcherMetrics> {
+        self.enabled.then_some(self.metrics)
+    }
+
+    pub fn btc_poll(self) {
+        if let Some(metrics) = self.metrics() {
+            metrics.btc_poll.inc();
+        }
+    }
+
+    pub fn inscription_processed(self, stage: InscriptionStage) {
+        if let Some(metrics) = self.metrics() {
+            metrics.inscriptions_processed[&stage].inc();
+        }
+    }
+
+    pub fn error(self, error_type: ErrorType) {
+        if let Some(metrics) = self.metrics() {
+            metrics.errors[&error_type].inc();
+        }
+    }
+
+    pub fn clock_skew(self, skew_secs: i64) {
+        if let Some(metrics) = self.metrics() {
+            metrics.clock_skew_seconds.set(skew_secs);
+        }
+    }
+
+    pub fn throughput(self, blocks_per_second: f64, time_to_tip: Option<Duration>) {
+        if let Some(metrics) = self.metrics() {
+            metrics.blocks_per_second.set(blocks_per_second);
+            if let Some(time_to_tip) = time_to_tip {
+                metrics.estimated_time_to_tip.set(time_to_tip);
+            }
+        }
+    }
+}
+
+/// Tracks the number of processed blocks over a sliding time window.
+#[derive(Debug)]
+pub(crate) struct ThroughputTracker {
+    window: Duration,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl ThroughputTracker {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Records that `blocks` were processed at `now`, and drops samples that left the window.
+    pub fn record(&mut self, now: Instant, blocks: u64) {
+        self.samples.push_back((now, blocks));
+        while let Some(&(timestamp, _)) = self.samples.front() {
+            if now.duration_since(timestamp) <= self.window {
+                break;
+            }
+            self.samples.pop_front();
+        }
+    }
+
+    /// Returns the number of blocks processed per second within the window ending at `now`.
+    pub fn blocks_per_second(&self, now: Instant) -> f64 {
+        let Some(&(first_timestamp, _)) = self.samples.front() else {
+            return 0.0;
+        };
+        let elapsed = now.duration_since(first_timestamp).max(self.window);
+        let blocks: u64 = self.samples.iter().map(|&(_, blocks)| blocks).sum();
+        blocks as f64 / elapsed.as_secs_f64()
+    }
+
+    /// Estimates the time needed to process `lag` blocks, or `None` if no blocks were processed recently.
+    pub fn estimated_time_to_tip(&self, now: Instant, lag: u64) -> Option<Duration> {
+        if lag == 0 {
+            return Some(Duration::ZERO);
+        }
+        let blocks_per_second = self.blocks_per_second(now);
+        (blocks_per_second > 0.0).then(|| Duration::from_secs_f64(lag as f64 / blocks_per_second))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throughput_over_sliding_window() {
+        let window = Duration::from_secs(60);
+        let start = Instant::now();
+        let mut tracker = ThroughputTracker::new(window);
+        assert_eq!(tracker.blocks_per_second(start), 0.0);
+        assert_eq!(tracker.estimated_time_to_tip(start, 10), None);
+        assert_eq!(
+            tracker.estimated_time_to_tip(start, 0),
+            Some(Duration::ZERO)
+        );
+
+        // 120 blocks in 60 seconds
+        for i in 1..=6 {
+            tracker.record(start + Duration::from_secs(i * 10), 20);
+        }
+        let now = start + Duration::from_secs(60);
+        assert_eq!(tracker.blocks_per_second(now), 2.0);
+        assert_eq!(
+            tracker.estimated_time_to_tip(now, 30),
+            Some(Duration::from_secs(15))
+        );
+
+        // Samples older than the window are dropped.
+        let now = start + Duration::from_secs(130);
+        tracker.record(now, 60);
+        assert_eq!(tracker.samples.len(), 1);
+        assert_eq!(tracker.blocks_per_second(now), 1.0);
+    }
+}
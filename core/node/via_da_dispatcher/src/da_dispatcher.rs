@@ -1,4 +1,8 @@
-use std::{future::Future, sync::Arc, time::Duration};
+use std::{
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context;
 use chrono::Utc;
@@ -15,7 +19,7 @@ use zksync_object_store::{ObjectStore, ObjectStoreError};
 use zksync_prover_interface::outputs::L1BatchProofForL1;
 use zksync_types::{protocol_version::ProtocolSemanticVersion, L1BatchNumber};
 
-use crate::metrics::METRICS;
+use crate::metrics::{DataAvailabilityDispatcherMetrics, METRICS};
 
 #[derive(Debug)]
 pub struct ViaDataAvailabilityDispatcher {
@@ -24,6 +28,8 @@ pub struct ViaDataAvailabilityDispatcher {
     config: DADispatcherConfig,
     blob_store: Arc<dyn ObjectStore>,
     dispatch_real_proof: bool,
+    /// `None` if metrics are disabled in the config.
+    metrics: Option<&'static DataAvailabilityDispatcherMetrics>,
 }
 
 impl ViaDataAvailabilityDispatcher {
@@ -34,12 +40,14 @@ impl ViaDataAvailabilityDispatcher {
         blob_store: Arc<dyn ObjectStore>,
         dispatch_real_proof: bool,
     ) -> Self {
+        let metrics = config.metrics_enabled().then_some(&*METRICS);
         Self {
             pool,
             config,
             client,
             blob_store,
             dispatch_real_proof,
+            metrics,
         }
     }
 
@@ -97,12 +105,17 @@ impl ViaDataAvailabilityDispatcher {
         drop(conn);
 
         for batch in batches {
-            let dispatch_latency = METRICS.blob_dispatch_latency.start();
+            let dispatch_started_at = Instant::now();
 
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
-            })
+            let dispatch_response = retry(
+                self.config.max_retries(),
+                batch.l1_batch_number,
+                self.metrics,
+                || {
+                    self.client
+                        .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
+                },
+            )
             .await
             .with_context(|| {
                 format!(
@@ -111,7 +124,7 @@ impl ViaDataAvailabilityDispatcher {
                     batch.pubdata.len()
                 )
             })?;
-            let dispatch_latency_duration = dispatch_latency.observe();
+            let dispatch_latency_duration = dispatch_started_at.elapsed();
 
             let sent_at = Utc::now().naive_utc();
 
@@ -125,10 +138,15 @@ impl ViaDataAvailabilityDispatcher {
                 .await?;
             drop(conn);
 
-            METRICS
-                .last_dispatched_l1_batch
-                .set(batch.l1_batch_number.0 as usize);
-            METRICS.blob_size.observe(batch.pubdata.len());
+            if let Some(metrics) = self.metrics {
+                metrics
+                    .blob_dispatch_latency
+                    .observe(dispatch_latency_duration);
+                metrics
+                    .last_dispatched_l1_batch
+                    .set(batch.l1_batch_number.0 as usize);
+                metrics.blob_size.observe(batch.pubdata.len());
+            }
             tracing::info!(
                 "Dispatched a DA for batch_number: {}, pubdata_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
                 batch.l1_batch_number,
@@ -160,7 +178,7 @@ impl ViaDataAvailabilityDispatcher {
         drop(conn);
 
         for batch in batches {
-            let dispatch_latency = METRICS.blob_dispatch_latency.start();
+            let dispatch_started_at = Instant::now();
 
             let dummy_proof = self
                 .prepare_dummy_proof_operation(batch)
@@ -172,12 +190,12 @@ impl ViaDataAvailabilityDispatcher {
                     )
                 })?;
 
-            let dispatch_response = retry(self.config.max_retries(), batch, || {
+            let dispatch_response = retry(self.config.max_retries(), batch, self.metrics, || {
                 self.client.dispatch_blob(batch.0, dummy_proof.clone())
             })
             .await?;
 
-            let dispatch_latency_duration = dispatch_latency.observe();
+            let dispatch_latency_duration = dispatch_started_at.elapsed();
 
             let sent_at = Utc::now().naive_utc();
 
@@ -186,8 +204,13 @@ impl ViaDataAvailabilityDispatcher {
                 .insert_proof_da(batch, dispatch_response.blob_id.as_str(), sent_at)
                 .await?;
 
-            METRICS.last_dispatched_proof_batch.set(batch.0 as usize);
-            METRICS.blob_size.observe(dummy_proof.len());
+            if let Some(metrics) = self.metrics {
+                metrics
+                    .blob_dispatch_latency
+                    .observe(dispatch_latency_duration);
+                metrics.last_dispatched_proof_batch.set(batch.0 as usize);
+                metrics.blob_size.observe(dummy_proof.len());
+            }
             tracing::info!(
                 "Dispatched a dummy proof for batch_number: {}, proof_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
                 batch,
@@ -230,12 +253,17 @@ impl ViaDataAvailabilityDispatcher {
             // concatenate all bytes
             let final_proof = proof_bytes.into_iter().flatten().collect::<Vec<u8>>();
 
-            let dispatch_latency = METRICS.proof_dispatch_latency.start();
+            let dispatch_started_at = Instant::now();
 
-            let dispatch_response = retry(self.config.max_retries(), proof.l1_batch_number, || {
-                self.client
-                    .dispatch_blob(proof.l1_batch_number.0, final_proof.clone())
-            })
+            let dispatch_response = retry(
+                self.config.max_retries(),
+                proof.l1_batch_number,
+                self.metrics,
+                || {
+                    self.client
+                        .dispatch_blob(proof.l1_batch_number.0, final_proof.clone())
+                },
+            )
             .await
             .with_context(|| {
                 format!(
@@ -245,7 +273,7 @@ impl ViaDataAvailabilityDispatcher {
                 )
             })?;
 
-            let dispatch_latency_duration = dispatch_latency.observe();
+            let dispatch_latency_duration = dispatch_started_at.elapsed();
 
             let sent_at = Utc::now().naive_utc();
 
@@ -259,10 +287,15 @@ impl ViaDataAvailabilityDispatcher {
                 .await?;
             drop(conn);
 
-            METRICS
-                .last_dispatched_proof_batch
-                .set(proof.l1_batch_number.0 as usize);
-            METRICS.blob_size.observe(final_proof.len());
+            if let Some(metrics) = self.metrics {
+                metrics
+                    .proof_dispatch_latency
+                    .observe(dispatch_latency_duration);
+                metrics
+                    .last_dispatched_proof_batch
+                    .set(proof.l1_batch_number.0 as usize);
+                metrics.blob_size.observe(final_proof.len());
+            }
             tracing::info!(
                 "Dispatched a proof for batch_number: {}, proof_size: {}, dispatch_latency: {dispatch_latency_duration:?}",
                 proof.l1_batch_number,
@@ -603,12 +636,14 @@ impl ViaDataAvailabilityDispatcher {
         drop(conn);
 
         let inclusion_latency = Utc::now().signed_duration_since(blob_info.sent_at);
-        if let Ok(latency) = inclusion_latency.to_std() {
-            METRICS.inclusion_latency.observe(latency);
+        if let Some(metrics) = self.metrics {
+            if let Ok(latency) = inclusion_latency.to_std() {
+                metrics.inclusion_latency.observe(latency);
+            }
+            metrics
+                .last_included_l1_batch
+                .set(blob_info.l1_batch_number.0 as usize);
         }
-        METRICS
-            .last_included_l1_batch
-            .set(blob_info.l1_batch_number.0 as usize);
 
         tracing::info!(
             "Received inclusion data for batch_number: {}, inclusion_latency_seconds: {}",
@@ -668,14 +703,15 @@ impl ViaDataAvailabilityDispatcher {
         drop(conn);
 
         let inclusion_latency = Utc::now().signed_duration_since(proof_info.sent_at);
-        if let Ok(latency) = inclusion_latency.to_std() {
-            METRICS.inclusion_latency.observe(latency);
+        if let Some(metrics) = self.metrics {
+            if let Ok(latency) = inclusion_latency.to_std() {
+                metrics.inclusion_latency.observe(latency);
+            }
+            metrics
+                .last_included_proof_batch
+                .set(proof_info.l1_batch_number.0 as usize);
         }
 
-        METRICS
-            .last_included_proof_batch
-            .set(proof_info.l1_batch_number.0 as usize);
-
         tracing::info!(
             "Received inclusion data for proof batch_number: {}, inclusion_latency_seconds: {}",
             proof_info.l1_batch_number,
@@ -689,6 +725,7 @@ impl ViaDataAvailabilityDispatcher {
 async fn retry<T, Fut, F>(
     max_retries: u16,
     batch_number: L1BatchNumber,
+    metrics: Option<&DataAvailabilityDispatcherMetrics>,
     mut f: F,
 ) -> Result<T, DAError>
 where
@@ -700,7 +737,9 @@ where
     loop {
         match f().await {
             Ok(result) => {
-                METRICS.dispatch_call_retries.observe(retries as usize);
+                if let Some(metrics) = metrics {
+                    metrics.dispatch_call_retries.observe(retries as usize);
+                }
                 return Ok(result);
             }
             Err(err) => {
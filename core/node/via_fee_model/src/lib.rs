@@ -78,6 +78,46 @@ impl BatchFeeModelInputProvider for ViaApiFeeInputProvider {
     }
 }
 
+/// [`BatchFeeModelInputProvider`] that always returns the configured fee input, ignoring scale factors.
+/// Used by test and regtest deployments that need stable fees.
+#[derive(Debug)]
+pub struct FixedFeeInputProvider {
+    fee_model_config: FeeModelConfigV2,
+    fee_input: BatchFeeInput,
+}
+
+impl FixedFeeInputProvider {
+    pub fn new(config: FeeModelConfig, fee_input: BatchFeeInput) -> anyhow::Result<Self> {
+        match config {
+            FeeModelConfig::V2(fee_model_config) => Ok(Self {
+                fee_model_config,
+                fee_input,
+            }),
+            FeeModelConfig::V1(_) => Err(anyhow::anyhow!("Via fee model must be inited using V2")),
+        }
+    }
+}
+
+#[async_trait]
+impl BatchFeeModelInputProvider for FixedFeeInputProvider {
+    async fn get_batch_fee_input_scaled(
+        &self,
+        _l1_gas_price_scale_factor: f64,
+        _l1_pubdata_price_scale_factor: f64,
+    ) -> anyhow::Result<BatchFeeInput> {
+        Ok(self.fee_input)
+    }
+
+    fn get_fee_model_params(&self) -> FeeParams {
+        FeeParams::V2(FeeParamsV2::new(
+            self.fee_model_config,
+            self.fee_input.l1_gas_price(),
+            self.fee_input.fair_pubdata_price(),
+            BaseTokenConversionRatio::default(),
+        ))
+    }
+}
+
 /// Mock [`BatchFeeModelInputProvider`] implementation that returns a constant value.
 /// Intended to be used in tests only.
 #[derive(Debug)]
@@ -95,3 +135,36 @@ impl BatchFeeModelInputProvider for MockBatchFeeParamsProvider {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn fixed_provider_ignores_scale_factors() {
+        let config = FeeModelConfig::V2(FeeModelConfigV2 {
+            minimal_l2_gas_price: 100_000_000,
+            compute_overhead_part: 0.0,
+            pubdata_overhead_part: 1.0,
+            batch_overhead_l1_gas: 800_000,
+            max_gas_per_batch: 200_000_000,
+            max_pubdata_per_batch: 100_000,
+        });
+        let fee_input = BatchFeeInput::pubdata_independent(1_000, 250_000_000, 2_000);
+        let provider = FixedFeeInputProvider::new(config, fee_input).unwrap();
+
+        for (l1_gas_price_scale, l1_pubdata_price_scale) in [(1.0, 1.0), (1.5, 2.0), (0.5, 10.0)] {
+            let actual = provider
+                .get_batch_fee_input_scaled(l1_gas_price_scale, l1_pubdata_price_scale)
+                .await
+                .unwrap();
+            assert_eq!(actual, fee_input);
+        }
+
+        let FeeParams::V2(params) = provider.get_fee_model_params() else {
+            panic!("expected V2 fee params");
+        };
+        assert_eq!(params.l1_gas_price(), 1_000);
+        assert_eq!(params.l1_pubdata_price(), 2_000);
+    }
+}
@@ -1,10 +1,15 @@
 //! General-purpose state keeper metrics.
 
 use std::{
-    sync::{Mutex, Weak},
+    ops::Deref,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, Weak,
+    },
     time::Duration,
 };
 
+use once_cell::sync::Lazy;
 use vise::{
     Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LatencyObserver,
     Metrics,
@@ -18,6 +23,50 @@ use zksync_types::ProtocolVersionId;
 
 use super::seal_criteria::SealResolution;
 
+/// Whether the state keeper metrics are reported; see [`set_metrics_enabled()`].
+static METRICS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Enables or disables the state keeper metrics. Unlike other components, the state keeper updates
+/// its metrics from the I/O, batch executor, seal criteria and updates manager, so the switch is
+/// process-wide rather than passed to each of them. While disabled, metrics stay registered, but
+/// aren't updated.
+pub fn set_metrics_enabled(enabled: bool) {
+    METRICS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+fn metrics_enabled() -> bool {
+    METRICS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Registered metrics that can be disabled with [`set_metrics_enabled()`]. While disabled, updates
+/// go to a detached instance of the metrics, which is never exported.
+#[derive(Debug)]
+pub struct SwitchableMetrics<M: Metrics + 'static> {
+    registered: &'static vise::Global<M>,
+    detached: Lazy<M>,
+}
+
+impl<M: Metrics + Default> SwitchableMetrics<M> {
+    const fn new(registered: &'static vise::Global<M>) -> Self {
+        Self {
+            registered,
+            detached: Lazy::new(M::default),
+        }
+    }
+}
+
+impl<M: Metrics> Deref for SwitchableMetrics<M> {
+    type Target = M;
+
+    fn deref(&self) -> &M {
+        if metrics_enabled() {
+            &**self.registered
+        } else {
+            &*self.detached
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
 pub enum TxExecutionStage {
@@ -147,7 +196,9 @@ impl StateKeeperMetrics {
 }
 
 #[vise::register]
-pub static KEEPER_METRICS: vise::Global<StateKeeperMetrics> = vise::Global::new();
+static REGISTERED_KEEPER_METRICS: vise::Global<StateKeeperMetrics> = vise::Global::new();
+pub static KEEPER_METRICS: SwitchableMetrics<StateKeeperMetrics> =
+    SwitchableMetrics::new(&REGISTERED_KEEPER_METRICS);
 
 /// State keeper-related gauges exposed via a collector.
 #[derive(Debug, Metrics)]
@@ -163,6 +214,10 @@ pub(super) struct StateKeeperGauges {
 
 impl StateKeeperGauges {
     pub(super) fn register(pool_ref: Weak<Mutex<MempoolStore>>) {
+        if !metrics_enabled() {
+            return;
+        }
+
         #[vise::register]
         static COLLECTOR: vise::Collector<Option<StateKeeperGauges>> = vise::Collector::new();
 
@@ -248,10 +303,25 @@ impl TxAggregationMetrics {
     pub fn l2_block_reason_inc(&self, reason: &L2BlockSealReason) {
         self.l2_block_reason[reason].inc();
     }
+
+    #[cfg(test)]
+    pub fn l1_batch_reason_count(
+        &self,
+        criterion: &'static str,
+        resolution: &SealResolution,
+    ) -> u64 {
+        let labels = TxAggregationLabels {
+            criterion,
+            seal_resolution: Some(resolution.into()),
+        };
+        self.reason[&labels].get()
+    }
 }
 
 #[vise::register]
-pub(super) static AGGREGATION_METRICS: vise::Global<TxAggregationMetrics> = vise::Global::new();
+static REGISTERED_AGGREGATION_METRICS: vise::Global<TxAggregationMetrics> = vise::Global::new();
+pub(super) static AGGREGATION_METRICS: SwitchableMetrics<TxAggregationMetrics> =
+    SwitchableMetrics::new(&REGISTERED_AGGREGATION_METRICS);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -322,7 +392,9 @@ impl L1BatchMetrics {
 }
 
 #[vise::register]
-pub(crate) static L1_BATCH_METRICS: vise::Global<L1BatchMetrics> = vise::Global::new();
+static REGISTERED_L1_BATCH_METRICS: vise::Global<L1BatchMetrics> = vise::Global::new();
+pub(crate) static L1_BATCH_METRICS: SwitchableMetrics<L1BatchMetrics> =
+    SwitchableMetrics::new(&REGISTERED_L1_BATCH_METRICS);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue, EncodeLabelSet)]
 #[metrics(label = "stage", rename_all = "snake_case")]
@@ -403,7 +475,9 @@ impl L2BlockMetrics {
 }
 
 #[vise::register]
-pub(super) static L2_BLOCK_METRICS: vise::Global<L2BlockMetrics> = vise::Global::new();
+static REGISTERED_L2_BLOCK_METRICS: vise::Global<L2BlockMetrics> = vise::Global::new();
+pub(super) static L2_BLOCK_METRICS: SwitchableMetrics<L2BlockMetrics> =
+    SwitchableMetrics::new(&REGISTERED_L2_BLOCK_METRICS);
 
 /// Tracking progress of L1 batch or L2 block sealing.
 #[must_use = "Progress must be `observe()`d"]
@@ -472,7 +546,9 @@ pub(super) struct ExecutorMetrics {
 }
 
 #[vise::register]
-pub(super) static EXECUTOR_METRICS: vise::Global<ExecutorMetrics> = vise::Global::new();
+static REGISTERED_EXECUTOR_METRICS: vise::Global<ExecutorMetrics> = vise::Global::new();
+pub(super) static EXECUTOR_METRICS: SwitchableMetrics<ExecutorMetrics> =
+    SwitchableMetrics::new(&REGISTERED_EXECUTOR_METRICS);
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "via_batch_tip")]
@@ -523,7 +599,9 @@ impl BatchTipMetrics {
 }
 
 #[vise::register]
-pub(crate) static BATCH_TIP_METRICS: vise::Global<BatchTipMetrics> = vise::Global::new();
+static REGISTERED_BATCH_TIP_METRICS: vise::Global<BatchTipMetrics> = vise::Global::new();
+pub(crate) static BATCH_TIP_METRICS: SwitchableMetrics<BatchTipMetrics> =
+    SwitchableMetrics::new(&REGISTERED_BATCH_TIP_METRICS);
 
 #[derive(Debug, Metrics)]
 #[metrics(prefix = "via_server_state_keeper_updates_manager")]
@@ -535,5 +613,7 @@ pub struct UpdatesManagerMetrics {
 }
 
 #[vise::register]
-pub(crate) static UPDATES_MANAGER_METRICS: vise::Global<UpdatesManagerMetrics> =
+static REGISTERED_UPDATES_MANAGER_METRICS: vise::Global<UpdatesManagerMetrics> =
     vise::Global::new();
+pub(crate) static UPDATES_MANAGER_METRICS: SwitchableMetrics<UpdatesManagerMetrics> =
+    SwitchableMetrics::new(&REGISTERED_UPDATES_MANAGER_METRICS);
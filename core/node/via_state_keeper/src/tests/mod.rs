@@ -30,9 +30,10 @@ use crate::{
     batch_executor::TxExecutionResult,
     io::PendingBatchData,
     keeper::POLL_WAIT_DURATION,
+    metrics::{set_metrics_enabled, AGGREGATION_METRICS},
     seal_criteria::{
         criteria::{GasCriterion, SlotsCriterion},
-        SequencerSealer, UnexecutableReason,
+        SealCriterion, SealData, SealResolution, SequencerSealer, UnexecutableReason,
     },
     testonly::{
         successful_exec,
@@ -305,6 +306,57 @@ async fn batch_sealed_before_l2_block_does() {
         .await;
 }
 
+#[tokio::test]
+async fn disabled_metrics_are_not_updated() {
+    /// Seals an L1 batch after each transaction. Used only in this test, so that metrics labeled
+    /// with its name aren't affected by concurrently running tests.
+    #[derive(Debug)]
+    struct MetricsTestCriterion;
+
+    impl SealCriterion for MetricsTestCriterion {
+        fn should_seal(
+            &self,
+            _config: &StateKeeperConfig,
+            _block_open_timestamp_ms: u128,
+            _tx_count: usize,
+            _block_data: &SealData,
+            _tx_data: &SealData,
+            _protocol_version: ProtocolVersionId,
+        ) -> SealResolution {
+            SealResolution::IncludeAndSeal
+        }
+
+        fn prom_criterion_name(&self) -> &'static str {
+            "metrics_test"
+        }
+    }
+
+    async fn run_scenario() {
+        let sealer = SequencerSealer::with_sealers(
+            StateKeeperConfig::default(),
+            vec![Box::new(MetricsTestCriterion)],
+        );
+        TestScenario::new()
+            .next_tx("First tx", random_tx(1), successful_exec())
+            .l2_block_sealed("L2 block 1")
+            .batch_sealed("Batch 1")
+            .run(sealer)
+            .await;
+    }
+
+    let sealed_batches = || {
+        AGGREGATION_METRICS.l1_batch_reason_count("metrics_test", &SealResolution::IncludeAndSeal)
+    };
+
+    set_metrics_enabled(false);
+    run_scenario().await;
+    set_metrics_enabled(true);
+    assert_eq!(sealed_batches(), 0);
+
+    run_scenario().await;
+    assert_eq!(sealed_batches(), 1);
+}
+
 #[tokio::test]
 async fn rejected_tx() {
     let config = StateKeeperConfig {